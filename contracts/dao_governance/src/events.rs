@@ -1,8 +1,16 @@
-use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol};
 
 const PROPOSAL_CREATED: Symbol = symbol_short!("PROP_CRT");
 const VOTE_CAST: Symbol = symbol_short!("VOTE_CST");
 const PROPOSAL_STATUS: Symbol = symbol_short!("PROP_STS");
+const PROPOSAL_AMENDED: Symbol = symbol_short!("PROP_AMD");
+const COMMENT_ADDED: Symbol = symbol_short!("COMMENT");
+const TREASURY_DEPOSIT: Symbol = symbol_short!("TRSY_IN");
+const TREASURY_DISBURSE: Symbol = symbol_short!("TRSY_OUT");
+const TREASURY_TOKEN: Symbol = symbol_short!("TRSY_TKN");
+const VOTE_ROOT: Symbol = symbol_short!("VOTE_ROOT");
+const BALLOT_OK: Symbol = symbol_short!("BALLOT_OK");
+const TALLY_REJECTED: Symbol = symbol_short!("TALLY_REJ");
 
 pub fn emit_proposal_created(env: &Env, proposal_id: u32, creator: Address) {
     env.events()
@@ -21,6 +29,60 @@ pub fn emit_proposal_finalized(env: &Env, proposal_id: u32, approved: bool) {
         .publish((PROPOSAL_STATUS, proposal_id), approved);
 }
 
+/// Emits an event when a proposal's title or description is amended
+pub fn emit_proposal_amended(env: &Env, proposal_id: u32, version: u32) {
+    env.events()
+        .publish((PROPOSAL_AMENDED, proposal_id), version);
+}
+
+/// Emits an event when a discussion entry is added to a proposal
+pub fn emit_comment_added(env: &Env, proposal_id: u32, comment_id: u32, author: Address) {
+    env.events()
+        .publish((COMMENT_ADDED, proposal_id, author), comment_id);
+}
+
+/// Emits an event when tokens are deposited into the treasury
+pub fn emit_treasury_deposit(env: &Env, token: Address, from: Address, amount: i128) {
+    env.events()
+        .publish((TREASURY_DEPOSIT, token, from), amount);
+}
+
+/// Emits an event when an executed Funding proposal pays out from the treasury
+pub fn emit_treasury_disbursement(
+    env: &Env,
+    proposal_id: u32,
+    token: Address,
+    recipient: Address,
+    amount: i128,
+) {
+    env.events()
+        .publish((TREASURY_DISBURSE, proposal_id, token, recipient), amount);
+}
+
+/// Emits an event when a token is added to or removed from the treasury whitelist
+pub fn emit_treasury_token_updated(env: &Env, token: Address, whitelisted: bool) {
+    env.events()
+        .publish((TREASURY_TOKEN, token), whitelisted);
+}
+
+/// Emits an event when a relayer submits an off-chain vote root
+pub fn emit_vote_root_submitted(env: &Env, proposal_id: u32, root: BytesN<32>, dispute_end: u64) {
+    env.events()
+        .publish((VOTE_ROOT, proposal_id), (root, dispute_end));
+}
+
+/// Emits an event when a ballot is proven to be part of a submitted vote root
+pub fn emit_ballot_verified(env: &Env, proposal_id: u32, voter: Address) {
+    env.events()
+        .publish((BALLOT_OK, proposal_id), voter);
+}
+
+/// Emits an event when a challenge invalidates a submitted vote root
+pub fn emit_tally_rejected(env: &Env, proposal_id: u32, root: BytesN<32>) {
+    env.events()
+        .publish((TALLY_REJECTED, proposal_id), root);
+}
+
 /// Emits an event when the DAO configuration is updated
 pub fn emit_config_updated(env: &Env) {
     env.events().publish((symbol_short!("CONFIG"),), ());
@@ -1,27 +1,51 @@
 // src/governance.rs
 use crate::client::calculate_voting_power;
 use crate::events::{
-    emit_proposal_created, emit_proposal_executed, emit_proposal_finalized, emit_vote_cast,
+    emit_comment_added, emit_proposal_amended, emit_proposal_created, emit_proposal_executed,
+    emit_proposal_finalized, emit_vote_cast,
 };
 use crate::storage::{
-    get_config, get_proposal, has_voted,
-    save_proposal, save_vote, generate_proposal_id,
+    get_comment_count, get_config, get_proposal, get_proposal_count, get_history_len,
+    get_funding_request, has_voted, push_comment, push_proposal_version, save_funding_request, save_proposal, save_vote,
+    generate_proposal_id, index_proposal, vote_count, with_effective_status, MAX_STATUS_SCAN,
+};
+use crate::index_storage;
+use crate::merkle;
+use crate::treasury;
+use crate::types::{
+    Comment, DaoError, FinalizeBatch, FundingRequest, Proposal, ProposalIndex, ProposalParams, ProposalStatus,
+    ProposalType, ProposalVersion, Vote, VoteType,
 };
-use crate::types::{DaoError, Proposal, ProposalStatus, ProposalType, Vote, VoteType};
 use core::cmp::max;
 use core::cmp::min;
 use soroban_sdk::{Address, Env, String};
 
+// Maximum number of proposals finalized by a single finalize_expired call
+const MAX_FINALIZE_BATCH: u32 = 50;
+
+// Maximum number of proposals indexed by a single backfill call
+const MAX_BACKFILL_BATCH: u32 = 50;
+
+// Deliberation limits
+const MAX_AMENDMENTS_PER_PROPOSAL: u32 = 10;
+const MAX_COMMENTS_PER_PROPOSAL: u32 = 500;
+const MAX_COMMENT_LENGTH: u32 = 256;
+
 pub fn create_proposal(
     env: &Env,
     proposer: &Address,
-    title: String,
-    description: String,
-    proposal_type: ProposalType,
-    voting_period: u64,
-    minimum_quorum: u64,
-    minimum_approval: u64,
+    params: ProposalParams,
+    start_time: Option<u64>,
 ) -> Result<u32, DaoError> {
+    let ProposalParams {
+        title,
+        description,
+        proposal_type,
+        voting_period,
+        minimum_quorum,
+        minimum_approval,
+    } = params;
+
     // Check if contract is paused
     let config = get_config(env);
     if config.paused {
@@ -50,6 +74,17 @@ pub fn create_proposal(
         return Err(DaoError::InvalidTimestamp);
     }
 
+    // Scheduled proposals stay Pending until their start time
+    let start_time = start_time.unwrap_or(now);
+    if start_time < now || start_time - now > max_voting_period {
+        return Err(DaoError::InvalidTimestamp);
+    }
+    let status = if start_time > now {
+        ProposalStatus::Pending
+    } else {
+        ProposalStatus::Active
+    };
+
     let voting_power = calculate_voting_power(
         env,
         &config.nft_contract,
@@ -69,9 +104,9 @@ pub fn create_proposal(
         description,
         proposal_type,
         proposer: proposer.clone(),
-        start_time: now,
-        end_time: now + actual_voting_period,
-        status: ProposalStatus::Active,
+        start_time,
+        end_time: start_time + actual_voting_period,
+        status,
         upvotes: 0,
         downvotes: 0,
         minimum_quorum,
@@ -97,11 +132,16 @@ pub fn cast_vote(
     }
 
     let mut proposal = get_proposal(env, proposal_id)?;
-    if !matches!(proposal.status, ProposalStatus::Active) {
-        return Err(DaoError::ProposalNotActive);
-    }
-    
     let now = env.ledger().timestamp();
+    match proposal.status {
+        ProposalStatus::Active => {}
+        ProposalStatus::Pending if now < proposal.start_time => {
+            return Err(DaoError::VotingNotStarted);
+        }
+        // Start time reached: the first vote persists the transition
+        ProposalStatus::Pending => proposal.status = ProposalStatus::Active,
+        _ => return Err(DaoError::ProposalNotActive),
+    }
     
     // Timestamp manipulation protection - validate reasonable time ranges
     let max_future_time = now + 24 * 3600; // 24 hours
@@ -152,29 +192,100 @@ pub fn cast_vote(
 }
 
 pub fn finalize_proposal(env: &Env, proposal_id: u32) -> Result<(), DaoError> {
-    let mut proposal = get_proposal(env, proposal_id)?;
+    let proposal = get_proposal(env, proposal_id)?;
     let now = env.ledger().timestamp();
     if now <= proposal.end_time {
         return Err(DaoError::VotingNotEnded);
     }
-    if !matches!(proposal.status, ProposalStatus::Active) {
+    if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Active) {
         return Err(DaoError::ProposalNotActive);
     }
 
+    let proposal = merkle::apply_accepted_tally(env, proposal)?;
+    tally_proposal(env, proposal);
+    Ok(())
+}
+
+// Finalize up to `limit` proposals whose voting period has ended, starting
+// from open proposal id `cursor`. At most MAX_STATUS_SCAN open proposals are
+// examined per call, since unexpired ones and those still inside a dispute
+// window are skipped; resume from `next_cursor` until it is None.
+pub fn finalize_expired(env: &Env, cursor: u32, limit: u32) -> FinalizeBatch {
+    let now = env.ledger().timestamp();
+    let limit = min(limit, MAX_FINALIZE_BATCH);
+    let ids = index_storage::get_from(
+        env,
+        &ProposalIndex::Open,
+        cursor,
+        MAX_STATUS_SCAN.saturating_add(1),
+    );
+    let scan_len = min(ids.len(), MAX_STATUS_SCAN);
+    let mut finalized = 0;
+    let mut scanned = 0;
+
+    while scanned < scan_len && finalized < limit {
+        let id = ids.get_unchecked(scanned);
+        scanned += 1;
+        let Ok(proposal) = get_proposal(env, id) else {
+            continue;
+        };
+        if now <= proposal.end_time {
+            continue;
+        }
+        // Proposals still inside a merkle dispute window are skipped
+        if let Ok(proposal) = merkle::apply_accepted_tally(env, proposal) {
+            tally_proposal(env, proposal);
+            finalized += 1;
+        }
+    }
+
+    FinalizeBatch {
+        finalized,
+        next_cursor: ids.get(scanned),
+    }
+}
+
+// Index proposals [start_id, start_id + limit) under their status, type and
+// proposer. Returns the id to resume from; past the last proposal when done.
+pub fn backfill_proposal_indexes(
+    env: &Env,
+    caller: &Address,
+    start_id: u32,
+    limit: u32,
+) -> Result<u32, DaoError> {
+    if *caller != get_config(env).admin {
+        return Err(DaoError::Unauthorized);
+    }
+
+    let start_id = max(start_id, 1);
+    let end_id = min(
+        start_id.saturating_add(min(limit, MAX_BACKFILL_BATCH)),
+        get_proposal_count(env).saturating_add(1),
+    );
+    for id in start_id..end_id {
+        if let Ok(proposal) = get_proposal(env, id) {
+            index_proposal(env, &proposal);
+        }
+    }
+    Ok(max(start_id, end_id))
+}
+
+fn tally_proposal(env: &Env, mut proposal: Proposal) {
+    let proposal_id = proposal.id;
     let total_votes = proposal.upvotes + proposal.downvotes;
     let _config = get_config(env);
     if total_votes < proposal.minimum_quorum {
         proposal.status = ProposalStatus::Rejected;
         save_proposal(env, &proposal);
         emit_proposal_finalized(env, proposal_id, false);
-        return Ok(());
+        return;
     }
     
     if total_votes == 0 {
         proposal.status = ProposalStatus::Rejected;
         save_proposal(env, &proposal);
         emit_proposal_finalized(env, proposal_id, false);
-        return Ok(());
+        return;
     }
 
     let approval_percentage = (proposal.upvotes * 100) / total_votes;
@@ -187,7 +298,6 @@ pub fn finalize_proposal(env: &Env, proposal_id: u32) -> Result<(), DaoError> {
     save_proposal(env, &proposal);
     let approved = matches!(proposal.status, ProposalStatus::Passed);
     emit_proposal_finalized(env, proposal_id, approved);
-    Ok(())
 }
 
 pub fn execute_proposal(env: &Env, proposal_id: u32) -> Result<(), DaoError> {
@@ -203,6 +313,15 @@ pub fn execute_proposal(env: &Env, proposal_id: u32) -> Result<(), DaoError> {
     if now < proposal.end_time + config.execution_delay {
         return Err(DaoError::ExecutionDelayNotMet);
     }
+
+    // Funding proposals pay out from the treasury; the proposal stays
+    // executable if the payout is refused
+    if matches!(proposal.proposal_type, ProposalType::Funding) {
+        if let Some(request) = get_funding_request(env, proposal_id) {
+            treasury::disburse(env, proposal_id, &request)?;
+        }
+    }
+
     proposal.executed = true;
     save_proposal(env, &proposal);
 
@@ -210,6 +329,132 @@ pub fn execute_proposal(env: &Env, proposal_id: u32) -> Result<(), DaoError> {
     Ok(())
 }
 
+// Create a Funding proposal together with the disbursement it pays out
+pub fn create_funding_proposal(
+    env: &Env,
+    proposer: &Address,
+    params: ProposalParams,
+    request: &FundingRequest,
+) -> Result<u32, DaoError> {
+    if params.proposal_type != ProposalType::Funding {
+        return Err(DaoError::ExecutionFailed);
+    }
+    treasury::validate_funding_request(env, request)?;
+
+    let proposal_id = create_proposal(env, proposer, params, None)?;
+    save_funding_request(env, proposal_id, request);
+    Ok(proposal_id)
+}
+
+// Replace the title and description of a proposal before any vote is cast.
+// The superseded text is appended to the proposal's version history.
+pub fn amend_proposal(
+    env: &Env,
+    proposer: &Address,
+    proposal_id: u32,
+    title: String,
+    description: String,
+) -> Result<u32, DaoError> {
+    let config = get_config(env);
+    if config.paused {
+        return Err(DaoError::ContractPaused);
+    }
+
+    let mut proposal = get_proposal(env, proposal_id)?;
+    if proposal.proposer != *proposer {
+        return Err(DaoError::Unauthorized);
+    }
+    if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Active) {
+        return Err(DaoError::ProposalNotActive);
+    }
+    if env.ledger().timestamp() > proposal.end_time {
+        return Err(DaoError::VotingEnded);
+    }
+    if vote_count(env, proposal_id) > 0 {
+        return Err(DaoError::AmendmentNotAllowed);
+    }
+    if title.is_empty() || description.is_empty() {
+        return Err(DaoError::ExecutionFailed);
+    }
+
+    let history_len = get_history_len(env, proposal_id);
+    if history_len >= MAX_AMENDMENTS_PER_PROPOSAL {
+        return Err(DaoError::AmendmentLimitExceeded);
+    }
+
+    // The original text is version 1; each amendment bumps the version
+    let superseded = ProposalVersion {
+        version: history_len + 1,
+        title: proposal.title.clone(),
+        description: proposal.description.clone(),
+        replaced_at: env.ledger().timestamp(),
+    };
+    let new_version = push_proposal_version(env, proposal_id, &superseded) + 1;
+
+    proposal.title = title;
+    proposal.description = description;
+
+    save_proposal(env, &proposal);
+    emit_proposal_amended(env, proposal_id, new_version);
+    Ok(new_version)
+}
+
+// Attach a discussion entry to a proposal. Only addresses with voting power
+// may comment.
+pub fn add_comment(
+    env: &Env,
+    author: &Address,
+    proposal_id: u32,
+    content: String,
+) -> Result<u32, DaoError> {
+    let config = get_config(env);
+    if config.paused {
+        return Err(DaoError::ContractPaused);
+    }
+
+    // Ensure the proposal exists
+    get_proposal(env, proposal_id)?;
+
+    if content.is_empty() {
+        return Err(DaoError::ExecutionFailed);
+    }
+    if content.len() > MAX_COMMENT_LENGTH {
+        return Err(DaoError::CommentTooLong);
+    }
+
+    let voting_power = calculate_voting_power(
+        env,
+        &config.nft_contract,
+        &config.reputation_contract,
+        author,
+    );
+    if voting_power == 0 {
+        return Err(DaoError::InsufficientVotingPower);
+    }
+
+    let comment_id = get_comment_count(env, proposal_id) + 1;
+    if comment_id > MAX_COMMENTS_PER_PROPOSAL {
+        return Err(DaoError::CommentLimitExceeded);
+    }
+
+    let comment = Comment {
+        id: comment_id,
+        proposal_id,
+        author: author.clone(),
+        content,
+        timestamp: env.ledger().timestamp(),
+    };
+    push_comment(env, proposal_id, &comment);
+
+    emit_comment_added(env, proposal_id, comment_id, author.clone());
+    Ok(comment_id)
+}
+
+// Read-only view of a proposal with its effective status
+pub fn get_proposal_view(env: &Env, proposal_id: u32) -> Result<Proposal, DaoError> {
+    get_proposal(env, proposal_id).map(|proposal| with_effective_status(env, proposal))
+}
+
 pub fn get_proposal_results(env: &Env, proposal_id: u32) -> Result<(u64, u64), DaoError> {
     let proposal = get_proposal(env, proposal_id)?;
     Ok((proposal.upvotes, proposal.downvotes))
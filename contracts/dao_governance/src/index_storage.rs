@@ -0,0 +1,148 @@
+// src/index_storage.rs
+//
+// Secondary proposal indexes, kept in persistent storage so they are not
+// loaded on every invocation. Each index is split into pages by proposal id:
+// page n holds the indexed ids in [n * INDEX_PAGE_SIZE, (n + 1) * INDEX_PAGE_SIZE),
+// so an id's page is known without a lookup and every page stays sorted.
+use crate::storage::bump_persistent;
+use crate::types::{DataKey, ProposalIndex, ProposalIndexMeta};
+use soroban_sdk::{Env, Map, Vec};
+
+// Width of the id range covered by a single index page
+pub const INDEX_PAGE_SIZE: u32 = 100;
+
+fn page_of(id: u32) -> u32 {
+    id / INDEX_PAGE_SIZE
+}
+
+fn get_meta(env: &Env, index: &ProposalIndex) -> ProposalIndexMeta {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProposalIndexMeta(index.clone()))
+        .unwrap_or_else(|| ProposalIndexMeta {
+            len: 0,
+            page_lens: Map::new(env),
+        })
+}
+
+fn set_meta(env: &Env, index: &ProposalIndex, meta: &ProposalIndexMeta) {
+    let key = DataKey::ProposalIndexMeta(index.clone());
+    if meta.len == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, meta);
+    bump_persistent(env, &key);
+}
+
+// Sorted ids stored in one page of an index
+pub fn get_page(env: &Env, index: &ProposalIndex, page: u32) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ProposalIndexPage(index.clone(), page))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+// Emptied pages are removed, so storage only holds pages with live entries
+fn set_page(env: &Env, index: &ProposalIndex, page: u32, ids: &Vec<u32>) {
+    let key = DataKey::ProposalIndexPage(index.clone(), page);
+    if ids.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, ids);
+    bump_persistent(env, &key);
+}
+
+// Numbers of the non-empty pages of an index, in ascending order
+pub fn pages(env: &Env, index: &ProposalIndex) -> Vec<u32> {
+    get_meta(env, index).page_lens.keys()
+}
+
+// Add an id to an index. Returns false if it was already present.
+pub fn insert(env: &Env, index: &ProposalIndex, id: u32) -> bool {
+    let page = page_of(id);
+    let mut ids = get_page(env, index, page);
+    let Err(pos) = ids.binary_search(id) else {
+        return false;
+    };
+    ids.insert(pos, id);
+    set_page(env, index, page, &ids);
+
+    let mut meta = get_meta(env, index);
+    meta.len += 1;
+    meta.page_lens.set(page, ids.len());
+    set_meta(env, index, &meta);
+    true
+}
+
+// Remove an id from an index. Returns false if it was not present.
+pub fn remove(env: &Env, index: &ProposalIndex, id: u32) -> bool {
+    let page = page_of(id);
+    let mut ids = get_page(env, index, page);
+    let Ok(pos) = ids.binary_search(id) else {
+        return false;
+    };
+    ids.remove(pos);
+    set_page(env, index, page, &ids);
+
+    let mut meta = get_meta(env, index);
+    meta.len = meta.len.saturating_sub(1);
+    if ids.is_empty() {
+        meta.page_lens.remove(page);
+    } else {
+        meta.page_lens.set(page, ids.len());
+    }
+    set_meta(env, index, &meta);
+    true
+}
+
+// Up to `limit` ids starting at position `offset`, reading only the pages
+// that overlap the requested range
+pub fn get_range(env: &Env, index: &ProposalIndex, offset: u32, limit: u32) -> Vec<u32> {
+    let meta = get_meta(env, index);
+    let mut result = Vec::new(env);
+    let mut skip = offset;
+
+    for (page, page_len) in meta.page_lens.iter() {
+        if result.len() >= limit {
+            break;
+        }
+        if skip >= page_len {
+            skip -= page_len;
+            continue;
+        }
+
+        for id in get_page(env, index, page).iter().skip(skip as usize) {
+            if result.len() >= limit {
+                break;
+            }
+            result.push_back(id);
+        }
+        skip = 0;
+    }
+    result
+}
+
+// Up to `limit` ids not lower than `start_id`, in ascending order
+pub fn get_from(env: &Env, index: &ProposalIndex, start_id: u32, limit: u32) -> Vec<u32> {
+    let mut result = Vec::new(env);
+    for page in pages(env, index).iter() {
+        if result.len() >= limit {
+            break;
+        }
+        if page < page_of(start_id) {
+            continue;
+        }
+
+        for id in get_page(env, index, page).iter() {
+            if result.len() >= limit {
+                break;
+            }
+            if id >= start_id {
+                result.push_back(id);
+            }
+        }
+    }
+    result
+}
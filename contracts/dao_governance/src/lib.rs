@@ -1,16 +1,24 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 mod client;
 mod events;
 mod governance;
+mod index_storage;
+mod merkle;
 mod storage;
+mod treasury;
 mod types;
 
 #[cfg(test)]
 mod test;
 
-use types::{DaoConfig, DaoError, Proposal, ProposalType, VoteType, DataKey};
+use types::{
+    Comment, DaoConfig, DaoError, DataKey, FinalizeBatch, FundingRequest, MerkleBallot, MerkleConfig, MerkleNode,
+    MerkleOpening, MerkleTally,
+    Proposal, ProposalPage, ProposalParams, ProposalStatus, ProposalType, ProposalVersion,
+    TreasuryTokenConfig, Vote, VoteType,
+};
 
 #[contract]
 pub struct DaoContract;
@@ -63,19 +71,89 @@ impl DaoContract {
         minimum_approval: u64,
     ) -> Result<u32, DaoError> {
         proposer.require_auth();
-        let proposal_id = governance::create_proposal(
-            &env,
-            &proposer,
+        let params = ProposalParams {
             title,
             description,
             proposal_type,
             voting_period,
             minimum_quorum,
             minimum_approval,
-        )?;
-        events::emit_proposal_created(&env, proposal_id, proposer);
+        };
+        governance::create_proposal(&env, &proposer, params, None)
+    }
+
+    // Create a proposal that stays Pending until `start_time`
+    pub fn create_scheduled_proposal(
+        env: Env,
+        proposer: Address,
+        params: ProposalParams,
+        start_time: u64,
+    ) -> Result<u32, DaoError> {
+        proposer.require_auth();
+        governance::create_proposal(&env, &proposer, params, Some(start_time))
+    }
+
+    // Create a Funding proposal that pays `funding.amount` of `funding.token`
+    // from the treasury to `funding.recipient` once executed.
+    // `params.proposal_type` must be Funding.
+    pub fn create_funding_proposal(
+        env: Env,
+        proposer: Address,
+        params: ProposalParams,
+        funding: FundingRequest,
+    ) -> Result<u32, DaoError> {
+        proposer.require_auth();
+        governance::create_funding_proposal(&env, &proposer, params, &funding)
+    }
 
-        Ok(proposal_id)
+    // Get the disbursement attached to a Funding proposal, if any
+    pub fn get_funding_request(env: Env, proposal_id: u32) -> Option<FundingRequest> {
+        storage::get_funding_request(&env, proposal_id)
+    }
+
+    // Amend the title and description of a proposal before the first vote
+    pub fn amend_proposal(
+        env: Env,
+        proposer: Address,
+        proposal_id: u32,
+        title: String,
+        description: String,
+    ) -> Result<u32, DaoError> {
+        proposer.require_auth();
+        governance::amend_proposal(&env, &proposer, proposal_id, title, description)
+    }
+
+    // Get the superseded versions of a proposal, oldest first, with pagination
+    pub fn get_proposal_history(
+        env: Env,
+        proposal_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ProposalVersion>, DaoError> {
+        storage::get_proposal(&env, proposal_id)?;
+        Ok(storage::get_proposal_history(&env, proposal_id, offset, limit))
+    }
+
+    // Attach a discussion entry (text hash or URI) to a proposal
+    pub fn add_comment(
+        env: Env,
+        author: Address,
+        proposal_id: u32,
+        content: String,
+    ) -> Result<u32, DaoError> {
+        author.require_auth();
+        governance::add_comment(&env, &author, proposal_id, content)
+    }
+
+    // Get discussion entries for a proposal with pagination
+    pub fn get_comments(
+        env: Env,
+        proposal_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Comment>, DaoError> {
+        storage::get_proposal(&env, proposal_id)?;
+        Ok(storage::get_comments(&env, proposal_id, offset, limit))
     }
 
     // Cast a vote on a proposal
@@ -102,6 +180,12 @@ impl DaoContract {
         governance::finalize_proposal(&env, proposal_id)
     }
 
+    // Finalize up to `limit` proposals whose voting period has ended, resuming
+    // from `cursor` (keeper entrypoint)
+    pub fn finalize_expired(env: Env, cursor: u32, limit: u32) -> FinalizeBatch {
+        governance::finalize_expired(&env, cursor, limit)
+    }
+
     // Execute a passed proposal
     pub fn execute_proposal(env: Env, caller: Address, proposal_id: u32) -> Result<(), DaoError> {
         caller.require_auth();
@@ -112,7 +196,7 @@ impl DaoContract {
 
     // Get a single proposal
     pub fn get_proposal(env: Env, id: u32) -> Result<Proposal, DaoError> {
-        storage::get_proposal(&env, id)
+        governance::get_proposal_view(&env, id)
     }
 
     // Get all proposals
@@ -125,16 +209,71 @@ impl DaoContract {
         storage::get_proposals_paginated(&env, page, page_size)
     }
 
+    // Get proposals in a given status with id >= `cursor`, ordered by id.
+    // Pass the returned `next_cursor` to continue; pages may be short.
+    pub fn get_proposals_by_status(
+        env: Env,
+        status: ProposalStatus,
+        cursor: u32,
+        limit: u32,
+    ) -> ProposalPage {
+        storage::get_proposals_by_status(&env, status, cursor, limit)
+    }
+
+    // Get proposals of a given type, ordered by id
+    pub fn get_proposals_by_type(
+        env: Env,
+        proposal_type: ProposalType,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Proposal> {
+        storage::get_proposals_by_type(&env, proposal_type, offset, limit)
+    }
+
+    // Get proposals created by a given address, ordered by id
+    pub fn get_proposals_by_proposer(
+        env: Env,
+        proposer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Proposal> {
+        storage::get_proposals_by_proposer(&env, &proposer, offset, limit)
+    }
+
     // Get total proposal count for pagination
     pub fn get_total_proposal_count(env: Env) -> u32 {
         storage::get_total_proposal_count(&env)
     }
 
+    // Index up to `limit` proposals starting at `start_id` (admin only). Used to
+    // cover proposals created before the indexes existed; returns the id to
+    // resume from.
+    pub fn backfill_proposal_indexes(
+        env: Env,
+        caller: Address,
+        start_id: u32,
+        limit: u32,
+    ) -> Result<u32, DaoError> {
+        caller.require_auth();
+        governance::backfill_proposal_indexes(&env, &caller, start_id, limit)
+    }
+
     // Get voting results for a proposal
     pub fn get_proposal_results(env: Env, id: u32) -> Result<(u64, u64), DaoError> {
         governance::get_proposal_results(&env, id)
     }
 
+    // Get the votes cast on a proposal with pagination
+    pub fn get_votes_for_proposal(
+        env: Env,
+        proposal_id: u32,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Vote>, DaoError> {
+        storage::get_proposal(&env, proposal_id)?;
+        Ok(storage::get_votes_for_proposal(&env, proposal_id, offset, limit))
+    }
+
     // Get a user's voting power
     pub fn get_voting_power(env: Env, user: Address) -> u64 {
         let config = storage::get_config(&env);
@@ -151,6 +290,134 @@ impl DaoContract {
         storage::has_voted(&env, proposal_id, &voter)
     }
 
+    // Whitelist a treasury token and set its per-period spending limit (admin only)
+    pub fn set_treasury_token(
+        env: Env,
+        caller: Address,
+        token: Address,
+        spending_limit: i128,
+        period: u64,
+    ) -> Result<(), DaoError> {
+        caller.require_auth();
+        treasury::set_treasury_token(&env, &caller, &token, spending_limit, period)
+    }
+
+    // Remove a token from the treasury whitelist (admin only)
+    pub fn remove_treasury_token(env: Env, caller: Address, token: Address) -> Result<(), DaoError> {
+        caller.require_auth();
+        treasury::remove_treasury_token(&env, &caller, &token)
+    }
+
+    // Get the whitelisted treasury tokens
+    pub fn get_treasury_tokens(env: Env) -> Vec<Address> {
+        storage::get_treasury_tokens(&env)
+    }
+
+    // Get the policy of a whitelisted treasury token
+    pub fn get_treasury_token_config(env: Env, token: Address) -> Option<TreasuryTokenConfig> {
+        storage::get_treasury_token_config(&env, &token)
+    }
+
+    // Deposit whitelisted tokens into the treasury
+    pub fn deposit(env: Env, from: Address, token: Address, amount: i128) -> Result<(), DaoError> {
+        from.require_auth();
+        treasury::deposit(&env, &from, &token, amount)
+    }
+
+    // Get the treasury balance of a token
+    pub fn get_treasury_balance(env: Env, token: Address) -> i128 {
+        treasury::balance(&env, &token)
+    }
+
+    // Configure the relayer and dispute window for off-chain vote aggregation (admin only)
+    pub fn set_merkle_config(
+        env: Env,
+        caller: Address,
+        relayer: Address,
+        dispute_window: u64,
+    ) -> Result<(), DaoError> {
+        caller.require_auth();
+        merkle::set_merkle_config(&env, &caller, &relayer, dispute_window)
+    }
+
+    // Get the off-chain vote aggregation settings
+    pub fn get_merkle_config(env: Env) -> Option<MerkleConfig> {
+        storage::get_merkle_config(&env)
+    }
+
+    // Opt a proposal into off-chain vote aggregation (proposer only)
+    pub fn enable_merkle_voting(env: Env, proposer: Address, proposal_id: u32) -> Result<(), DaoError> {
+        proposer.require_auth();
+        merkle::enable_merkle_voting(&env, &proposer, proposal_id)
+    }
+
+    // Fix the voting power of off-chain voters while voting is open. Merkle
+    // ballots are only valid up to their voter's snapshot.
+    pub fn snapshot_voting_power(
+        env: Env,
+        proposal_id: u32,
+        voters: Vec<Address>,
+    ) -> Result<(), DaoError> {
+        merkle::snapshot_voting_power(&env, proposal_id, &voters)
+    }
+
+    // Get the voting power snapshot of a voter for a proposal, if any
+    pub fn get_power_snapshot(env: Env, proposal_id: u32, voter: Address) -> Option<u64> {
+        storage::get_power_snapshot(&env, proposal_id, &voter)
+    }
+
+    // Submit the sum-tree root and totals of off-chain ballots (relayer only)
+    pub fn submit_vote_root(
+        env: Env,
+        relayer: Address,
+        proposal_id: u32,
+        root: BytesN<32>,
+        upvotes: u64,
+        downvotes: u64,
+    ) -> Result<(), DaoError> {
+        relayer.require_auth();
+        merkle::submit_vote_root(&env, &relayer, proposal_id, root, upvotes, downvotes)
+    }
+
+    // Get the submitted off-chain tally of a proposal, if any
+    pub fn get_merkle_tally(env: Env, proposal_id: u32) -> Option<MerkleTally> {
+        storage::get_merkle_tally(&env, proposal_id)
+    }
+
+    // Check whether a ballot is included in a proposal's submitted vote root
+    pub fn verify_ballot(
+        env: Env,
+        proposal_id: u32,
+        ballot: MerkleBallot,
+        proof: Vec<MerkleNode>,
+    ) -> Result<bool, DaoError> {
+        merkle::is_ballot_included(&env, proposal_id, &ballot, &proof)
+    }
+
+    // Prove a ballot during the dispute window. Returns true if the ballot
+    // was invalid and the submitted tally has been voided.
+    pub fn dispute_ballot(
+        env: Env,
+        proposal_id: u32,
+        ballot: MerkleBallot,
+        proof: Vec<MerkleNode>,
+    ) -> Result<bool, DaoError> {
+        merkle::dispute_ballot(&env, proposal_id, &ballot, &proof)
+    }
+
+    // Open any node of the submitted tree during the dispute window. Returns
+    // true if the node's weight or the totals were inconsistent and the
+    // submitted tally has been voided.
+    pub fn dispute_node(
+        env: Env,
+        proposal_id: u32,
+        node: MerkleNode,
+        opening: MerkleOpening,
+        proof: Vec<MerkleNode>,
+    ) -> Result<bool, DaoError> {
+        merkle::dispute_node(&env, proposal_id, &node, &opening, &proof)
+    }
+
     // Emergency pause/unpause functions (admin only)
     pub fn pause_contract(env: Env, caller: Address) -> Result<(), DaoError> {
         caller.require_auth();
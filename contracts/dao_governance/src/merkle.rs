@@ -0,0 +1,370 @@
+// src/merkle.rs
+//
+// Off-chain vote aggregation. A configured relayer commits to the full set of
+// weighted ballots for a proposal with the root of a merkle sum-tree, where
+// every node also carries the vote weight beneath it. During the dispute
+// window anyone can open a node of the tree against the root; a node whose
+// weight does not match its preimage, totals that do not match the root, or
+// an invalid ballot void the submitted tally. Once the window closes the tally
+// is added to the on-chain votes when the proposal is finalized.
+//
+// Ballots are checked against voting power snapshots, which anyone can take
+// for a voter while the proposal is open for voting.
+use crate::client::calculate_voting_power;
+use crate::events::{emit_ballot_verified, emit_tally_rejected, emit_vote_root_submitted};
+use crate::storage::{
+    get_config, get_merkle_config, get_merkle_tally, get_merkle_voter, get_power_snapshot,
+    get_proposal, has_voted, is_merkle_enabled, remove_merkle_tally, save_merkle_config,
+    save_merkle_tally, save_merkle_voter, save_power_snapshot, set_merkle_enabled,
+};
+use crate::types::{
+    DaoError, MerkleBallot, MerkleConfig, MerkleNode, MerkleOpening, MerkleTally, Proposal,
+    ProposalStatus, VoteType,
+};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+// Maximum number of voters snapshotted by a single call
+const MAX_SNAPSHOT_BATCH: u32 = 50;
+
+// Set the relayer and dispute window (admin only)
+pub fn set_merkle_config(
+    env: &Env,
+    caller: &Address,
+    relayer: &Address,
+    dispute_window: u64,
+) -> Result<(), DaoError> {
+    if *caller != get_config(env).admin {
+        return Err(DaoError::Unauthorized);
+    }
+    if dispute_window == 0 {
+        return Err(DaoError::InvalidTimestamp);
+    }
+
+    save_merkle_config(
+        env,
+        &MerkleConfig {
+            relayer: relayer.clone(),
+            dispute_window,
+        },
+    );
+    Ok(())
+}
+
+// Opt a proposal into off-chain aggregation. Must happen before voting ends.
+pub fn enable_merkle_voting(env: &Env, proposer: &Address, proposal_id: u32) -> Result<(), DaoError> {
+    get_merkle_config(env).ok_or(DaoError::MerkleNotConfigured)?;
+
+    let proposal = get_proposal(env, proposal_id)?;
+    if proposal.proposer != *proposer {
+        return Err(DaoError::Unauthorized);
+    }
+    if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Active) {
+        return Err(DaoError::ProposalNotActive);
+    }
+    if env.ledger().timestamp() > proposal.end_time {
+        return Err(DaoError::VotingEnded);
+    }
+
+    set_merkle_enabled(env, proposal_id);
+    Ok(())
+}
+
+// Fix the current voting power of `voters` for a merkle-enabled proposal.
+// Only possible while voting is open; a voter's first snapshot is final.
+pub fn snapshot_voting_power(
+    env: &Env,
+    proposal_id: u32,
+    voters: &Vec<Address>,
+) -> Result<(), DaoError> {
+    if !is_merkle_enabled(env, proposal_id) {
+        return Err(DaoError::MerkleNotEnabled);
+    }
+    if voters.len() > MAX_SNAPSHOT_BATCH {
+        return Err(DaoError::VoteLimitExceeded);
+    }
+
+    let proposal = get_proposal(env, proposal_id)?;
+    if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Active) {
+        return Err(DaoError::ProposalNotActive);
+    }
+    let now = env.ledger().timestamp();
+    if now < proposal.start_time {
+        return Err(DaoError::VotingNotStarted);
+    }
+    if now > proposal.end_time {
+        return Err(DaoError::VotingEnded);
+    }
+
+    let config = get_config(env);
+    for voter in voters.iter() {
+        if get_power_snapshot(env, proposal_id, &voter).is_some() {
+            continue;
+        }
+        let voting_power = calculate_voting_power(
+            env,
+            &config.nft_contract,
+            &config.reputation_contract,
+            &voter,
+        );
+        save_power_snapshot(env, proposal_id, &voter, voting_power);
+    }
+    Ok(())
+}
+
+pub fn leaf_hash(env: &Env, ballot: &MerkleBallot) -> BytesN<32> {
+    env.crypto().sha256(&ballot.clone().to_xdr(env)).to_bytes()
+}
+
+// Leaf node of a ballot, covering its voting power on the side it voted for
+pub fn leaf_node(env: &Env, ballot: &MerkleBallot) -> MerkleNode {
+    let (upvotes, downvotes) = match ballot.vote_type {
+        VoteType::Upvote => (ballot.voting_power, 0),
+        VoteType::Downvote => (0, ballot.voting_power),
+    };
+    MerkleNode {
+        hash: leaf_hash(env, ballot),
+        upvotes,
+        downvotes,
+    }
+}
+
+// Parent of two nodes. Children are hashed in sorted order so proofs do not
+// need position bits. None if the combined weight overflows.
+pub fn hash_pair(env: &Env, a: &MerkleNode, b: &MerkleNode) -> Option<MerkleNode> {
+    let (first, second) = if a.hash <= b.hash { (a, b) } else { (b, a) };
+    let mut data = Bytes::new(env);
+    for node in [first, second] {
+        data.append(&Bytes::from(node.hash.clone()));
+        data.extend_from_array(&node.upvotes.to_be_bytes());
+        data.extend_from_array(&node.downvotes.to_be_bytes());
+    }
+
+    Some(MerkleNode {
+        hash: env.crypto().sha256(&data).to_bytes(),
+        upvotes: a.upvotes.checked_add(b.upvotes)?,
+        downvotes: a.downvotes.checked_add(b.downvotes)?,
+    })
+}
+
+fn compute_root(env: &Env, node: MerkleNode, proof: &Vec<MerkleNode>) -> Option<MerkleNode> {
+    let mut node = node;
+    for sibling in proof.iter() {
+        node = hash_pair(env, &node, &sibling)?;
+    }
+    Some(node)
+}
+
+// Whether a path to the root pairs a weighted node with an identical sibling.
+// Two copies of the same ballot always meet as identical subtrees below their
+// lowest common ancestor, so this is how a duplicated leaf shows up in the
+// proof of either copy.
+fn repeats_subtree(env: &Env, node: &MerkleNode, proof: &Vec<MerkleNode>) -> bool {
+    let mut node = node.clone();
+    for sibling in proof.iter() {
+        if sibling == node && (node.upvotes > 0 || node.downvotes > 0) {
+            return true;
+        }
+        match hash_pair(env, &node, &sibling) {
+            Some(parent) => node = parent,
+            None => return false,
+        }
+    }
+    false
+}
+
+// Identifies a proven ballot by its leaf and its position in the tree
+fn ballot_path_hash(env: &Env, leaf: &BytesN<32>, proof: &Vec<MerkleNode>) -> BytesN<32> {
+    let mut data = Bytes::from(leaf.clone());
+    data.append(&proof.clone().to_xdr(env));
+    env.crypto().sha256(&data).to_bytes()
+}
+
+// Submit the aggregated tally for a proposal once its voting period is over.
+// Roots are accepted until `end_time + dispute_window`; a root voided by a
+// challenge may be replaced within the same deadline.
+pub fn submit_vote_root(
+    env: &Env,
+    relayer: &Address,
+    proposal_id: u32,
+    root: BytesN<32>,
+    upvotes: u64,
+    downvotes: u64,
+) -> Result<(), DaoError> {
+    let config = get_merkle_config(env).ok_or(DaoError::MerkleNotConfigured)?;
+    if *relayer != config.relayer {
+        return Err(DaoError::Unauthorized);
+    }
+    if !is_merkle_enabled(env, proposal_id) {
+        return Err(DaoError::MerkleNotEnabled);
+    }
+
+    let proposal = get_proposal(env, proposal_id)?;
+    if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Active) {
+        return Err(DaoError::ProposalNotActive);
+    }
+    let now = env.ledger().timestamp();
+    if now <= proposal.end_time {
+        return Err(DaoError::VotingNotEnded);
+    }
+    if now > proposal.end_time.saturating_add(config.dispute_window) {
+        return Err(DaoError::DisputeWindowClosed);
+    }
+    if get_merkle_tally(env, proposal_id).is_some() {
+        return Err(DaoError::MerkleTallyExists);
+    }
+    upvotes.checked_add(downvotes).ok_or(DaoError::ExecutionFailed)?;
+
+    let dispute_end = now.saturating_add(config.dispute_window);
+    save_merkle_tally(
+        env,
+        proposal_id,
+        &MerkleTally {
+            root: root.clone(),
+            upvotes,
+            downvotes,
+            submitted_at: now,
+            dispute_end,
+        },
+    );
+    emit_vote_root_submitted(env, proposal_id, root, dispute_end);
+    Ok(())
+}
+
+// Check that a ballot is part of the submitted root without changing state
+pub fn is_ballot_included(
+    env: &Env,
+    proposal_id: u32,
+    ballot: &MerkleBallot,
+    proof: &Vec<MerkleNode>,
+) -> Result<bool, DaoError> {
+    let tally = get_merkle_tally(env, proposal_id).ok_or(DaoError::MerkleTallyNotFound)?;
+    Ok(compute_root(env, leaf_node(env, ballot), proof)
+        .is_some_and(|root| root.hash == tally.root))
+}
+
+// Prove a ballot against the submitted root during the dispute window
+pub fn dispute_ballot(
+    env: &Env,
+    proposal_id: u32,
+    ballot: &MerkleBallot,
+    proof: &Vec<MerkleNode>,
+) -> Result<bool, DaoError> {
+    let node = leaf_node(env, ballot);
+    dispute_node(env, proposal_id, &node, &MerkleOpening::Leaf(ballot.clone()), proof)
+}
+
+// Open a node of the submitted tree during the dispute window. `node` is the
+// node as committed by its parent and `proof` its path to the root. The tally
+// is voided, returning true, if:
+// - the totals differ from the weight the root's children cover,
+// - the node's weight differs from the weight of its ballot or children, or
+// - the node's proof or opening pairs two identical weighted subtrees, or
+// - the node is a ballot claiming more voting power than the voter's snapshot,
+//   from a voter who also voted on-chain, or from a voter for whom a ballot at
+//   another leaf or position was already proven.
+// Otherwise a proven ballot is recorded and false is returned. Proving the
+// same ballot at the same position again fails with AlreadyVoted.
+pub fn dispute_node(
+    env: &Env,
+    proposal_id: u32,
+    node: &MerkleNode,
+    opening: &MerkleOpening,
+    proof: &Vec<MerkleNode>,
+) -> Result<bool, DaoError> {
+    let tally = get_merkle_tally(env, proposal_id).ok_or(DaoError::MerkleTallyNotFound)?;
+    if env.ledger().timestamp() > tally.dispute_end {
+        return Err(DaoError::DisputeWindowClosed);
+    }
+
+    let root = compute_root(env, node.clone(), proof).ok_or(DaoError::InvalidMerkleProof)?;
+    if root.hash != tally.root {
+        return Err(DaoError::InvalidMerkleProof);
+    }
+
+    let opened = match opening {
+        MerkleOpening::Leaf(ballot) => Some(leaf_node(env, ballot)),
+        MerkleOpening::Branch(left, right) => hash_pair(env, left, right),
+    };
+    // Children whose weights overflow can never match their parent's hash
+    let opened = opened.ok_or(DaoError::InvalidMerkleProof)?;
+    if opened.hash != node.hash {
+        return Err(DaoError::InvalidMerkleProof);
+    }
+
+    let mut invalid = root.upvotes != tally.upvotes
+        || root.downvotes != tally.downvotes
+        || opened.upvotes != node.upvotes
+        || opened.downvotes != node.downvotes
+        || repeats_subtree(env, node, proof);
+    if let MerkleOpening::Branch(left, right) = opening {
+        invalid = invalid || repeats_subtree(env, left, &Vec::from_array(env, [right.clone()]));
+    }
+
+    let mut ballot_path = None;
+    if let MerkleOpening::Leaf(ballot) = opening {
+        let path = ballot_path_hash(env, &node.hash, proof);
+        let proven = get_merkle_voter(env, &tally.root, &ballot.voter);
+        if proven.as_ref() == Some(&path) {
+            return Err(DaoError::AlreadyVoted);
+        }
+        // Any other ballot proven for the same voter counts them twice
+        invalid = invalid || proven.is_some() || !is_valid_ballot(env, proposal_id, ballot);
+        ballot_path = Some(path);
+    }
+
+    if invalid {
+        remove_merkle_tally(env, proposal_id);
+        emit_tally_rejected(env, proposal_id, tally.root);
+        return Ok(true);
+    }
+
+    if let (MerkleOpening::Leaf(ballot), Some(path)) = (opening, ballot_path) {
+        save_merkle_voter(env, &tally.root, &ballot.voter, &path);
+        emit_ballot_verified(env, proposal_id, ballot.voter.clone());
+    }
+    Ok(false)
+}
+
+fn is_valid_ballot(env: &Env, proposal_id: u32, ballot: &MerkleBallot) -> bool {
+    let within_snapshot = get_power_snapshot(env, proposal_id, &ballot.voter)
+        .is_some_and(|voting_power| ballot.voting_power <= voting_power);
+
+    within_snapshot && !has_voted(env, proposal_id, &ballot.voter)
+}
+
+// Merge an undisputed tally into the proposal before it is finalized.
+// Fails while a tally can still be submitted or disputed.
+pub fn apply_accepted_tally(env: &Env, mut proposal: Proposal) -> Result<Proposal, DaoError> {
+    if !is_merkle_enabled(env, proposal.id) {
+        return Ok(proposal);
+    }
+
+    let now = env.ledger().timestamp();
+    match get_merkle_tally(env, proposal.id) {
+        Some(tally) => {
+            if now <= tally.dispute_end {
+                return Err(DaoError::DisputeWindowActive);
+            }
+            proposal.upvotes = proposal
+                .upvotes
+                .checked_add(tally.upvotes)
+                .ok_or(DaoError::ExecutionFailed)?;
+            proposal.downvotes = proposal
+                .downvotes
+                .checked_add(tally.downvotes)
+                .ok_or(DaoError::ExecutionFailed)?;
+        }
+        None => {
+            // No tally: wait out the submission deadline, then fall back to
+            // the on-chain votes only
+            let dispute_window = get_merkle_config(env)
+                .map(|config| config.dispute_window)
+                .unwrap_or(0);
+            if now <= proposal.end_time.saturating_add(dispute_window) {
+                return Err(DaoError::DisputeWindowActive);
+            }
+        }
+    }
+    Ok(proposal)
+}
+
@@ -1,12 +1,63 @@
+use crate::index_storage;
 use crate::types::*;
-use soroban_sdk::{Address, Env, Vec, String};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, String, TryFromVal, Val, Vec};
 use core::cmp::min;
 
+// Storage lifetime of persistent entries
+const DAY_IN_LEDGERS: u32 = 17280; // Assuming 5 seconds per ledger
+const PERSISTENT_LIFETIME_THRESHOLD: u32 = 90 * DAY_IN_LEDGERS;
+const PERSISTENT_BUMP_AMOUNT: u32 = 180 * DAY_IN_LEDGERS;
+
+// Maximum number of entries returned by a single paginated query
+const MAX_PAGE_SIZE: u32 = 50;
+
+// Maximum number of open proposals examined by a single status query or
+// finalize_expired call
+pub const MAX_STATUS_SCAN: u32 = 100;
+
 // Proposal Storage
 pub fn save_proposal(env: &Env, proposal: &Proposal) {
-    env.storage()
-        .instance()
-        .set(&DataKey::Proposal(proposal.id), proposal);
+    let key = DataKey::Proposal(proposal.id);
+    let previous: Option<Proposal> = env.storage().instance().get(&key);
+
+    // Keep the secondary indexes in sync. Type and proposer never change after
+    // creation, so only the status index needs updating on later saves.
+    match previous {
+        None => index_proposal(env, proposal),
+        Some(previous) => {
+            let previous_index = status_index(previous.status);
+            let index = status_index(proposal.status);
+            if previous_index != index {
+                index_storage::remove(env, &previous_index, proposal.id);
+                index_storage::insert(env, &index, proposal.id);
+            }
+        }
+    }
+
+    env.storage().instance().set(&key, proposal);
+}
+
+// Index a proposal under its status, type and proposer. Ids already present
+// are left untouched, so this is safe to repeat.
+pub fn index_proposal(env: &Env, proposal: &Proposal) {
+    index_storage::insert(env, &status_index(proposal.status), proposal.id);
+    index_storage::insert(env, &ProposalIndex::Type(proposal.proposal_type), proposal.id);
+    index_storage::insert(
+        env,
+        &ProposalIndex::Proposer(proposal.proposer.clone()),
+        proposal.id,
+    );
+}
+
+// Pending and Active proposals share the open index, since the clock alone
+// moves them between Pending, Active and Ended
+fn status_index(status: ProposalStatus) -> ProposalIndex {
+    match status {
+        ProposalStatus::Pending | ProposalStatus::Active | ProposalStatus::Ended => {
+            ProposalIndex::Open
+        }
+        status => ProposalIndex::Status(status),
+    }
 }
 
 pub fn get_proposal(env: &Env, id: u32) -> Result<Proposal, DaoError> {
@@ -16,12 +67,42 @@ pub fn get_proposal(env: &Env, id: u32) -> Result<Proposal, DaoError> {
         .ok_or(DaoError::ProposalNotFound)
 }
 
+// Status reported to readers. Proposals stay stored as Pending/Active until a
+// vote or finalization persists a change, so derive the status from the clock.
+pub fn effective_status(env: &Env, proposal: &Proposal) -> ProposalStatus {
+    match proposal.status {
+        ProposalStatus::Pending | ProposalStatus::Active => {
+            let now = env.ledger().timestamp();
+            if now < proposal.start_time {
+                ProposalStatus::Pending
+            } else if now > proposal.end_time {
+                ProposalStatus::Ended
+            } else {
+                ProposalStatus::Active
+            }
+        }
+        status => status,
+    }
+}
+
+pub fn with_effective_status(env: &Env, mut proposal: Proposal) -> Proposal {
+    proposal.status = effective_status(env, &proposal);
+    proposal
+}
+
+fn load_proposal_view(env: &Env, id: u32) -> Option<Proposal> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Proposal(id))
+        .map(|proposal| with_effective_status(env, proposal))
+}
+
 pub fn get_all_proposals(env: &Env) -> Vec<Proposal> {
     let count = get_proposal_count(env);
     let mut result = Vec::new(env);
 
     for i in 1..=count {
-        if let Some(proposal) = env.storage().instance().get(&DataKey::Proposal(i)) {
+        if let Some(proposal) = load_proposal_view(env, i) {
             result.push_back(proposal);
         }
     }
@@ -42,7 +123,7 @@ pub fn get_proposals_paginated(env: &Env, page: u32, page_size: u32) -> Vec<Prop
     let actual_page_size = min(page_size, max_page_size);
     
     for i in start_index..=end_index {
-        if let Some(proposal) = env.storage().instance().get(&DataKey::Proposal(i)) {
+        if let Some(proposal) = load_proposal_view(env, i) {
             result.push_back(proposal);
         }
         
@@ -55,6 +136,82 @@ pub fn get_proposals_paginated(env: &Env, page: u32, page_size: u32) -> Vec<Prop
     result
 }
 
+fn get_indexed_proposals(env: &Env, index: &ProposalIndex, offset: u32, limit: u32) -> Vec<Proposal> {
+    let mut result = Vec::new(env);
+    for id in index_storage::get_range(env, index, offset, min(limit, MAX_PAGE_SIZE)).iter() {
+        if let Some(proposal) = load_proposal_view(env, id) {
+            result.push_back(proposal);
+        }
+    }
+    result
+}
+
+// Proposals in a given status with id >= `cursor`. Open statuses are derived
+// from the clock, so at most MAX_STATUS_SCAN open proposals are examined per
+// call and a page may hold fewer than `limit` entries while a cursor remains.
+pub fn get_proposals_by_status(
+    env: &Env,
+    status: ProposalStatus,
+    cursor: u32,
+    limit: u32,
+) -> ProposalPage {
+    let index = status_index(status);
+    let limit = min(limit, MAX_PAGE_SIZE);
+    let mut proposals = Vec::new(env);
+
+    if index != ProposalIndex::Open {
+        let ids = index_storage::get_from(env, &index, cursor, limit.saturating_add(1));
+        for id in ids.iter().take(limit as usize) {
+            if let Some(proposal) = load_proposal_view(env, id) {
+                proposals.push_back(proposal);
+            }
+        }
+        let next_cursor = if ids.len() > limit { ids.get(limit) } else { None };
+        return ProposalPage { proposals, next_cursor };
+    }
+
+    // Open proposals move between Pending, Active and Ended with time alone,
+    // so filter a bounded slice of the open index by effective status.
+    let ids = index_storage::get_from(env, &index, cursor, MAX_STATUS_SCAN.saturating_add(1));
+    let scan_len = min(ids.len(), MAX_STATUS_SCAN);
+    let mut scanned = 0;
+    while scanned < scan_len && proposals.len() < limit {
+        if let Some(proposal) = load_proposal_view(env, ids.get_unchecked(scanned)) {
+            if proposal.status == status {
+                proposals.push_back(proposal);
+            }
+        }
+        scanned += 1;
+    }
+    ProposalPage {
+        proposals,
+        next_cursor: ids.get(scanned),
+    }
+}
+
+pub fn get_proposals_by_type(
+    env: &Env,
+    proposal_type: ProposalType,
+    offset: u32,
+    limit: u32,
+) -> Vec<Proposal> {
+    get_indexed_proposals(env, &ProposalIndex::Type(proposal_type), offset, limit)
+}
+
+pub fn get_proposals_by_proposer(
+    env: &Env,
+    proposer: &Address,
+    offset: u32,
+    limit: u32,
+) -> Vec<Proposal> {
+    get_indexed_proposals(
+        env,
+        &ProposalIndex::Proposer(proposer.clone()),
+        offset,
+        limit,
+    )
+}
+
 // Get total number of proposals for pagination
 pub fn get_total_proposal_count(env: &Env) -> u32 {
     get_proposal_count(env)
@@ -130,14 +287,267 @@ pub fn get_vote(env: &Env, proposal_id: u32, voter: &Address) -> Option<Vote> {
         .get(&DataKey::Vote(proposal_id, voter.clone()))
 }
 
-#[allow(dead_code)]
-pub fn get_votes_for_proposal(env: &Env, proposal_id: u32) -> Vec<Vote> {
-    env.storage()
+pub fn get_votes_for_proposal(env: &Env, proposal_id: u32, offset: u32, limit: u32) -> Vec<Vote> {
+    let votes: Vec<Vote> = env
+        .storage()
         .instance()
         .get(&DataKey::ProposalVotes(proposal_id))
+        .unwrap_or_else(|| Vec::new(env));
+
+    let start = min(offset, votes.len());
+    let end = min(start.saturating_add(min(limit, MAX_PAGE_SIZE)), votes.len());
+    votes.slice(start..end)
+}
+
+// Amendment history and comments are append-only lists kept in persistent
+// storage, split into fixed-size pages per proposal so a busy proposal never
+// grows a single ledger entry or the instance storage loaded on every call.
+const HISTORY_PAGE_SIZE: u32 = 5;
+const COMMENT_PAGE_SIZE: u32 = 50;
+
+fn get_list_len(env: &Env, key: &DataKey) -> u32 {
+    env.storage().persistent().get(key).unwrap_or(0)
+}
+
+// Append an item at position `len` of a paged list and store the new length
+fn push_paged<T, F>(env: &Env, len_key: &DataKey, page_key: F, page_size: u32, item: &T) -> u32
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+    F: Fn(u32) -> DataKey,
+{
+    let len = get_list_len(env, len_key);
+    let key = page_key(len / page_size);
+    let mut items: Vec<T> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    items.push_back(item.clone());
+    env.storage().persistent().set(&key, &items);
+    bump_persistent(env, &key);
+
+    env.storage().persistent().set(len_key, &(len + 1));
+    bump_persistent(env, len_key);
+    len + 1
+}
+
+// Up to `limit` items of a paged list from position `offset`, reading only
+// the pages that overlap the requested range
+fn get_paged<T, F>(env: &Env, len_key: &DataKey, page_key: F, page_size: u32, offset: u32, limit: u32) -> Vec<T>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+    F: Fn(u32) -> DataKey,
+{
+    let mut result = Vec::new(env);
+    let end = min(offset.saturating_add(min(limit, MAX_PAGE_SIZE)), get_list_len(env, len_key));
+    let mut position = offset;
+    while position < end {
+        let page = position / page_size;
+        let items: Vec<T> = env
+            .storage()
+            .persistent()
+            .get(&page_key(page))
+            .unwrap_or_else(|| Vec::new(env));
+        let page_end = min(end, (page + 1) * page_size);
+        result.append(&items.slice(position - page * page_size..page_end - page * page_size));
+        position = page_end;
+    }
+    result
+}
+
+// Amendment History Storage
+pub fn get_history_len(env: &Env, proposal_id: u32) -> u32 {
+    get_list_len(env, &DataKey::ProposalHistoryLen(proposal_id))
+}
+
+pub fn push_proposal_version(env: &Env, proposal_id: u32, version: &ProposalVersion) -> u32 {
+    push_paged(
+        env,
+        &DataKey::ProposalHistoryLen(proposal_id),
+        |page| DataKey::ProposalHistory(proposal_id, page),
+        HISTORY_PAGE_SIZE,
+        version,
+    )
+}
+
+pub fn get_proposal_history(env: &Env, proposal_id: u32, offset: u32, limit: u32) -> Vec<ProposalVersion> {
+    get_paged(
+        env,
+        &DataKey::ProposalHistoryLen(proposal_id),
+        |page| DataKey::ProposalHistory(proposal_id, page),
+        HISTORY_PAGE_SIZE,
+        offset,
+        limit,
+    )
+}
+
+// Comment Storage
+pub fn get_comment_count(env: &Env, proposal_id: u32) -> u32 {
+    get_list_len(env, &DataKey::ProposalCommentCount(proposal_id))
+}
+
+pub fn push_comment(env: &Env, proposal_id: u32, comment: &Comment) -> u32 {
+    push_paged(
+        env,
+        &DataKey::ProposalCommentCount(proposal_id),
+        |page| DataKey::ProposalComments(proposal_id, page),
+        COMMENT_PAGE_SIZE,
+        comment,
+    )
+}
+
+pub fn get_comments(env: &Env, proposal_id: u32, offset: u32, limit: u32) -> Vec<Comment> {
+    get_paged(
+        env,
+        &DataKey::ProposalCommentCount(proposal_id),
+        |page| DataKey::ProposalComments(proposal_id, page),
+        COMMENT_PAGE_SIZE,
+        offset,
+        limit,
+    )
+}
+
+pub fn vote_count(env: &Env, proposal_id: u32) -> u32 {
+    env.storage()
+        .instance()
+        .get::<_, Vec<Vote>>(&DataKey::ProposalVotes(proposal_id))
+        .map(|votes| votes.len())
+        .unwrap_or(0)
+}
+
+// Treasury Storage
+pub fn get_treasury_tokens(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TreasuryTokens)
         .unwrap_or_else(|| Vec::new(env))
 }
 
+pub fn get_treasury_token_config(env: &Env, token: &Address) -> Option<TreasuryTokenConfig> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TreasuryToken(token.clone()))
+}
+
+pub fn save_treasury_token_config(env: &Env, token: &Address, config: &TreasuryTokenConfig) {
+    if get_treasury_token_config(env, token).is_none() {
+        let mut tokens = get_treasury_tokens(env);
+        tokens.push_back(token.clone());
+        env.storage().instance().set(&DataKey::TreasuryTokens, &tokens);
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::TreasuryToken(token.clone()), config);
+}
+
+pub fn remove_treasury_token_config(env: &Env, token: &Address) {
+    let mut tokens = get_treasury_tokens(env);
+    if let Some(pos) = tokens.first_index_of(token) {
+        tokens.remove(pos);
+        env.storage().instance().set(&DataKey::TreasuryTokens, &tokens);
+    }
+    env.storage()
+        .instance()
+        .remove(&DataKey::TreasuryToken(token.clone()));
+}
+
+pub fn get_spending_window(env: &Env, token: &Address) -> SpendingWindow {
+    env.storage()
+        .instance()
+        .get(&DataKey::TreasuryWindow(token.clone()))
+        .unwrap_or(SpendingWindow { start: 0, spent: 0 })
+}
+
+pub fn save_spending_window(env: &Env, token: &Address, window: &SpendingWindow) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TreasuryWindow(token.clone()), window);
+}
+
+pub fn get_funding_request(env: &Env, proposal_id: u32) -> Option<FundingRequest> {
+    env.storage()
+        .instance()
+        .get(&DataKey::FundingRequest(proposal_id))
+}
+
+pub fn save_funding_request(env: &Env, proposal_id: u32, request: &FundingRequest) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FundingRequest(proposal_id), request);
+}
+
+// Merkle Vote Storage
+pub fn get_merkle_config(env: &Env) -> Option<MerkleConfig> {
+    env.storage().instance().get(&DataKey::MerkleConfig)
+}
+
+pub fn save_merkle_config(env: &Env, config: &MerkleConfig) {
+    env.storage().instance().set(&DataKey::MerkleConfig, config);
+}
+
+pub fn is_merkle_enabled(env: &Env, proposal_id: u32) -> bool {
+    env.storage()
+        .instance()
+        .has(&DataKey::MerkleMode(proposal_id))
+}
+
+pub fn set_merkle_enabled(env: &Env, proposal_id: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MerkleMode(proposal_id), &true);
+}
+
+pub fn get_merkle_tally(env: &Env, proposal_id: u32) -> Option<MerkleTally> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MerkleTally(proposal_id))
+}
+
+pub fn save_merkle_tally(env: &Env, proposal_id: u32, tally: &MerkleTally) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MerkleTally(proposal_id), tally);
+}
+
+pub fn remove_merkle_tally(env: &Env, proposal_id: u32) {
+    env.storage()
+        .instance()
+        .remove(&DataKey::MerkleTally(proposal_id));
+}
+
+// Leaf and position of the ballot already verified for a voter under a given
+// root. Kept in persistent storage since it grows with the electorate.
+pub fn get_merkle_voter(env: &Env, root: &BytesN<32>, voter: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MerkleVoter(root.clone(), voter.clone()))
+}
+
+pub fn save_merkle_voter(env: &Env, root: &BytesN<32>, voter: &Address, ballot_path: &BytesN<32>) {
+    let key = DataKey::MerkleVoter(root.clone(), voter.clone());
+    env.storage().persistent().set(&key, ballot_path);
+    bump_persistent(env, &key);
+}
+
+// Voting power of a voter fixed for a proposal while its voting was open
+pub fn get_power_snapshot(env: &Env, proposal_id: u32, voter: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PowerSnapshot(proposal_id, voter.clone()))
+}
+
+pub fn save_power_snapshot(env: &Env, proposal_id: u32, voter: &Address, voting_power: u64) {
+    let key = DataKey::PowerSnapshot(proposal_id, voter.clone());
+    env.storage().persistent().set(&key, &voting_power);
+    bump_persistent(env, &key);
+}
+
+pub fn bump_persistent(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
 // DAO Config Storage
 pub fn save_config(env: &Env, config: &DaoConfig) {
     env.storage().instance().set(&DataKey::Config, config);
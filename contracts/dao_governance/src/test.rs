@@ -3,9 +3,11 @@
 use super::*;
 use soroban_sdk::{
     Address, Env,
-    testutils::{Address as _, Ledger},
+    testutils::{Address as _, Events, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    symbol_short, Symbol, TryFromVal,
 };
-use types::ProposalStatus;
+use types::{ProposalIndex, ProposalStatus};
 
 fn with_contract<R, F>(env: &Env, contract_id: &Address, f: F) -> R
 where
@@ -803,3 +805,1385 @@ fn test_execute_already_executed_proposal() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_get_proposals_by_status() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+
+    // Create a short and a long running proposal
+    let short_id = with_contract(&env, &contract_id, || {
+        DaoContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            String::from_str(&env, "Short Proposal"),
+            String::from_str(&env, "Ends first"),
+            ProposalType::Funding,
+            3600,
+            50,
+            60,
+        )
+    }).unwrap();
+
+    let long_id = with_contract(&env, &contract_id, || {
+        DaoContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            String::from_str(&env, "Long Proposal"),
+            String::from_str(&env, "Ends later"),
+            ProposalType::Policy,
+            7200,
+            50,
+            60,
+        )
+    }).unwrap();
+
+    let active = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Active, 0, 10)
+    }).proposals;
+    assert_eq!(active.len(), 2);
+
+    // Finalize the short proposal without votes so it is rejected
+    env.ledger().with_mut(|l| {
+        l.timestamp = 3601;
+    });
+    with_contract(&env, &contract_id, || {
+        DaoContract::finalize_proposal(env.clone(), admin.clone(), short_id)
+    }).unwrap();
+
+    let active = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Active, 0, 10)
+    }).proposals;
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().id, long_id);
+
+    let rejected = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Rejected, 0, 10)
+    }).proposals;
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected.get(0).unwrap().id, short_id);
+
+    let passed = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Passed, 0, 10)
+    }).proposals;
+    assert_eq!(passed.len(), 0);
+}
+
+#[test]
+fn test_get_proposals_by_status_cursor() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+    let params = ProposalParams {
+        title: String::from_str(&env, "Proposal"),
+        description: String::from_str(&env, "Description"),
+        proposal_type: ProposalType::Other,
+        voting_period: 7200,
+        minimum_quorum: 50,
+        minimum_approval: 60,
+    };
+
+    // One scheduled proposal followed by two that are open right away
+    with_contract(&env, &contract_id, || {
+        DaoContract::create_scheduled_proposal(env.clone(), proposer.clone(), params.clone(), 1000)
+    }).unwrap();
+    for _ in 0..2 {
+        with_contract(&env, &contract_id, || {
+            DaoContract::create_scheduled_proposal(env.clone(), proposer.clone(), params.clone(), 0)
+        }).unwrap();
+    }
+
+    let page = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Active, 0, 1)
+    });
+    assert_eq!(page.proposals.len(), 1);
+    assert_eq!(page.proposals.get(0).unwrap().id, 2);
+    assert_eq!(page.next_cursor, Some(3));
+
+    let page = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Active, 3, 1)
+    });
+    assert_eq!(page.proposals.len(), 1);
+    assert_eq!(page.proposals.get(0).unwrap().id, 3);
+    assert_eq!(page.next_cursor, None);
+
+    let page = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Pending, 0, 1)
+    });
+    assert_eq!(page.proposals.len(), 1);
+    assert_eq!(page.proposals.get(0).unwrap().id, 1);
+    assert_eq!(page.next_cursor, Some(2));
+
+    let page = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Pending, 2, 10)
+    });
+    assert_eq!(page.proposals.len(), 0);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn test_get_proposals_by_type_and_proposer() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer1 = create_address(&env, "proposer1");
+    let proposer2 = create_address(&env, "proposer2");
+
+    let proposals = [
+        (proposer1.clone(), ProposalType::Funding),
+        (proposer2.clone(), ProposalType::Funding),
+        (proposer1.clone(), ProposalType::Feature),
+        (proposer1.clone(), ProposalType::Funding),
+    ];
+    for (proposer, proposal_type) in proposals.iter() {
+        with_contract(&env, &contract_id, || {
+            DaoContract::create_proposal(
+                env.clone(),
+                proposer.clone(),
+                String::from_str(&env, "Proposal"),
+                String::from_str(&env, "Description"),
+                *proposal_type,
+                7200,
+                50,
+                60,
+            )
+        }).unwrap();
+    }
+
+    let funding = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_type(env.clone(), ProposalType::Funding, 0, 10)
+    });
+    assert_eq!(funding.len(), 3);
+    assert_eq!(funding.get(0).unwrap().id, 1);
+    assert_eq!(funding.get(1).unwrap().id, 2);
+    assert_eq!(funding.get(2).unwrap().id, 4);
+
+    // Pagination over the type index
+    let funding_page = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_type(env.clone(), ProposalType::Funding, 1, 1)
+    });
+    assert_eq!(funding_page.len(), 1);
+    assert_eq!(funding_page.get(0).unwrap().id, 2);
+
+    let by_proposer1 = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_proposer(env.clone(), proposer1.clone(), 0, 10)
+    });
+    assert_eq!(by_proposer1.len(), 3);
+    assert!(by_proposer1.iter().all(|p| p.proposer == proposer1));
+
+    let by_proposer2 = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_proposer(env.clone(), proposer2.clone(), 0, 10)
+    });
+    assert_eq!(by_proposer2.len(), 1);
+
+    // Offset past the end returns an empty page
+    let empty = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_proposer(env.clone(), proposer1.clone(), 5, 10)
+    });
+    assert_eq!(empty.len(), 0);
+}
+
+#[test]
+fn test_proposal_indexes_span_pages() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let index = ProposalIndex::Type(ProposalType::Policy);
+    let page_size = index_storage::INDEX_PAGE_SIZE;
+
+    with_contract(&env, &contract_id, || {
+        // Ids land on their id-range page and are kept sorted
+        for id in [page_size * 2 + 5, 7, page_size + 1, page_size - 1, page_size] {
+            assert!(index_storage::insert(&env, &index, id));
+        }
+        assert!(!index_storage::insert(&env, &index, 7));
+        assert_eq!(index_storage::pages(&env, &index).len(), 3);
+
+        let range = index_storage::get_range(&env, &index, 1, 3);
+        assert_eq!(range.len(), 3);
+        assert_eq!(range.get(0).unwrap(), page_size - 1);
+        assert_eq!(range.get(1).unwrap(), page_size);
+        assert_eq!(range.get(2).unwrap(), page_size + 1);
+
+        // Emptied pages are dropped from the index
+        assert!(index_storage::remove(&env, &index, page_size));
+        assert!(index_storage::remove(&env, &index, page_size + 1));
+        assert!(!index_storage::remove(&env, &index, page_size + 1));
+        let pages = index_storage::pages(&env, &index);
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages.get(1).unwrap(), 2);
+        assert_eq!(index_storage::get_range(&env, &index, 2, 10).len(), 1);
+    });
+}
+
+#[test]
+fn test_backfill_proposal_indexes() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+
+    // Proposals stored before the indexes existed
+    with_contract(&env, &contract_id, || {
+        for id in 1..=3 {
+            let proposal = Proposal {
+                id,
+                title: String::from_str(&env, "Legacy Proposal"),
+                description: String::from_str(&env, "Created before indexing"),
+                proposal_type: ProposalType::Feature,
+                proposer: proposer.clone(),
+                start_time: 0,
+                end_time: 7200,
+                status: ProposalStatus::Active,
+                upvotes: 0,
+                downvotes: 0,
+                minimum_quorum: 50,
+                minimum_approval: 60,
+                executed: false,
+            };
+            env.storage().instance().set(&DataKey::Proposal(id), &proposal);
+        }
+        env.storage().instance().set(&DataKey::ProposalCount, &3u32);
+    });
+
+    let by_proposer = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_proposer(env.clone(), proposer.clone(), 0, 10)
+    });
+    assert_eq!(by_proposer.len(), 0);
+
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::backfill_proposal_indexes(env.clone(), proposer.clone(), 1, 10)
+    });
+    assert_eq!(result, Err(DaoError::Unauthorized));
+
+    let next = with_contract(&env, &contract_id, || {
+        DaoContract::backfill_proposal_indexes(env.clone(), admin.clone(), 1, 2)
+    }).unwrap();
+    assert_eq!(next, 3);
+    let next = with_contract(&env, &contract_id, || {
+        DaoContract::backfill_proposal_indexes(env.clone(), admin.clone(), next, 10)
+    }).unwrap();
+    assert_eq!(next, 4);
+
+    // Repeating the backfill does not duplicate entries
+    with_contract(&env, &contract_id, || {
+        DaoContract::backfill_proposal_indexes(env.clone(), admin.clone(), 1, 10)
+    }).unwrap();
+
+    let by_proposer = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_proposer(env.clone(), proposer.clone(), 0, 10)
+    });
+    assert_eq!(by_proposer.len(), 3);
+    let by_type = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_type(env.clone(), ProposalType::Feature, 0, 10)
+    });
+    assert_eq!(by_type.len(), 3);
+    let active = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Active, 0, 10)
+    }).proposals;
+    assert_eq!(active.len(), 3);
+}
+
+#[test]
+fn test_get_votes_for_proposal() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+    let voter1 = create_address(&env, "voter1");
+    let voter2 = create_address(&env, "voter2");
+    let voter3 = create_address(&env, "voter3");
+
+    let proposal_id = with_contract(&env, &contract_id, || {
+        DaoContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            String::from_str(&env, "Test Proposal"),
+            String::from_str(&env, "This is a test proposal"),
+            ProposalType::Funding,
+            7200,
+            50,
+            60,
+        )
+    }).unwrap();
+
+    for voter in [voter1.clone(), voter2.clone(), voter3.clone()].iter() {
+        with_contract(&env, &contract_id, || {
+            DaoContract::cast_vote(env.clone(), voter.clone(), proposal_id, VoteType::Upvote)
+        }).unwrap();
+    }
+
+    let first_page = with_contract(&env, &contract_id, || {
+        DaoContract::get_votes_for_proposal(env.clone(), proposal_id, 0, 2)
+    }).unwrap();
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().voter, voter1);
+    assert_eq!(first_page.get(1).unwrap().voter, voter2);
+
+    let second_page = with_contract(&env, &contract_id, || {
+        DaoContract::get_votes_for_proposal(env.clone(), proposal_id, 2, 2)
+    }).unwrap();
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().voter, voter3);
+
+    // Unknown proposals are reported as errors
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::get_votes_for_proposal(env.clone(), 999, 0, 10)
+    });
+    assert_eq!(result, Err(DaoError::ProposalNotFound));
+}
+
+#[test]
+fn test_scheduled_proposal_lifecycle() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+    let voter = create_address(&env, "voter");
+
+    // Voting opens at t=1000 and runs for 2 hours
+    let proposal_id = with_contract(&env, &contract_id, || {
+        DaoContract::create_scheduled_proposal(
+            env.clone(),
+            proposer.clone(),
+            ProposalParams {
+                title: String::from_str(&env, "Scheduled Proposal"),
+                description: String::from_str(&env, "Starts later"),
+                proposal_type: ProposalType::Policy,
+                voting_period: 7200,
+                minimum_quorum: 50,
+                minimum_approval: 60,
+            },
+            1000,
+        )
+    }).unwrap();
+
+    let proposal = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposal(env.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Pending);
+    assert_eq!(proposal.start_time, 1000);
+    assert_eq!(proposal.end_time, 8200);
+
+    // Voting before the start time is rejected
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::cast_vote(env.clone(), voter.clone(), proposal_id, VoteType::Upvote)
+    });
+    assert_eq!(result, Err(DaoError::VotingNotStarted));
+
+    // Once the start time is reached the proposal reads as Active
+    env.ledger().with_mut(|l| {
+        l.timestamp = 1000;
+    });
+    let proposal = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposal(env.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Active);
+
+    let active = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Active, 0, 10)
+    }).proposals;
+    assert_eq!(active.len(), 1);
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::cast_vote(env.clone(), voter.clone(), proposal_id, VoteType::Upvote)
+    }).unwrap();
+
+    // After the voting period it is reported as ended until finalized
+    env.ledger().with_mut(|l| {
+        l.timestamp = 8201;
+    });
+    let proposal = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposal(env.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Ended);
+
+    let ended = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Ended, 0, 10)
+    }).proposals;
+    assert_eq!(ended.len(), 1);
+
+    let finalized = with_contract(&env, &contract_id, || {
+        DaoContract::finalize_expired(env.clone(), 0, 10)
+    }).finalized;
+    assert_eq!(finalized, 1);
+
+    let proposal = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposal(env.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+}
+
+#[test]
+fn test_create_scheduled_proposal_in_the_past() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 5000;
+    });
+
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::create_scheduled_proposal(
+            env.clone(),
+            proposer.clone(),
+            ProposalParams {
+                title: String::from_str(&env, "Scheduled Proposal"),
+                description: String::from_str(&env, "Starts in the past"),
+                proposal_type: ProposalType::Policy,
+                voting_period: 7200,
+                minimum_quorum: 50,
+                minimum_approval: 60,
+            },
+            4000,
+        )
+    });
+    assert_eq!(result, Err(DaoError::InvalidTimestamp));
+}
+
+#[test]
+fn test_finalize_expired_respects_limit() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+
+    for _ in 0..3 {
+        with_contract(&env, &contract_id, || {
+            DaoContract::create_proposal(
+                env.clone(),
+                proposer.clone(),
+                String::from_str(&env, "Proposal"),
+                String::from_str(&env, "Description"),
+                ProposalType::Other,
+                7200,
+                50,
+                60,
+            )
+        }).unwrap();
+    }
+
+    // Nothing has expired yet
+    let finalized = with_contract(&env, &contract_id, || {
+        DaoContract::finalize_expired(env.clone(), 0, 10)
+    }).finalized;
+    assert_eq!(finalized, 0);
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+
+    let finalized = with_contract(&env, &contract_id, || {
+        DaoContract::finalize_expired(env.clone(), 0, 2)
+    }).finalized;
+    assert_eq!(finalized, 2);
+
+    let finalized = with_contract(&env, &contract_id, || {
+        DaoContract::finalize_expired(env.clone(), 0, 10)
+    }).finalized;
+    assert_eq!(finalized, 1);
+
+    let rejected = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposals_by_status(env.clone(), ProposalStatus::Rejected, 0, 10)
+    }).proposals;
+    assert_eq!(rejected.len(), 3);
+}
+
+#[test]
+fn test_finalize_expired_scan_is_capped() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+
+    // Fill the open index with ids whose proposals no longer load; each one
+    // still counts towards the scan
+    with_contract(&env, &contract_id, || {
+        for id in 1..=storage::MAX_STATUS_SCAN {
+            index_storage::insert(&env, &ProposalIndex::Open, id);
+        }
+        env.storage().instance().set(&DataKey::ProposalCount, &storage::MAX_STATUS_SCAN);
+    });
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            String::from_str(&env, "Proposal"),
+            String::from_str(&env, "Description"),
+            ProposalType::Other,
+            7200,
+            50,
+            60,
+        )
+    }).unwrap();
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+
+    // The first call stops after MAX_STATUS_SCAN open proposals
+    let batch = with_contract(&env, &contract_id, || {
+        DaoContract::finalize_expired(env.clone(), 0, 10)
+    });
+    assert_eq!(batch.finalized, 0);
+    assert_eq!(batch.next_cursor, Some(storage::MAX_STATUS_SCAN + 1));
+
+    let batch = with_contract(&env, &contract_id, || {
+        DaoContract::finalize_expired(env.clone(), batch.next_cursor.unwrap(), 10)
+    });
+    assert_eq!(batch.finalized, 1);
+    assert_eq!(batch.next_cursor, None);
+}
+
+#[test]
+fn test_amend_proposal_before_votes() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+    let other = create_address(&env, "other");
+    let voter = create_address(&env, "voter");
+
+    let proposal_id = with_contract(&env, &contract_id, || {
+        DaoContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            String::from_str(&env, "Original Title"),
+            String::from_str(&env, "Original description"),
+            ProposalType::Feature,
+            7200,
+            50,
+            60,
+        )
+    }).unwrap();
+
+    // Only the proposer may amend
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::amend_proposal(
+            env.clone(),
+            other.clone(),
+            proposal_id,
+            String::from_str(&env, "Hijacked"),
+            String::from_str(&env, "Hijacked"),
+        )
+    });
+    assert_eq!(result, Err(DaoError::Unauthorized));
+
+    let version = with_contract(&env, &contract_id, || {
+        DaoContract::amend_proposal(
+            env.clone(),
+            proposer.clone(),
+            proposal_id,
+            String::from_str(&env, "Amended Title"),
+            String::from_str(&env, "Amended description"),
+        )
+    }).unwrap();
+    assert_eq!(version, 2);
+
+    let proposal = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposal(env.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(proposal.title, String::from_str(&env, "Amended Title"));
+    assert_eq!(proposal.description, String::from_str(&env, "Amended description"));
+
+    let history = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposal_history(env.clone(), proposal_id, 0, 10)
+    }).unwrap();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().version, 1);
+    assert_eq!(history.get(0).unwrap().title, String::from_str(&env, "Original Title"));
+
+    // Once a vote is cast the proposal is frozen
+    with_contract(&env, &contract_id, || {
+        DaoContract::cast_vote(env.clone(), voter.clone(), proposal_id, VoteType::Upvote)
+    }).unwrap();
+
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::amend_proposal(
+            env.clone(),
+            proposer.clone(),
+            proposal_id,
+            String::from_str(&env, "Too Late"),
+            String::from_str(&env, "Too late"),
+        )
+    });
+    assert_eq!(result, Err(DaoError::AmendmentNotAllowed));
+}
+
+#[test]
+fn test_proposal_comments() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+    let member = create_address(&env, "member");
+
+    let proposal_id = with_contract(&env, &contract_id, || {
+        DaoContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            String::from_str(&env, "Test Proposal"),
+            String::from_str(&env, "This is a test proposal"),
+            ProposalType::Funding,
+            7200,
+            50,
+            60,
+        )
+    }).unwrap();
+
+    for i in 0..3 {
+        let comment_id = with_contract(&env, &contract_id, || {
+            DaoContract::add_comment(
+                env.clone(),
+                member.clone(),
+                proposal_id,
+                String::from_str(&env, "ipfs://QmDiscussionEntry"),
+            )
+        }).unwrap();
+        assert_eq!(comment_id, i + 1);
+    }
+
+    let page = with_contract(&env, &contract_id, || {
+        DaoContract::get_comments(env.clone(), proposal_id, 1, 5)
+    }).unwrap();
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().id, 2);
+    assert_eq!(page.get(0).unwrap().author, member);
+
+    // Entries longer than the limit are rejected
+    let long_content = String::from_bytes(&env, &[b'a'; 257]);
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::add_comment(env.clone(), member.clone(), proposal_id, long_content.clone())
+    });
+    assert_eq!(result, Err(DaoError::CommentTooLong));
+
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::add_comment(
+            env.clone(),
+            member.clone(),
+            999,
+            String::from_str(&env, "ipfs://QmDiscussionEntry"),
+        )
+    });
+    assert_eq!(result, Err(DaoError::ProposalNotFound));
+}
+
+#[test]
+fn test_comments_paged_in_persistent_storage() {
+    let env = Env::default();
+    let (contract_id, _admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let proposer = create_address(&env, "proposer");
+    let member = create_address(&env, "member");
+
+    let proposal_id = with_contract(&env, &contract_id, || {
+        DaoContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            String::from_str(&env, "Test Proposal"),
+            String::from_str(&env, "This is a test proposal"),
+            ProposalType::Feature,
+            7200,
+            50,
+            60,
+        )
+    }).unwrap();
+
+    for _ in 0..52 {
+        with_contract(&env, &contract_id, || {
+            DaoContract::add_comment(
+                env.clone(),
+                member.clone(),
+                proposal_id,
+                String::from_str(&env, "ipfs://QmDiscussionEntry"),
+            )
+        }).unwrap();
+    }
+
+    // Entries live in persistent pages, not in instance storage
+    with_contract(&env, &contract_id, || {
+        let first: Vec<Comment> = env.storage().persistent().get(&DataKey::ProposalComments(proposal_id, 0)).unwrap();
+        let second: Vec<Comment> = env.storage().persistent().get(&DataKey::ProposalComments(proposal_id, 1)).unwrap();
+        assert_eq!(first.len(), 50);
+        assert_eq!(second.len(), 2);
+        assert!(!env.storage().instance().has(&DataKey::ProposalComments(proposal_id, 0)));
+    });
+
+    // A page crossing the page boundary keeps the entry order
+    let page = with_contract(&env, &contract_id, || {
+        DaoContract::get_comments(env.clone(), proposal_id, 48, 10)
+    }).unwrap();
+    assert_eq!(page.len(), 4);
+    for (i, comment) in page.iter().enumerate() {
+        assert_eq!(comment.id, 49 + i as u32);
+    }
+}
+
+fn create_token<'a>(env: &Env) -> (Address, StellarAssetClient<'a>, TokenClient<'a>) {
+    let token_admin = create_address(env, "token_admin");
+    let token = env.register_stellar_asset_contract_v2(token_admin).address();
+    (
+        token.clone(),
+        StellarAssetClient::new(env, &token),
+        TokenClient::new(env, &token),
+    )
+}
+
+fn funding_params(env: &Env, proposal_type: ProposalType) -> ProposalParams {
+    ProposalParams {
+        title: String::from_str(env, "Fund Project"),
+        description: String::from_str(env, "Pay the project team"),
+        proposal_type,
+        voting_period: 7200,
+        minimum_quorum: 50,
+        minimum_approval: 60,
+    }
+}
+
+// Creates a Funding proposal, passes it and moves time past the execution delay
+fn pass_funding_proposal(
+    env: &Env,
+    contract_id: &Address,
+    admin: &Address,
+    funding: FundingRequest,
+) -> Result<u32, DaoError> {
+    let proposer = create_address(env, "proposer");
+    let voter = create_address(env, "voter");
+    let start = env.ledger().timestamp();
+
+    let proposal_id = with_contract(env, contract_id, || {
+        DaoContract::create_funding_proposal(
+            env.clone(),
+            proposer.clone(),
+            funding_params(env, ProposalType::Funding),
+            funding,
+        )
+    })?;
+
+    with_contract(env, contract_id, || {
+        DaoContract::cast_vote(env.clone(), voter.clone(), proposal_id, VoteType::Upvote)
+    })?;
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = start + 7201;
+    });
+    with_contract(env, contract_id, || {
+        DaoContract::finalize_proposal(env.clone(), admin.clone(), proposal_id)
+    })?;
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = start + 7201 + 86400 + 1;
+    });
+    Ok(proposal_id)
+}
+
+#[test]
+fn test_treasury_deposit_and_balance() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let (token, token_admin_client, token_client) = create_token(&env);
+    let donor = create_address(&env, "donor");
+    token_admin_client.mint(&donor, &1_000);
+
+    // Deposits of tokens that are not whitelisted are refused
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::deposit(env.clone(), donor.clone(), token.clone(), 100)
+    });
+    assert_eq!(result, Err(DaoError::TokenNotWhitelisted));
+
+    // Only the admin may whitelist tokens
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::set_treasury_token(env.clone(), donor.clone(), token.clone(), 500, 86400)
+    });
+    assert_eq!(result, Err(DaoError::Unauthorized));
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::set_treasury_token(env.clone(), admin.clone(), token.clone(), 500, 86400)
+    }).unwrap();
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::deposit(env.clone(), donor.clone(), token.clone(), 400)
+    }).unwrap();
+
+    let balance = with_contract(&env, &contract_id, || {
+        DaoContract::get_treasury_balance(env.clone(), token.clone())
+    });
+    assert_eq!(balance, 400);
+    assert_eq!(token_client.balance(&donor), 600);
+
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::deposit(env.clone(), donor.clone(), token.clone(), 0)
+    });
+    assert_eq!(result, Err(DaoError::InvalidAmount));
+
+    let tokens = with_contract(&env, &contract_id, || {
+        DaoContract::get_treasury_tokens(env.clone())
+    });
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(tokens.get(0).unwrap(), token);
+}
+
+#[test]
+fn test_treasury_disbursement_on_execution() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let (token, token_admin_client, token_client) = create_token(&env);
+    let donor = create_address(&env, "donor");
+    let recipient = create_address(&env, "recipient");
+    token_admin_client.mint(&donor, &1_000);
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::set_treasury_token(env.clone(), admin.clone(), token.clone(), 500, 86400 * 30)
+    }).unwrap();
+    with_contract(&env, &contract_id, || {
+        DaoContract::deposit(env.clone(), donor.clone(), token.clone(), 1_000)
+    }).unwrap();
+
+    let funding = FundingRequest {
+        recipient: recipient.clone(),
+        token: token.clone(),
+        amount: 300,
+    };
+    let proposal_id = pass_funding_proposal(&env, &contract_id, &admin, funding).unwrap();
+
+    let stored = with_contract(&env, &contract_id, || {
+        DaoContract::get_funding_request(env.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(stored.amount, 300);
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::execute_proposal(env.clone(), admin.clone(), proposal_id)
+    }).unwrap();
+
+    assert_eq!(token_client.balance(&recipient), 300);
+    let balance = with_contract(&env, &contract_id, || {
+        DaoContract::get_treasury_balance(env.clone(), token.clone())
+    });
+    assert_eq!(balance, 700);
+
+    // A second payout in the same period would exceed the 500 limit
+    let funding = FundingRequest {
+        recipient: recipient.clone(),
+        token: token.clone(),
+        amount: 300,
+    };
+    let proposal_id = pass_funding_proposal(&env, &contract_id, &admin, funding).unwrap();
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::execute_proposal(env.clone(), admin.clone(), proposal_id)
+    });
+    assert_eq!(result, Err(DaoError::SpendingLimitExceeded));
+    assert_eq!(token_client.balance(&recipient), 300);
+
+    // Once the period rolls over the payout goes through
+    env.ledger().with_mut(|l| {
+        l.timestamp += 86400 * 30;
+    });
+    with_contract(&env, &contract_id, || {
+        DaoContract::execute_proposal(env.clone(), admin.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(token_client.balance(&recipient), 600);
+}
+
+#[test]
+fn test_funding_proposal_validation() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let (token, _token_admin_client, _token_client) = create_token(&env);
+    let recipient = create_address(&env, "recipient");
+
+    // Token not whitelisted
+    let funding = FundingRequest {
+        recipient: recipient.clone(),
+        token: token.clone(),
+        amount: 100,
+    };
+    let result = pass_funding_proposal(&env, &contract_id, &admin, funding);
+    assert_eq!(result, Err(DaoError::TokenNotWhitelisted));
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::set_treasury_token(env.clone(), admin.clone(), token.clone(), 500, 86400)
+    }).unwrap();
+
+    // Amount above the per-period limit can never be paid out
+    let funding = FundingRequest {
+        recipient: recipient.clone(),
+        token: token.clone(),
+        amount: 501,
+    };
+    let result = pass_funding_proposal(&env, &contract_id, &admin, funding);
+    assert_eq!(result, Err(DaoError::SpendingLimitExceeded));
+
+    // The params must describe a Funding proposal
+    let funding = FundingRequest {
+        recipient: recipient.clone(),
+        token: token.clone(),
+        amount: 100,
+    };
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::create_funding_proposal(
+            env.clone(),
+            recipient.clone(),
+            funding_params(&env, ProposalType::Policy),
+            funding,
+        )
+    });
+    assert_eq!(result, Err(DaoError::ExecutionFailed));
+
+    // Passed proposal but an empty treasury
+    let funding = FundingRequest {
+        recipient: recipient.clone(),
+        token: token.clone(),
+        amount: 100,
+    };
+    let proposal_id = pass_funding_proposal(&env, &contract_id, &admin, funding).unwrap();
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::execute_proposal(env.clone(), admin.clone(), proposal_id)
+    });
+    assert_eq!(result, Err(DaoError::InsufficientTreasuryBalance));
+}
+
+#[test]
+fn test_funding_proposal_emits_single_created_event() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let (token, _token_admin_client, _token_client) = create_token(&env);
+    let proposer = create_address(&env, "proposer");
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::set_treasury_token(env.clone(), admin.clone(), token.clone(), 500, 86400)
+    }).unwrap();
+
+    let funding = FundingRequest {
+        recipient: proposer.clone(),
+        token: token.clone(),
+        amount: 100,
+    };
+    with_contract(&env, &contract_id, || {
+        DaoContract::create_funding_proposal(
+            env.clone(),
+            proposer.clone(),
+            funding_params(&env, ProposalType::Funding),
+            funding,
+        )
+    }).unwrap();
+
+    let created = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            Symbol::try_from_val(&env, &topics.get(0).unwrap())
+                .is_ok_and(|topic| topic == symbol_short!("PROP_CRT"))
+        })
+        .count();
+    assert_eq!(created, 1);
+}
+
+// Builds a four-leaf merkle sum-tree and returns the root and each leaf's proof
+fn build_vote_tree(env: &Env, ballots: &[MerkleBallot; 4]) -> (MerkleNode, [Vec<MerkleNode>; 4]) {
+    let leaves: [MerkleNode; 4] = [
+        merkle::leaf_node(env, &ballots[0]),
+        merkle::leaf_node(env, &ballots[1]),
+        merkle::leaf_node(env, &ballots[2]),
+        merkle::leaf_node(env, &ballots[3]),
+    ];
+    let left = merkle::hash_pair(env, &leaves[0], &leaves[1]).unwrap();
+    let right = merkle::hash_pair(env, &leaves[2], &leaves[3]).unwrap();
+    let root = merkle::hash_pair(env, &left, &right).unwrap();
+
+    let proofs = [
+        Vec::from_array(env, [leaves[1].clone(), right.clone()]),
+        Vec::from_array(env, [leaves[0].clone(), right.clone()]),
+        Vec::from_array(env, [leaves[3].clone(), left.clone()]),
+        Vec::from_array(env, [leaves[2].clone(), left.clone()]),
+    ];
+    (root, proofs)
+}
+
+// Sets up a merkle-enabled proposal whose voting period ends at t=7200
+fn setup_merkle_proposal(env: &Env, contract_id: &Address, admin: &Address, relayer: &Address) -> u32 {
+    let proposer = create_address(env, "proposer");
+
+    with_contract(env, contract_id, || {
+        DaoContract::set_merkle_config(env.clone(), admin.clone(), relayer.clone(), 3600)
+    }).unwrap();
+
+    let proposal_id = with_contract(env, contract_id, || {
+        DaoContract::create_proposal(
+            env.clone(),
+            proposer.clone(),
+            String::from_str(env, "Community Vote"),
+            String::from_str(env, "Large off-chain vote"),
+            ProposalType::Policy,
+            7200,
+            300,
+            60,
+        )
+    }).unwrap();
+
+    with_contract(env, contract_id, || {
+        DaoContract::enable_merkle_voting(env.clone(), proposer.clone(), proposal_id)
+    }).unwrap();
+
+    proposal_id
+}
+
+// Snapshots the voting power of each ballot's voter while voting is open
+fn snapshot_ballot_voters(env: &Env, contract_id: &Address, proposal_id: u32, ballots: &[MerkleBallot]) {
+    let mut voters = Vec::new(env);
+    for ballot in ballots.iter() {
+        voters.push_back(ballot.voter.clone());
+    }
+    with_contract(env, contract_id, || {
+        DaoContract::snapshot_voting_power(env.clone(), proposal_id, voters)
+    }).unwrap();
+}
+
+#[test]
+fn test_merkle_tally_accepted_after_dispute_window() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let relayer = create_address(&env, "relayer");
+    let proposal_id = setup_merkle_proposal(&env, &contract_id, &admin, &relayer);
+
+    let ballots = [
+        MerkleBallot { voter: create_address(&env, "v1"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v2"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v3"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v4"), vote_type: VoteType::Downvote, voting_power: 105 },
+    ];
+    let (root, proofs) = build_vote_tree(&env, &ballots);
+    snapshot_ballot_voters(&env, &contract_id, proposal_id, &ballots);
+
+    // Roots cannot be submitted while voting is open
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), 315, 105)
+    });
+    assert_eq!(result, Err(DaoError::VotingNotEnded));
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+
+    // Only the configured relayer may submit
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), admin.clone(), proposal_id, root.hash.clone(), 315, 105)
+    });
+    assert_eq!(result, Err(DaoError::Unauthorized));
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), 315, 105)
+    }).unwrap();
+
+    // Valid ballots are upheld during the dispute window
+    for i in 0..4 {
+        let included = with_contract(&env, &contract_id, || {
+            DaoContract::verify_ballot(env.clone(), proposal_id, ballots[i].clone(), proofs[i].clone())
+        }).unwrap();
+        assert!(included);
+
+        let voided = with_contract(&env, &contract_id, || {
+            DaoContract::dispute_ballot(env.clone(), proposal_id, ballots[i].clone(), proofs[i].clone())
+        }).unwrap();
+        assert!(!voided);
+    }
+
+    // A proof for the wrong leaf is rejected
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, ballots[0].clone(), proofs[1].clone())
+    });
+    assert_eq!(result, Err(DaoError::InvalidMerkleProof));
+
+    // Finalization waits for the dispute window
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::finalize_proposal(env.clone(), admin.clone(), proposal_id)
+    });
+    assert_eq!(result, Err(DaoError::DisputeWindowActive));
+    let finalized = with_contract(&env, &contract_id, || {
+        DaoContract::finalize_expired(env.clone(), 0, 10)
+    }).finalized;
+    assert_eq!(finalized, 0);
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201 + 3601;
+    });
+    with_contract(&env, &contract_id, || {
+        DaoContract::finalize_proposal(env.clone(), admin.clone(), proposal_id)
+    }).unwrap();
+
+    let proposal = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposal(env.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(proposal.upvotes, 315);
+    assert_eq!(proposal.downvotes, 105);
+    assert_eq!(proposal.status, ProposalStatus::Passed);
+}
+
+#[test]
+fn test_merkle_tally_voided_by_challenge() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let relayer = create_address(&env, "relayer");
+    let proposal_id = setup_merkle_proposal(&env, &contract_id, &admin, &relayer);
+
+    // The relayer inflated one voter's weight beyond their snapshotted voting power
+    let ballots = [
+        MerkleBallot { voter: create_address(&env, "v1"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v2"), vote_type: VoteType::Upvote, voting_power: 10_000 },
+        MerkleBallot { voter: create_address(&env, "v3"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v4"), vote_type: VoteType::Downvote, voting_power: 105 },
+    ];
+    let (root, proofs) = build_vote_tree(&env, &ballots);
+    snapshot_ballot_voters(&env, &contract_id, proposal_id, &ballots);
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+    with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), 10_210, 105)
+    }).unwrap();
+
+    let voided = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, ballots[1].clone(), proofs[1].clone())
+    }).unwrap();
+    assert!(voided);
+
+    let tally = with_contract(&env, &contract_id, || {
+        DaoContract::get_merkle_tally(env.clone(), proposal_id)
+    });
+    assert!(tally.is_none());
+
+    // Without a replacement root the proposal falls back to on-chain votes
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7200 + 3601;
+    });
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), 10_210, 105)
+    });
+    assert_eq!(result, Err(DaoError::DisputeWindowClosed));
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::finalize_proposal(env.clone(), admin.clone(), proposal_id)
+    }).unwrap();
+
+    let proposal = with_contract(&env, &contract_id, || {
+        DaoContract::get_proposal(env.clone(), proposal_id)
+    }).unwrap();
+    assert_eq!(proposal.upvotes, 0);
+    assert_eq!(proposal.status, ProposalStatus::Rejected);
+}
+
+#[test]
+fn test_merkle_duplicated_ballot_voided() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let relayer = create_address(&env, "relayer");
+    let proposal_id = setup_merkle_proposal(&env, &contract_id, &admin, &relayer);
+
+    // The relayer committed the same ballot twice to count it twice
+    let duplicated = MerkleBallot { voter: create_address(&env, "v1"), vote_type: VoteType::Upvote, voting_power: 105 };
+    let ballots = [
+        duplicated.clone(),
+        MerkleBallot { voter: create_address(&env, "v2"), vote_type: VoteType::Upvote, voting_power: 105 },
+        duplicated.clone(),
+        MerkleBallot { voter: create_address(&env, "v3"), vote_type: VoteType::Downvote, voting_power: 105 },
+    ];
+    let (root, proofs) = build_vote_tree(&env, &ballots);
+    snapshot_ballot_voters(&env, &contract_id, proposal_id, &ballots);
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+    with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), 315, 105)
+    }).unwrap();
+
+    let voided = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, duplicated.clone(), proofs[0].clone())
+    }).unwrap();
+    assert!(!voided);
+
+    // Replaying the same proof does not count the ballot again
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, duplicated.clone(), proofs[0].clone())
+    });
+    assert_eq!(result, Err(DaoError::AlreadyVoted));
+
+    // The second copy of the identical leaf voids the tally
+    let voided = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, duplicated.clone(), proofs[2].clone())
+    }).unwrap();
+    assert!(voided);
+
+    let tally = with_contract(&env, &contract_id, || {
+        DaoContract::get_merkle_tally(env.clone(), proposal_id)
+    });
+    assert!(tally.is_none());
+}
+
+#[test]
+fn test_merkle_mirrored_subtree_voided() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let relayer = create_address(&env, "relayer");
+    let proposal_id = setup_merkle_proposal(&env, &contract_id, &admin, &relayer);
+
+    // Both halves of the tree are the same pair of ballots, so every copy has
+    // the same proof
+    let first = MerkleBallot { voter: create_address(&env, "v1"), vote_type: VoteType::Upvote, voting_power: 105 };
+    let second = MerkleBallot { voter: create_address(&env, "v2"), vote_type: VoteType::Upvote, voting_power: 105 };
+    let ballots = [first.clone(), second.clone(), first.clone(), second.clone()];
+    let (root, proofs) = build_vote_tree(&env, &ballots);
+    assert_eq!(proofs[0], proofs[2]);
+    snapshot_ballot_voters(&env, &contract_id, proposal_id, &ballots[..2]);
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+    with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), 420, 0)
+    }).unwrap();
+
+    let voided = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, first.clone(), proofs[0].clone())
+    }).unwrap();
+    assert!(voided);
+}
+
+#[test]
+fn test_merkle_inflated_totals_voided() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let relayer = create_address(&env, "relayer");
+    let proposal_id = setup_merkle_proposal(&env, &contract_id, &admin, &relayer);
+
+    let ballots = [
+        MerkleBallot { voter: create_address(&env, "v1"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v2"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v3"), vote_type: VoteType::Downvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v4"), vote_type: VoteType::Downvote, voting_power: 105 },
+    ];
+    let (root, proofs) = build_vote_tree(&env, &ballots);
+    snapshot_ballot_voters(&env, &contract_id, proposal_id, &ballots);
+
+    // A valid tree submitted with totals that do not match its root
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+    with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), 10_000, 210)
+    }).unwrap();
+
+    // Any valid ballot exposes the mismatch
+    let voided = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, ballots[0].clone(), proofs[0].clone())
+    }).unwrap();
+    assert!(voided);
+    let tally = with_contract(&env, &contract_id, || {
+        DaoContract::get_merkle_tally(env.clone(), proposal_id)
+    });
+    assert!(tally.is_none());
+}
+
+#[test]
+fn test_merkle_inconsistent_node_voided() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let relayer = create_address(&env, "relayer");
+    let proposal_id = setup_merkle_proposal(&env, &contract_id, &admin, &relayer);
+
+    let ballots = [
+        MerkleBallot { voter: create_address(&env, "v1"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v2"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v3"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v4"), vote_type: VoteType::Downvote, voting_power: 105 },
+    ];
+    snapshot_ballot_voters(&env, &contract_id, proposal_id, &ballots);
+
+    // The left subtree claims far more weight than its two ballots carry,
+    // and the root and totals are built consistently on top of that claim
+    let leaves = [
+        merkle::leaf_node(&env, &ballots[0]),
+        merkle::leaf_node(&env, &ballots[1]),
+        merkle::leaf_node(&env, &ballots[2]),
+        merkle::leaf_node(&env, &ballots[3]),
+    ];
+    let mut left = merkle::hash_pair(&env, &leaves[0], &leaves[1]).unwrap();
+    left.upvotes = 5_000;
+    let right = merkle::hash_pair(&env, &leaves[2], &leaves[3]).unwrap();
+    let root = merkle::hash_pair(&env, &left, &right).unwrap();
+    assert_eq!(root.upvotes, 5_105);
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+    with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), root.upvotes, root.downvotes)
+    }).unwrap();
+
+    // Ballots below the inconsistent node no longer prove against the root
+    let proof = Vec::from_array(&env, [leaves[1].clone(), right.clone()]);
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, ballots[0].clone(), proof.clone())
+    });
+    assert_eq!(result, Err(DaoError::InvalidMerkleProof));
+
+    // Opening an honest node changes nothing
+    let voided = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_node(
+            env.clone(),
+            proposal_id,
+            right.clone(),
+            MerkleOpening::Branch(leaves[2].clone(), leaves[3].clone()),
+            Vec::from_array(&env, [left.clone()]),
+        )
+    }).unwrap();
+    assert!(!voided);
+
+    // Opening the inconsistent node voids the tally
+    let voided = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_node(
+            env.clone(),
+            proposal_id,
+            left.clone(),
+            MerkleOpening::Branch(leaves[0].clone(), leaves[1].clone()),
+            Vec::from_array(&env, [right.clone()]),
+        )
+    }).unwrap();
+    assert!(voided);
+}
+
+#[test]
+fn test_merkle_ballot_requires_snapshot() {
+    let env = Env::default();
+    let (contract_id, admin, _nft_contract, _reputation_contract) = setup_contract(&env);
+    let relayer = create_address(&env, "relayer");
+    let proposal_id = setup_merkle_proposal(&env, &contract_id, &admin, &relayer);
+
+    let ballots = [
+        MerkleBallot { voter: create_address(&env, "v1"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v2"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v3"), vote_type: VoteType::Upvote, voting_power: 105 },
+        MerkleBallot { voter: create_address(&env, "v4"), vote_type: VoteType::Downvote, voting_power: 105 },
+    ];
+    let (root, proofs) = build_vote_tree(&env, &ballots);
+
+    // Only the first three voters were snapshotted while voting was open
+    snapshot_ballot_voters(&env, &contract_id, proposal_id, &ballots[..3]);
+    let snapshot = with_contract(&env, &contract_id, || {
+        DaoContract::get_power_snapshot(env.clone(), proposal_id, ballots[0].voter.clone())
+    });
+    assert_eq!(snapshot, Some(105));
+
+    env.ledger().with_mut(|l| {
+        l.timestamp = 7201;
+    });
+
+    // Snapshots can no longer be taken once voting has ended
+    let result = with_contract(&env, &contract_id, || {
+        DaoContract::snapshot_voting_power(env.clone(), proposal_id, Vec::from_array(&env, [ballots[3].voter.clone()]))
+    });
+    assert_eq!(result, Err(DaoError::VotingEnded));
+
+    with_contract(&env, &contract_id, || {
+        DaoContract::submit_vote_root(env.clone(), relayer.clone(), proposal_id, root.hash.clone(), 315, 105)
+    }).unwrap();
+
+    let voided = with_contract(&env, &contract_id, || {
+        DaoContract::dispute_ballot(env.clone(), proposal_id, ballots[3].clone(), proofs[3].clone())
+    }).unwrap();
+    assert!(voided);
+}
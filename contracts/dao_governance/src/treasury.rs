@@ -0,0 +1,124 @@
+// src/treasury.rs
+use crate::events::{emit_treasury_deposit, emit_treasury_disbursement, emit_treasury_token_updated};
+use crate::storage::{
+    get_config, get_spending_window, get_treasury_token_config, remove_treasury_token_config,
+    save_spending_window, save_treasury_token_config,
+};
+use crate::types::{DaoError, FundingRequest, SpendingWindow, TreasuryTokenConfig};
+use soroban_sdk::{token::TokenClient, Address, Env};
+
+fn require_admin(env: &Env, caller: &Address) -> Result<(), DaoError> {
+    if *caller != get_config(env).admin {
+        return Err(DaoError::Unauthorized);
+    }
+    Ok(())
+}
+
+// Whitelist a token (or update its policy) so the treasury can hold it
+pub fn set_treasury_token(
+    env: &Env,
+    caller: &Address,
+    token: &Address,
+    spending_limit: i128,
+    period: u64,
+) -> Result<(), DaoError> {
+    require_admin(env, caller)?;
+    if spending_limit <= 0 || period == 0 {
+        return Err(DaoError::InvalidAmount);
+    }
+
+    let config = TreasuryTokenConfig {
+        spending_limit,
+        period,
+    };
+    save_treasury_token_config(env, token, &config);
+    emit_treasury_token_updated(env, token.clone(), true);
+    Ok(())
+}
+
+// Remove a token from the whitelist. Funds already held stay in the treasury
+// but can no longer be deposited or disbursed until the token is re-added.
+pub fn remove_treasury_token(env: &Env, caller: &Address, token: &Address) -> Result<(), DaoError> {
+    require_admin(env, caller)?;
+    if get_treasury_token_config(env, token).is_none() {
+        return Err(DaoError::TokenNotWhitelisted);
+    }
+
+    remove_treasury_token_config(env, token);
+    emit_treasury_token_updated(env, token.clone(), false);
+    Ok(())
+}
+
+pub fn deposit(env: &Env, from: &Address, token: &Address, amount: i128) -> Result<(), DaoError> {
+    if get_config(env).paused {
+        return Err(DaoError::ContractPaused);
+    }
+    if get_treasury_token_config(env, token).is_none() {
+        return Err(DaoError::TokenNotWhitelisted);
+    }
+    if amount <= 0 {
+        return Err(DaoError::InvalidAmount);
+    }
+
+    TokenClient::new(env, token).transfer(from, &env.current_contract_address(), &amount);
+    emit_treasury_deposit(env, token.clone(), from.clone(), amount);
+    Ok(())
+}
+
+pub fn balance(env: &Env, token: &Address) -> i128 {
+    TokenClient::new(env, token).balance(&env.current_contract_address())
+}
+
+// Check that a funding request can be honoured by the treasury policy
+pub fn validate_funding_request(env: &Env, request: &FundingRequest) -> Result<(), DaoError> {
+    let config =
+        get_treasury_token_config(env, &request.token).ok_or(DaoError::TokenNotWhitelisted)?;
+    if request.amount <= 0 {
+        return Err(DaoError::InvalidAmount);
+    }
+    if request.amount > config.spending_limit {
+        return Err(DaoError::SpendingLimitExceeded);
+    }
+    Ok(())
+}
+
+// Pay out an executed Funding proposal, enforcing the per-period spending limit
+pub fn disburse(env: &Env, proposal_id: u32, request: &FundingRequest) -> Result<(), DaoError> {
+    validate_funding_request(env, request)?;
+    let config = get_treasury_token_config(env, &request.token)
+        .ok_or(DaoError::TokenNotWhitelisted)?;
+
+    let now = env.ledger().timestamp();
+    let mut window = get_spending_window(env, &request.token);
+    if now >= window.start.saturating_add(config.period) {
+        window = SpendingWindow { start: now, spent: 0 };
+    }
+
+    let spent = window
+        .spent
+        .checked_add(request.amount)
+        .ok_or(DaoError::SpendingLimitExceeded)?;
+    if spent > config.spending_limit {
+        return Err(DaoError::SpendingLimitExceeded);
+    }
+    if balance(env, &request.token) < request.amount {
+        return Err(DaoError::InsufficientTreasuryBalance);
+    }
+
+    window.spent = spent;
+    save_spending_window(env, &request.token, &window);
+
+    TokenClient::new(env, &request.token).transfer(
+        &env.current_contract_address(),
+        &request.recipient,
+        &request.amount,
+    );
+    emit_treasury_disbursement(
+        env,
+        proposal_id,
+        request.token.clone(),
+        request.recipient.clone(),
+        request.amount,
+    );
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use soroban_sdk::{contracterror, contracttype, Address, String};
+use soroban_sdk::{contracterror, contracttype, Address, BytesN, Map, String, Vec};
 
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 #[repr(u32)]
@@ -15,6 +15,21 @@ pub enum DataKey {
     Config,
     ProposalCount,
     ProposalVotes(u32),
+    ProposalIndexMeta(ProposalIndex),
+    ProposalIndexPage(ProposalIndex, u32),
+    ProposalHistory(u32, u32),
+    ProposalHistoryLen(u32),
+    ProposalComments(u32, u32),
+    ProposalCommentCount(u32),
+    TreasuryTokens,
+    TreasuryToken(Address),
+    TreasuryWindow(Address),
+    FundingRequest(u32),
+    MerkleConfig,
+    MerkleMode(u32),
+    MerkleTally(u32),
+    MerkleVoter(BytesN<32>, Address),
+    PowerSnapshot(u32, Address),
 }
 
 #[contracterror]
@@ -38,6 +53,22 @@ pub enum DaoError {
     ExternalContractError = 15,
     InvalidTimestamp = 16,
     VoteLimitExceeded = 17,
+    VotingNotStarted = 18,
+    AmendmentNotAllowed = 19,
+    AmendmentLimitExceeded = 20,
+    CommentTooLong = 21,
+    CommentLimitExceeded = 22,
+    TokenNotWhitelisted = 23,
+    InvalidAmount = 24,
+    SpendingLimitExceeded = 25,
+    InsufficientTreasuryBalance = 26,
+    MerkleNotConfigured = 27,
+    MerkleNotEnabled = 28,
+    MerkleTallyNotFound = 29,
+    MerkleTallyExists = 30,
+    InvalidMerkleProof = 31,
+    DisputeWindowActive = 32,
+    DisputeWindowClosed = 33,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -59,6 +90,28 @@ pub enum ProposalStatus {
     Passed = 3,
     Rejected = 4,
     Executed = 5,
+    // Voting period is over but the proposal has not been finalized yet.
+    // Only ever reported by read paths, never stored.
+    Ended = 6,
+}
+
+// Secondary index over proposal ids. `Open` holds the proposals stored as
+// Pending or Active, whose reported status moves with the clock.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum ProposalIndex {
+    Open,
+    Status(ProposalStatus),
+    Type(ProposalType),
+    Proposer(Address),
+}
+
+// Bookkeeping for a paged proposal index: the number of ids per non-empty page
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct ProposalIndexMeta {
+    pub len: u32,
+    pub page_lens: Map<u32, u32>,
 }
 
 #[derive(Clone, Debug)]
@@ -79,6 +132,58 @@ pub struct Proposal {
     pub executed: bool,
 }
 
+// Fields supplied when creating a proposal
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct ProposalParams {
+    pub title: String,
+    pub description: String,
+    pub proposal_type: ProposalType,
+    pub voting_period: u64,
+    pub minimum_quorum: u64,
+    pub minimum_approval: u64,
+}
+
+// One page of a cursor-paged proposal query. `next_cursor` is the proposal id
+// to resume from, or None once there is nothing left to read.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct ProposalPage {
+    pub proposals: Vec<Proposal>,
+    pub next_cursor: Option<u32>,
+}
+
+// Outcome of one finalize_expired call. `next_cursor` is the open proposal id
+// to resume from, or None once the open index has been fully examined.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct FinalizeBatch {
+    pub finalized: u32,
+    pub next_cursor: Option<u32>,
+}
+
+// A superseded title/description of a proposal, kept when it is amended
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct ProposalVersion {
+    pub version: u32,
+    pub title: String,
+    pub description: String,
+    pub replaced_at: u64,
+}
+
+// A discussion entry attached to a proposal. `content` holds a hash or URI of
+// the off-chain text rather than the text itself.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct Comment {
+    pub id: u32,
+    pub proposal_id: u32,
+    pub author: Address,
+    pub content: String,
+    pub timestamp: u64,
+}
+
 #[derive(Clone, Debug)]
 #[contracttype]
 pub struct Vote {
@@ -89,6 +194,86 @@ pub struct Vote {
     pub timestamp: u64,
 }
 
+// Disbursement requested by a Funding proposal, paid out on execution
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct FundingRequest {
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+// Per-token treasury policy: at most `spending_limit` may leave the treasury
+// per spending window. Windows are fixed, not rolling: a window opens with the
+// first payout after the previous one lapsed and lasts `period` seconds, so up
+// to twice the limit can leave around a window boundary. Size the limit for
+// that worst case.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct TreasuryTokenConfig {
+    pub spending_limit: i128,
+    pub period: u64,
+}
+
+// Amount disbursed in the current fixed spending window of a token
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct SpendingWindow {
+    pub start: u64,
+    pub spent: i128,
+}
+
+// Off-chain vote aggregation settings: the relayer allowed to submit vote
+// roots and how long each submitted root can be disputed
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct MerkleConfig {
+    pub relayer: Address,
+    pub dispute_window: u64,
+}
+
+// A weighted ballot as committed to in a relayer's merkle tree. Leaf hashes
+// are the SHA-256 of the ballot's XDR encoding.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct MerkleBallot {
+    pub voter: Address,
+    pub vote_type: VoteType,
+    pub voting_power: u64,
+}
+
+// A node of the relayer's merkle sum-tree: its hash and the vote weight it
+// claims to cover. A parent hashes both children's hashes and weights, and
+// covers the sum of their weights.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MerkleNode {
+    pub hash: BytesN<32>,
+    pub upvotes: u64,
+    pub downvotes: u64,
+}
+
+// The preimage of a disputed node: the ballot of a leaf or the two children
+// of an inner node
+#[derive(Clone, Debug)]
+#[contracttype]
+pub enum MerkleOpening {
+    Leaf(MerkleBallot),
+    Branch(MerkleNode, MerkleNode),
+}
+
+// Aggregated off-chain tally submitted by the relayer for a proposal. The
+// totals must equal the weight covered by the root's children.
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct MerkleTally {
+    pub root: BytesN<32>,
+    pub upvotes: u64,
+    pub downvotes: u64,
+    pub submitted_at: u64,
+    pub dispute_end: u64,
+}
+
 #[derive(Clone, Debug)]
 #[contracttype]
 pub struct DaoConfig {
@@ -1,12 +1,9 @@
-use crate::storage::{get_admin, bump_instance, set_contract_version, set_uri_base};
+use crate::storage::{get_admin, bump_instance, set_contract_version, set_uri_base, MAX_URI_LENGTH, MAX_URI_SUFFIX_LENGTH};
 use crate::storage as storage_mod;
-use crate::types::NFTError;
+use crate::types::{NFTError, PauseScope, ALL_PAUSE_SCOPES};
+use crate::upgrade::CURRENT_CONTRACT_VERSION;
 use soroban_sdk::{Address, Env, String, log};
 
-// Constants
-const CURRENT_CONTRACT_VERSION: u32 = 1;
-const PAUSED_FLAG: u32 = 1;
-const UNPAUSED_FLAG: u32 = 0;
 
 // Initialize contract
 pub fn initialize(env: &Env, admin: Address) -> Result<(), NFTError> {
@@ -28,9 +25,6 @@ pub fn initialize(env: &Env, admin: Address) -> Result<(), NFTError> {
     // Set initial contract version
     set_contract_version(env, CURRENT_CONTRACT_VERSION);
     
-    // Initialize paused state to unpaused
-    env.storage().instance().set(&crate::types::DataKey::ContractVersion, &UNPAUSED_FLAG);
-    
     // Bump instance storage
     bump_instance(env);
     
@@ -45,7 +39,7 @@ pub fn add_authorized_minter(env: &Env, admin: Address, minter: Address) -> Resu
     verify_admin(env, &admin)?;
     
     // Check contract is not paused
-    verify_not_paused(env)?;
+    verify_not_paused(env, PauseScope::Admin)?;
     
     // Add minter to authorized list
     storage_mod::add_authorized_minter(env, minter.clone());
@@ -59,7 +53,7 @@ pub fn remove_authorized_minter(env: &Env, admin: Address, minter: Address) -> R
     verify_admin(env, &admin)?;
     
     // Check contract is not paused
-    verify_not_paused(env)?;
+    verify_not_paused(env, PauseScope::Admin)?;
     
     // Remove minter from authorized list
     storage_mod::remove_authorized_minter(env, minter.clone());
@@ -78,7 +72,12 @@ pub fn set_uri_base_for_tokens(
     verify_admin(env, &admin)?;
     
     // Check contract is not paused
-    verify_not_paused(env)?;
+    verify_not_paused(env, PauseScope::Admin)?;
+    
+    // Keep built token URIs within bounds
+    if base_uri.len() > MAX_URI_LENGTH || suffix.len() > MAX_URI_SUFFIX_LENGTH {
+        return Err(NFTError::MetadataTooLarge);
+    }
     
     // Set the URI base
     set_uri_base(env, base_uri, suffix);
@@ -86,13 +85,31 @@ pub fn set_uri_base_for_tokens(
     Ok(())
 }
 
-// Pause the contract
+// Set the URI of collection-level metadata
+pub fn set_contract_uri(env: &Env, admin: Address, uri: String) -> Result<(), NFTError> {
+    // Verify admin authorization
+    verify_admin(env, &admin)?;
+    
+    // Check contract is not paused
+    verify_not_paused(env, PauseScope::Admin)?;
+    
+    if uri.len() > MAX_URI_LENGTH {
+        return Err(NFTError::MetadataTooLarge);
+    }
+    
+    storage_mod::set_contract_uri(env, uri);
+    
+    Ok(())
+}
+
+// Pause every scope
 pub fn pause_contract(env: &Env, admin: Address) -> Result<(), NFTError> {
     // Verify admin authorization
     verify_admin(env, &admin)?;
     
-    // Set paused flag
-    env.storage().instance().set(&crate::types::DataKey::ContractVersion, &PAUSED_FLAG);
+    for scope in ALL_PAUSE_SCOPES {
+        update_pause(env, scope, true);
+    }
     
     // Bump instance storage
     bump_instance(env);
@@ -102,13 +119,14 @@ pub fn pause_contract(env: &Env, admin: Address) -> Result<(), NFTError> {
     Ok(())
 }
 
-// Unpause the contract
+// Unpause every scope
 pub fn unpause_contract(env: &Env, admin: Address) -> Result<(), NFTError> {
     // Verify admin authorization
     verify_admin(env, &admin)?;
     
-    // Set unpaused flag
-    env.storage().instance().set(&crate::types::DataKey::ContractVersion, &UNPAUSED_FLAG);
+    for scope in ALL_PAUSE_SCOPES {
+        update_pause(env, scope, false);
+    }
     
     // Bump instance storage
     bump_instance(env);
@@ -118,21 +136,21 @@ pub fn unpause_contract(env: &Env, admin: Address) -> Result<(), NFTError> {
     Ok(())
 }
 
-// Upgrade contract version (for future use)
-pub fn upgrade_contract(env: &Env, admin: Address, new_version: u32) -> Result<(), NFTError> {
+// Pause or unpause a single scope
+pub fn set_paused(env: &Env, admin: Address, scope: PauseScope, paused: bool) -> Result<(), NFTError> {
     // Verify admin authorization
     verify_admin(env, &admin)?;
     
-    // Set new contract version
-    set_contract_version(env, new_version);
+    update_pause(env, scope, paused);
     
-    log!(env, "Contract upgraded to version {} by admin: {}", new_version, admin);
+    // Bump instance storage
+    bump_instance(env);
     
     Ok(())
 }
 
 // Helper: Verify caller is admin
-fn verify_admin(env: &Env, caller: &Address) -> Result<(), NFTError> {
+pub(crate) fn verify_admin(env: &Env, caller: &Address) -> Result<(), NFTError> {
     caller.require_auth();
     
     let admin = get_admin(env)?;
@@ -144,16 +162,26 @@ fn verify_admin(env: &Env, caller: &Address) -> Result<(), NFTError> {
     Ok(())
 }
 
-// Helper: Verify contract is not paused
-fn verify_not_paused(env: &Env) -> Result<(), NFTError> {
-    let paused: u32 = env.storage()
-        .instance()
-        .get(&crate::types::DataKey::ContractVersion)
-        .unwrap_or(UNPAUSED_FLAG);
-    
-    if paused == PAUSED_FLAG {
+// Helper: Verify the scope of an operation is not paused
+// Pausing and upgrading are never blocked so the admin can always recover
+pub(crate) fn verify_not_paused(env: &Env, scope: PauseScope) -> Result<(), NFTError> {
+    if storage_mod::is_paused(env, scope) {
         return Err(NFTError::ContractPaused);
     }
     
     Ok(())
+}
+
+// Helper: Store a scope's pause flag and emit an event when it changes
+fn update_pause(env: &Env, scope: PauseScope, paused: bool) {
+    if storage_mod::is_paused(env, scope) == paused {
+        return;
+    }
+    
+    storage_mod::set_paused(env, scope, paused);
+    
+    env.events().publish(
+        ("pause", scope),
+        paused
+    );
 }
\ No newline at end of file
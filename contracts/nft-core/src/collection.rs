@@ -0,0 +1,125 @@
+use crate::admin::verify_not_paused;
+use crate::minting::{build_attribute_map, create_nft};
+use crate::storage::{
+    add_collection_token, get_collection, increment_collection_count, is_authorized_minter, save_collection,
+    MAX_URI_LENGTH, MAX_URI_SUFFIX_LENGTH,
+};
+use crate::types::{Collection, CollectionMint, CollectionParams, NFTError, PauseScope};
+use soroban_sdk::{Address, Env, log};
+
+// Constants
+const MAX_COLLECTION_NAME_LENGTH: u32 = 100;
+
+// Create a named collection owned by its issuer
+// # Security considerations
+// - Only authorized minters can create collections
+// - URI limits match the contract base URI so built URIs always fit
+// - A max supply of zero is rejected; use None for an unlimited series
+
+pub fn create_collection(
+    env: &Env,
+    issuer: Address,
+    params: CollectionParams
+) -> Result<u64, NFTError> {
+    let CollectionParams { name, max_supply, default_attributes, base_uri, uri_suffix } = params;
+    
+    verify_not_paused(env, PauseScope::Mint)?;
+    
+    // Authorization validation
+    if !is_authorized_minter(env, &issuer) {
+        return Err(NFTError::Unauthorized);
+    }
+    
+    // Require issuer authorization
+    issuer.require_auth();
+    
+    // Input validation
+    if name.is_empty() || max_supply == Some(0) {
+        return Err(NFTError::InvalidInput);
+    }
+    
+    if name.len() > MAX_COLLECTION_NAME_LENGTH
+        || base_uri.as_ref().is_some_and(|uri| uri.len() > MAX_URI_LENGTH)
+        || uri_suffix.len() > MAX_URI_SUFFIX_LENGTH
+    {
+        return Err(NFTError::MetadataTooLarge);
+    }
+    
+    let collection = Collection {
+        id: increment_collection_count(env),
+        issuer: issuer.clone(),
+        name: name.clone(),
+        max_supply,
+        minted: 0,
+        default_attributes: build_attribute_map(env, default_attributes),
+        base_uri,
+        uri_suffix,
+        created_at: env.ledger().timestamp(),
+    };
+    save_collection(env, &collection);
+    
+    // Emit collection event
+    env.events().publish(
+        ("collection", collection.id),
+        (issuer.clone(), name)
+    );
+    
+    log!(env, "Collection created: {} by {}", collection.id, issuer);
+    
+    Ok(collection.id)
+}
+
+// Mint a token into a collection
+// # Security considerations
+// - Only the collection's issuer can mint into it, and must still be an authorized minter
+// - The collection's max supply can never be exceeded
+// - Token attributes override the collection defaults with the same key
+
+pub fn mint_into_collection(
+    env: &Env,
+    minter: Address,
+    params: CollectionMint
+) -> Result<u128, NFTError> {
+    let CollectionMint { collection_id, recipient, title, description, attributes, transferable } = params;
+    
+    verify_not_paused(env, PauseScope::Mint)?;
+    
+    let mut collection = get_collection(env, collection_id)?;
+    
+    // Authorization validation
+    if collection.issuer != minter || !is_authorized_minter(env, &minter) {
+        return Err(NFTError::Unauthorized);
+    }
+    
+    // Require minter authorization
+    minter.require_auth();
+    
+    // Input validation
+    if title.is_empty() || description.is_empty() {
+        return Err(NFTError::InvalidInput);
+    }
+    
+    // Supply enforcement
+    if collection.max_supply.is_some_and(|max| collection.minted >= max) {
+        return Err(NFTError::CollectionSupplyExceeded);
+    }
+    
+    let mut token_attributes = collection.default_attributes.clone();
+    for (key, value) in attributes.iter() {
+        token_attributes.set(key, value);
+    }
+    
+    let token_id = create_nft(env, &minter, &recipient, &title, &description, token_attributes, transferable);
+    
+    add_collection_token(env, collection_id, collection.minted, token_id);
+    collection.minted += 1;
+    save_collection(env, &collection);
+    
+    // Emit collection mint event
+    env.events().publish(
+        ("collection_mint", collection_id),
+        (token_id, recipient)
+    );
+    
+    Ok(token_id)
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code)]
-use crate::types::{NFT, NFTError, NFTMetadata, NFTMintBatch};
-use soroban_sdk::{Address, Env, String, Vec};
+use crate::types::{Collection, CollectionMint, CollectionParams, NFT, NFTError, NFTMetadata, NFTMintBatch, PauseScope};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 /// NFT Core interface - represents the core functionality of the NFT contract
 #[allow(dead_code)]
@@ -30,6 +30,62 @@ pub trait NFTCoreInterface {
     fn burn_nft(env: Env, owner: Address, token_id: u128) -> Result<(), NFTError>;
 }
 
+/// Collection interface - represents named series of NFTs with their own issuer and supply
+#[allow(dead_code)]
+pub trait NFTCollectionInterface {
+    // Create a collection issued by an authorized minter
+    fn create_collection(
+        env: Env,
+        issuer: Address,
+        params: CollectionParams
+    ) -> Result<u64, NFTError>;
+    
+    // Mint a token into a collection, within its max supply
+    fn mint_into_collection(
+        env: Env,
+        minter: Address,
+        params: CollectionMint
+    ) -> Result<u128, NFTError>;
+    
+    // Get a collection by ID
+    fn get_collection(env: Env, collection_id: u64) -> Result<Collection, NFTError>;
+    
+    // Get NFTs of a collection by mint position, skipping burned tokens; limit is capped
+    fn get_tokens_by_collection(env: Env, collection_id: u64, start_pos: u32, limit: u32) -> Vec<NFT>;
+    
+    // Get the collection a token was minted into
+    fn get_token_collection(env: Env, token_id: u128) -> Option<u64>;
+}
+
+/// Transfer interface - represents ownership transfers and approvals for transferable NFTs
+#[allow(dead_code)]
+pub trait NFTTransferInterface {
+    // Transfer a token owned by `from`
+    fn transfer(env: Env, from: Address, to: Address, token_id: u128) -> Result<(), NFTError>;
+    
+    // Transfer a token on the owner's behalf as an approved address or operator
+    fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u128
+    ) -> Result<(), NFTError>;
+    
+    // Approve an address to transfer a single token, or clear it with None
+    fn approve(env: Env, caller: Address, approved: Option<Address>, token_id: u128) -> Result<(), NFTError>;
+    
+    // Grant or revoke an operator for all of the owner's tokens
+    fn set_approval_for_all(env: Env, owner: Address, operator: Address, approved: bool) -> Result<(), NFTError>;
+}
+
+/// Issuer interface - represents operations reserved to a token's issuer
+#[allow(dead_code)]
+pub trait NFTIssuerInterface {
+    // Set or clear a URI overriding the base URI for one token
+    fn set_token_uri(env: Env, issuer: Address, token_id: u128, uri: Option<String>) -> Result<(), NFTError>;
+}
+
 /// Admin interface - represents administrative operations for the NFT contract
 #[allow(dead_code)]
 pub trait NFTAdminInterface {
@@ -42,14 +98,23 @@ pub trait NFTAdminInterface {
     // Set the base URI for external metadata
     fn set_uri_base(env: Env, admin: Address, base_uri: String, suffix: String) -> Result<(), NFTError>;
     
-    // Pause contract operations (except admin functions)
+    // Set the URI of collection-level metadata
+    fn set_contract_uri(env: Env, admin: Address, uri: String) -> Result<(), NFTError>;
+    
+    // Pause every scope of contract operations
     fn pause_contract(env: Env, admin: Address) -> Result<(), NFTError>;
     
-    // Unpause contract operations
+    // Unpause every scope of contract operations
     fn unpause_contract(env: Env, admin: Address) -> Result<(), NFTError>;
     
-    // Upgrade contract version
-    fn upgrade_contract(env: Env, admin: Address, new_version: u32) -> Result<(), NFTError>;
+    // Pause or unpause a single scope of contract operations
+    fn set_paused(env: Env, admin: Address, scope: PauseScope, paused: bool) -> Result<(), NFTError>;
+    
+    // Replace the contract code with an uploaded WASM
+    fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), NFTError>;
+    
+    // Move storage forward to the layout of the running code
+    fn migrate(env: Env, admin: Address) -> Result<u32, NFTError>;
 }
 
 /// Query interface - represents read-only operations for the NFT contract
@@ -69,6 +134,12 @@ pub trait NFTQueryInterface {
         limit: u32
     ) -> Vec<NFT>;
     
+    // Get the address approved to transfer a token
+    fn get_approved(env: Env, token_id: u128) -> Option<Address>;
+    
+    // Check if an operator may transfer all of the owner's tokens
+    fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool;
+    
     // Check if an address is an authorized minter
     fn is_authorized_minter(env: Env, minter: Address) -> bool;
     
@@ -78,6 +149,12 @@ pub trait NFTQueryInterface {
     // Get the external URI for a token
     fn get_token_uri(env: Env, token_id: u128) -> Option<String>;
     
+    // Check if a scope of contract operations is paused
+    fn is_paused(env: Env, scope: PauseScope) -> bool;
+    
+    // Get the URI of collection-level metadata
+    fn contract_uri(env: Env) -> Option<String>;
+    
     // Get the contract version
     fn get_contract_version(env: Env) -> u32;
 }
\ No newline at end of file
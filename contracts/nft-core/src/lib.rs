@@ -1,14 +1,18 @@
 #![no_std]
-use soroban_sdk::{contract, contractimpl, Address, Env, String, Vec};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
 
 mod types;
 mod storage;
 mod minting;
+mod collection;
+mod transfer;
+mod uri;
+mod upgrade;
 mod admin;
 mod interfaces;
 mod test;
 
-pub use types::{NFT, NFTError, NFTMetadata, DataKey, NFTMintBatch, ExternalURIMetadata};
+pub use types::{NFT, NFTError, NFTMetadata, DataKey, NFTMintBatch, ExternalURIMetadata, PauseScope, Collection, CollectionParams, CollectionMint};
 
 #[contract]
 pub struct NFTCore;
@@ -41,10 +45,60 @@ impl NFTCore {
         minting::batch_mint_nfts(&env, minter, batch)
     }
     
+    // Collection operations
+    pub fn create_collection(
+        env: Env,
+        issuer: Address,
+        params: CollectionParams
+    ) -> Result<u64, types::NFTError> {
+        collection::create_collection(&env, issuer, params)
+    }
+    
+    pub fn mint_into_collection(
+        env: Env,
+        minter: Address,
+        params: CollectionMint
+    ) -> Result<u128, types::NFTError> {
+        collection::mint_into_collection(&env, minter, params)
+    }
+    
     pub fn burn_nft(env: Env, owner: Address, token_id: u128) -> Result<(), types::NFTError> {
         minting::burn_nft(&env, owner, token_id)
     }
     
+    // Transfer operations
+    pub fn transfer(env: Env, from: Address, to: Address, token_id: u128) -> Result<(), types::NFTError> {
+        transfer::transfer(&env, from, to, token_id)
+    }
+    
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u128
+    ) -> Result<(), types::NFTError> {
+        transfer::transfer_from(&env, spender, from, to, token_id)
+    }
+    
+    pub fn approve(
+        env: Env,
+        caller: Address,
+        approved: Option<Address>,
+        token_id: u128
+    ) -> Result<(), types::NFTError> {
+        transfer::approve(&env, caller, approved, token_id)
+    }
+    
+    pub fn set_approval_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool
+    ) -> Result<(), types::NFTError> {
+        transfer::set_approval_for_all(&env, owner, operator, approved)
+    }
+    
     // Admin operations
     pub fn add_authorized_minter(env: Env, admin: Address, minter: Address) -> Result<(), types::NFTError> {
         admin::add_authorized_minter(&env, admin, minter)
@@ -58,6 +112,20 @@ impl NFTCore {
         admin::set_uri_base_for_tokens(&env, admin, base_uri, suffix)
     }
     
+    pub fn set_contract_uri(env: Env, admin: Address, uri: String) -> Result<(), types::NFTError> {
+        admin::set_contract_uri(&env, admin, uri)
+    }
+    
+    // Issuer operations
+    pub fn set_token_uri(
+        env: Env,
+        issuer: Address,
+        token_id: u128,
+        uri: Option<String>
+    ) -> Result<(), types::NFTError> {
+        uri::set_token_uri(&env, issuer, token_id, uri)
+    }
+    
     pub fn pause_contract(env: Env, admin: Address) -> Result<(), types::NFTError> {
         admin::pause_contract(&env, admin)
     }
@@ -66,8 +134,16 @@ impl NFTCore {
         admin::unpause_contract(&env, admin)
     }
     
-    pub fn upgrade_contract(env: Env, admin: Address, new_version: u32) -> Result<(), types::NFTError> {
-        admin::upgrade_contract(&env, admin, new_version)
+    pub fn set_paused(env: Env, admin: Address, scope: PauseScope, paused: bool) -> Result<(), types::NFTError> {
+        admin::set_paused(&env, admin, scope, paused)
+    }
+    
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), types::NFTError> {
+        upgrade::upgrade(&env, admin, new_wasm_hash)
+    }
+    
+    pub fn migrate(env: Env, admin: Address) -> Result<u32, types::NFTError> {
+        upgrade::migrate(&env, admin)
     }
     
     // Query operations
@@ -88,6 +164,31 @@ impl NFTCore {
         storage::get_nfts_by_owner_paginated(&env, owner, start_pos, limit)
     }
     
+    pub fn get_collection(env: Env, collection_id: u64) -> Result<types::Collection, types::NFTError> {
+        storage::get_collection(&env, collection_id)
+    }
+    
+    pub fn get_tokens_by_collection(
+        env: Env,
+        collection_id: u64,
+        start_pos: u32,
+        limit: u32
+    ) -> Vec<types::NFT> {
+        storage::get_tokens_by_collection(&env, collection_id, start_pos, limit)
+    }
+    
+    pub fn get_token_collection(env: Env, token_id: u128) -> Option<u64> {
+        storage::get_token_collection(&env, token_id)
+    }
+    
+    pub fn get_approved(env: Env, token_id: u128) -> Option<Address> {
+        storage::get_approved(&env, token_id)
+    }
+    
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        storage::is_approved_for_all(&env, &owner, &operator)
+    }
+    
     pub fn is_authorized_minter(env: Env, minter: Address) -> bool {
         storage::is_authorized_minter(&env, &minter)
     }
@@ -101,10 +202,15 @@ impl NFTCore {
         storage::build_token_uri(&env, token_id)
     }
     
+    pub fn is_paused(env: Env, scope: PauseScope) -> bool {
+        storage::is_paused(&env, scope)
+    }
+    
+    pub fn contract_uri(env: Env) -> Option<String> {
+        storage::get_contract_uri(&env)
+    }
+    
     pub fn get_contract_version(env: Env) -> u32 {
-        env.storage()
-            .instance()
-            .get(&DataKey::ContractVersion)
-            .unwrap_or(0)
+        storage::get_contract_version(&env)
     }
 }
\ No newline at end of file
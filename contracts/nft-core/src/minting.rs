@@ -1,5 +1,9 @@
-use crate::storage::{get_nft, increment_token_count, is_authorized_minter, save_nft, bump_instance, bump_nft};
-use crate::types::{NFT, NFTError, NFTMetadata, DataKey, NFTMintBatch};
+use crate::storage::{
+    get_nft, increment_token_count, is_authorized_minter, save_nft, bump_instance, bump_nft, remove_approval,
+    remove_owner_token, remove_token_uri_override, remove_collection_token,
+};
+use crate::admin::verify_not_paused;
+use crate::types::{NFT, NFTError, NFTMetadata, DataKey, NFTMintBatch, PauseScope};
 use soroban_sdk::{Address, Env, Map, String, Vec, log};
 
 // Mint a new NFT
@@ -18,9 +22,7 @@ pub fn mint_nft(
     transferable: bool
 ) -> Result<u128, NFTError> {
     // Check if contract is paused
-    if env.storage().instance().has(&DataKey::Paused) {
-        return Err(NFTError::ContractPaused);
-    }
+    verify_not_paused(env, PauseScope::Mint)?;
     
     // Authorization validation
     if !is_authorized_minter(env, &minter) {
@@ -44,6 +46,19 @@ pub fn mint_nft(
         return Err(NFTError::InvalidRecipient);
     }
     
+    Ok(create_nft(env, &minter, &recipient, &title, &description, build_attribute_map(env, attributes), transferable))
+}
+
+// Helper: store a validated NFT, returning its token ID
+pub(crate) fn create_nft(
+    env: &Env,
+    minter: &Address,
+    recipient: &Address,
+    title: &String,
+    description: &String,
+    attributes: Map<String, String>,
+    transferable: bool
+) -> u128 {
     // Increment token counter
     let token_id = increment_token_count(env);
     
@@ -53,7 +68,7 @@ pub fn mint_nft(
         title: title.clone(),
         description: description.clone(),
         creation_date: env.ledger().timestamp(),
-        attributes: build_attribute_map(env, attributes.clone()),
+        attributes,
     };
     
     // Create the NFT
@@ -80,7 +95,7 @@ pub fn mint_nft(
     
     log!(env, "NFT minted: {} to {}", token_id, recipient);
     
-    Ok(token_id)
+    token_id
 }
 
 // Mint multiple NFTs in a batch
@@ -95,9 +110,7 @@ pub fn batch_mint_nfts(
     batch: NFTMintBatch,
 ) -> Result<Vec<u128>, NFTError> {
     // Check if contract is paused
-    if env.storage().instance().has(&DataKey::Paused) {
-        return Err(NFTError::ContractPaused);
-    }
+    verify_not_paused(env, PauseScope::Mint)?;
     
     // Authorization validation
     if !is_authorized_minter(env, &minter) {
@@ -197,9 +210,7 @@ pub fn batch_mint_nfts(
 
 pub fn burn_nft(env: &Env, owner: Address, token_id: u128) -> Result<(), NFTError> {
     // Check if contract is paused
-    if env.storage().instance().has(&DataKey::Paused) {
-        return Err(NFTError::ContractPaused);
-    }
+    verify_not_paused(env, PauseScope::Burn)?;
     
     // Get the NFT
     let nft = get_nft(env, token_id)?;
@@ -215,29 +226,11 @@ pub fn burn_nft(env: &Env, owner: Address, token_id: u128) -> Result<(), NFTErro
     // Remove NFT from storage
     env.storage().persistent().remove(&DataKey::NFT(token_id));
     
-    // Update owner's token list
-    let mut owner_tokens: Vec<u128> = env
-        .storage()
-        .persistent()
-        .get(&DataKey::OwnerTokens(owner.clone()))
-        .unwrap_or_else(|| Vec::new(env));
-    
-    let index = owner_tokens.first_index_of(&token_id);
-    if let Some(idx) = index {
-        owner_tokens.remove(idx);
-        
-        if owner_tokens.is_empty() {
-            env.storage().persistent().remove(&DataKey::OwnerTokens(owner.clone()));
-        } else {
-            env.storage().persistent().set(&DataKey::OwnerTokens(owner.clone()), &owner_tokens);
-            // Extend TTL for the updated owner token list
-            env.storage().persistent().extend_ttl(
-                &DataKey::OwnerTokens(owner.clone()),
-                26280,
-                26280
-            );
-        }
-    }
+    // Update owner's token list and drop any outstanding approval
+    remove_owner_token(env, &owner, token_id);
+    remove_approval(env, token_id);
+    remove_token_uri_override(env, token_id);
+    remove_collection_token(env, token_id);
     
     // Emit burn event
     env.events().publish(
@@ -281,7 +274,7 @@ fn validate_metadata(
 }
 
 // Helper function to build attribute map
-fn build_attribute_map(
+pub(crate) fn build_attribute_map(
     env: &Env,
     attributes: Vec<(String, String)>
 ) -> Map<String, String> {
@@ -1,10 +1,20 @@
-use crate::types::{DataKey, NFT, NFTError, ExternalURIMetadata};
+use crate::types::{Collection, DataKey, NFT, NFTError, ExternalURIMetadata, PauseScope};
 use soroban_sdk::{Address, Env, Vec, String, log};
 
 // Ledger constants (approx. 30 days in ledgers)
 const INSTANCE_LIFETIME_THRESHOLD: u32 = 26280;
 const NFT_LIFETIME_THRESHOLD: u32 = 26280;
 
+// URI limits; a built token URI is at most base + u128 digits + suffix
+pub const MAX_URI_LENGTH: u32 = 200;
+pub const MAX_URI_SUFFIX_LENGTH: u32 = 16;
+const MAX_U128_DIGITS: usize = 39;
+const MAX_BUILT_URI_LENGTH: usize = MAX_URI_LENGTH as usize + MAX_U128_DIGITS + MAX_URI_SUFFIX_LENGTH as usize;
+
+// Collection listing limits
+pub const COLLECTION_PAGE_SIZE: u32 = 100;
+pub const MAX_COLLECTION_QUERY_LIMIT: u32 = 50;
+
 pub fn get_token_count(env: &Env) -> u128 {
     env.storage()
         .instance()
@@ -51,6 +61,71 @@ pub fn save_nft(env: &Env, nft: &NFT) {
     }
 }
 
+// Remove a token from its owner's token list
+pub fn remove_owner_token(env: &Env, owner: &Address, token_id: u128) {
+    let key = DataKey::OwnerTokens(owner.clone());
+    let mut owner_tokens: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+
+    if let Some(idx) = owner_tokens.first_index_of(token_id) {
+        owner_tokens.remove(idx);
+
+        if owner_tokens.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &owner_tokens);
+            env.storage().persistent().extend_ttl(
+                &key,
+                INSTANCE_LIFETIME_THRESHOLD,
+                INSTANCE_LIFETIME_THRESHOLD
+            );
+        }
+    }
+}
+
+pub fn get_approved(env: &Env, token_id: u128) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Approval(token_id))
+}
+
+pub fn set_approval(env: &Env, token_id: u128, approved: &Address) {
+    env.storage().persistent().set(&DataKey::Approval(token_id), approved);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Approval(token_id),
+        NFT_LIFETIME_THRESHOLD,
+        NFT_LIFETIME_THRESHOLD
+    );
+}
+
+pub fn remove_approval(env: &Env, token_id: u128) {
+    env.storage().persistent().remove(&DataKey::Approval(token_id));
+}
+
+pub fn is_approved_for_all(env: &Env, owner: &Address, operator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OperatorApproval(owner.clone(), operator.clone()))
+        .unwrap_or(false)
+}
+
+pub fn set_operator_approval(env: &Env, owner: &Address, operator: &Address, approved: bool) {
+    let key = DataKey::OperatorApproval(owner.clone(), operator.clone());
+    if approved {
+        env.storage().persistent().set(&key, &true);
+        env.storage().persistent().extend_ttl(
+            &key,
+            INSTANCE_LIFETIME_THRESHOLD,
+            INSTANCE_LIFETIME_THRESHOLD
+        );
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
 pub fn get_nft(env: &Env, token_id: u128) -> Result<NFT, NFTError> {
     env.storage()
         .persistent()
@@ -194,17 +269,217 @@ pub fn get_uri_base(env: &Env) -> Option<ExternalURIMetadata> {
         .get(&DataKey::URIBase)
 }
 
-pub fn build_token_uri(env: &Env, _token_id: u128) -> Option<String> {
-    let uri_data = get_uri_base(env);
+// Build a token's URI: the issuer's override if set, otherwise base URI + token ID + suffix,
+// preferring the base URI of the token's collection over the contract's
+pub fn build_token_uri(env: &Env, token_id: u128) -> Option<String> {
+    if !env.storage().persistent().has(&DataKey::NFT(token_id)) {
+        return None;
+    }
+    
+    if let Some(uri) = get_token_uri_override(env, token_id) {
+        return Some(uri);
+    }
     
-    if uri_data.is_none() {
+    let collection_base = get_token_collection(env, token_id)
+        .and_then(|collection_id| get_collection(env, collection_id).ok())
+        .and_then(|collection| collection.base_uri.map(|base| (base, collection.uri_suffix)));
+    let (base_uri, suffix) = match collection_base {
+        Some(base) => base,
+        None => {
+            let uri_data = get_uri_base(env)?;
+            (uri_data.base_uri, uri_data.token_uri_suffix)
+        }
+    };
+    // Lengths are capped when the base is set, but values stored before the
+    // caps existed may be longer; those tokens get no built URI
+    if base_uri.len() > MAX_URI_LENGTH || suffix.len() > MAX_URI_SUFFIX_LENGTH {
         return None;
     }
+    let base_len = base_uri.len() as usize;
+    let suffix_len = suffix.len() as usize;
     
-    let uri_data = uri_data.unwrap();
-    let base_uri = uri_data.base_uri.clone();
+    let mut buf = [0u8; MAX_BUILT_URI_LENGTH];
+    base_uri.copy_into_slice(&mut buf[..base_len]);
+    let digits_len = write_decimal(token_id, &mut buf[base_len..]);
+    let end = base_len + digits_len;
+    suffix.copy_into_slice(&mut buf[end..end + suffix_len]);
     
-    Some(base_uri)
+    Some(String::from_bytes(env, &buf[..end + suffix_len]))
+}
+
+// Write the decimal digits of `value` to the start of `buf`, returning the digit count
+fn write_decimal(mut value: u128, buf: &mut [u8]) -> usize {
+    let mut digits = [0u8; MAX_U128_DIGITS];
+    let mut len = 0;
+    loop {
+        digits[len] = b'0' + (value % 10) as u8;
+        len += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    
+    for (slot, digit) in buf.iter_mut().zip(digits[..len].iter().rev()) {
+        *slot = *digit;
+    }
+    len
+}
+
+pub fn get_token_uri_override(env: &Env, token_id: u128) -> Option<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenURI(token_id))
+}
+
+pub fn set_token_uri_override(env: &Env, token_id: u128, uri: &String) {
+    env.storage().persistent().set(&DataKey::TokenURI(token_id), uri);
+    env.storage().persistent().extend_ttl(
+        &DataKey::TokenURI(token_id),
+        NFT_LIFETIME_THRESHOLD,
+        NFT_LIFETIME_THRESHOLD
+    );
+}
+
+pub fn remove_token_uri_override(env: &Env, token_id: u128) {
+    env.storage().persistent().remove(&DataKey::TokenURI(token_id));
+}
+
+pub fn get_contract_uri(env: &Env) -> Option<String> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContractURI)
+}
+
+pub fn set_contract_uri(env: &Env, uri: String) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ContractURI, &uri);
+    
+    // Bump instance storage
+    bump_instance(env);
+}
+
+pub fn increment_collection_count(env: &Env) -> u64 {
+    let count = env.storage()
+        .instance()
+        .get(&DataKey::CollectionCount)
+        .unwrap_or(0u64) + 1;
+    env.storage()
+        .instance()
+        .set(&DataKey::CollectionCount, &count);
+    
+    // Bump instance storage lifetime
+    bump_instance(env);
+    
+    count
+}
+
+pub fn get_collection(env: &Env, collection_id: u64) -> Result<Collection, NFTError> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Collection(collection_id))
+        .ok_or(NFTError::CollectionNotFound)
+}
+
+pub fn save_collection(env: &Env, collection: &Collection) {
+    env.storage().persistent().set(&DataKey::Collection(collection.id), collection);
+    env.storage().persistent().extend_ttl(
+        &DataKey::Collection(collection.id),
+        NFT_LIFETIME_THRESHOLD,
+        NFT_LIFETIME_THRESHOLD
+    );
+}
+
+// Record a newly minted token in its collection. Tokens are appended to
+// fixed-size pages by mint position, so no single entry grows with the collection
+pub fn add_collection_token(env: &Env, collection_id: u64, position: u32, token_id: u128) {
+    let key = DataKey::CollectionTokens(collection_id, position / COLLECTION_PAGE_SIZE);
+    let mut tokens: Vec<u128> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| Vec::new(env));
+    tokens.push_back(token_id);
+    env.storage().persistent().set(&key, &tokens);
+    env.storage().persistent().extend_ttl(&key, NFT_LIFETIME_THRESHOLD, NFT_LIFETIME_THRESHOLD);
+    
+    env.storage().persistent().set(&DataKey::TokenCollection(token_id), &collection_id);
+    env.storage().persistent().extend_ttl(
+        &DataKey::TokenCollection(token_id),
+        NFT_LIFETIME_THRESHOLD,
+        NFT_LIFETIME_THRESHOLD
+    );
+}
+
+// Detach a burned token from its collection, if it belongs to one.
+// Its page entry is kept so mint positions stay stable; listings skip it
+pub fn remove_collection_token(env: &Env, token_id: u128) {
+    env.storage().persistent().remove(&DataKey::TokenCollection(token_id));
+}
+
+pub fn get_token_collection(env: &Env, token_id: u128) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenCollection(token_id))
+}
+
+// List a collection's tokens from mint position `start_pos`. At most
+// MAX_COLLECTION_QUERY_LIMIT positions are read; burned tokens are skipped,
+// so a page may hold fewer than `limit` NFTs
+pub fn get_tokens_by_collection(
+    env: &Env,
+    collection_id: u64,
+    start_pos: u32,
+    limit: u32
+) -> Vec<NFT> {
+    let mut nfts = Vec::new(env);
+    let Ok(collection) = get_collection(env, collection_id) else {
+        return nfts;
+    };
+    
+    let end = start_pos
+        .saturating_add(limit.min(MAX_COLLECTION_QUERY_LIMIT))
+        .min(collection.minted);
+    let mut position = start_pos;
+    while position < end {
+        let page = position / COLLECTION_PAGE_SIZE;
+        let token_ids: Vec<u128> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CollectionTokens(collection_id, page))
+            .unwrap_or_else(|| Vec::new(env));
+        let page_end = end.min((page + 1) * COLLECTION_PAGE_SIZE);
+        
+        for offset in (position % COLLECTION_PAGE_SIZE)..(page_end - page * COLLECTION_PAGE_SIZE) {
+            let Some(id) = token_ids.get(offset) else {
+                break;
+            };
+            if let Some(nft) = env.storage().persistent().get(&DataKey::NFT(id)) {
+                nfts.push_back(nft);
+                // Extend NFT storage lifetime
+                bump_nft(env, id);
+            }
+        }
+        position = page_end;
+    }
+    
+    nfts
+}
+
+pub fn is_paused(env: &Env, scope: PauseScope) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused(scope))
+        .unwrap_or(false)
+}
+
+pub fn set_paused(env: &Env, scope: PauseScope, paused: bool) {
+    if paused {
+        env.storage().instance().set(&DataKey::Paused(scope), &true);
+    } else {
+        env.storage().instance().remove(&DataKey::Paused(scope));
+    }
 }
 
 // Storage lifetime management functions
@@ -220,10 +495,25 @@ pub fn bump_nft(env: &Env, token_id: u128) {
     );
 }
 
+// Deployments from before layout versioning have no LayoutVersion entry
+pub fn get_contract_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::LayoutVersion)
+        .unwrap_or(1)
+}
+
 pub fn set_contract_version(env: &Env, version: u32) {
     env.storage()
         .instance()
-        .set(&DataKey::ContractVersion, &version);
+        .set(&DataKey::LayoutVersion, &version);
     
     bump_instance(env);
+}
+
+// Read and clear the layout 1 ContractVersion slot
+pub fn take_legacy_version_slot(env: &Env) -> Option<u32> {
+    let value = env.storage().instance().get(&DataKey::ContractVersion);
+    env.storage().instance().remove(&DataKey::ContractVersion);
+    value
 }
\ No newline at end of file
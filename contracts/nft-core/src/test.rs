@@ -1,7 +1,42 @@
 #![cfg(test)]
 
-use crate::{NFTCore, NFTMintBatch, types::{NFT, NFTError}};
-use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec, Symbol, IntoVal};
+use crate::{NFTCore, NFTMintBatch, PauseScope, types::{Collection, CollectionMint, CollectionParams, DataKey, NFT, NFTError}};
+use crate::storage::{COLLECTION_PAGE_SIZE, MAX_COLLECTION_QUERY_LIMIT};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String, Vec, Symbol, IntoVal, Val};
+
+extern crate std;
+
+use std::sync::OnceLock;
+
+// Two builds of this contract for the upgrade tests, compiled from the current
+// source on first use. The release and release-with-logs profiles produce
+// different code with the same storage layout.
+static NFT_CORE_WASM: OnceLock<std::vec::Vec<u8>> = OnceLock::new();
+static NFT_CORE_WASM_WITH_LOGS: OnceLock<std::vec::Vec<u8>> = OnceLock::new();
+
+fn nft_core_wasm() -> &'static [u8] {
+    NFT_CORE_WASM.get_or_init(|| build_wasm("release"))
+}
+
+fn nft_core_wasm_with_logs() -> &'static [u8] {
+    NFT_CORE_WASM_WITH_LOGS.get_or_init(|| build_wasm("release-with-logs"))
+}
+
+// Helper: build this crate to WASM in a target directory of its own, so the
+// nested build does not wait on the lock held by the running `cargo test`.
+// Needs the wasm32v1-none target (`rustup target add wasm32v1-none`), which
+// leaves out the WASM features the Soroban VM rejects.
+fn build_wasm(profile: &str) -> std::vec::Vec<u8> {
+    let target_dir = std::format!("{}/../../target/nft-core-test-wasm", env!("CARGO_MANIFEST_DIR"));
+    let status = std::process::Command::new(env!("CARGO"))
+        .args(["build", "-p", "nft-core", "--lib", "--target", "wasm32v1-none", "--profile", profile])
+        .args(["--target-dir", &target_dir])
+        .status()
+        .expect("Failed to run cargo");
+    assert!(status.success(), "Failed to build nft-core with the {} profile", profile);
+    std::fs::read(std::format!("{}/wasm32v1-none/{}/nft_core.wasm", target_dir, profile))
+        .expect("Failed to read the nft-core build")
+}
 
 #[test]
 fn test_initialize() {
@@ -240,9 +275,186 @@ fn test_pause_contract() {
     let version = client.get_contract_version();
     assert!(version > 0);
     
+    // Every scope is paused
+    assert!(client.is_paused(&PauseScope::Mint));
+    assert!(client.is_paused(&PauseScope::Burn));
+    assert!(client.is_paused(&PauseScope::Transfer));
+    assert!(client.is_paused(&PauseScope::Admin));
+    
     // Unpause contract
     let unpause_result = client.unpause_contract(&admin);
     assert!(unpause_result.is_ok());
+    assert!(!client.is_paused(&PauseScope::Mint));
+    assert!(!client.is_paused(&PauseScope::Admin));
+    
+    // Pausing does not overwrite the contract version
+    assert_eq!(client.get_contract_version(), version);
+}
+
+// Negative test: pausing the contract blocks minting
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_pause_blocks_minting() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    client.pause_contract(&admin).expect("Failed to pause");
+    
+    mint_token(&env, &client, &admin, &Address::generate(&env), false);
+}
+
+// Test the mint scope blocks single and batch minting only
+#[test]
+fn test_pause_mint_scope() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, true);
+    client.set_paused(&admin, &PauseScope::Mint, &true).expect("Failed to pause minting");
+    
+    let mint_args = vec![
+        &env,
+        admin.into_val(&env),
+        owner.into_val(&env),
+        String::from_str(&env, "Test NFT").into_val(&env),
+        String::from_str(&env, "This is a test NFT").into_val(&env),
+        Vec::<(String, String)>::new(&env).into_val(&env),
+        true.into_val(&env)
+    ];
+    assert_eq!(client.try_call("mint_nft", mint_args), Err(NFTError::ContractPaused));
+    
+    let batch = NFTMintBatch {
+        recipients: vec![&env, owner.clone()],
+        titles: vec![&env, String::from_str(&env, "NFT 1")],
+        descriptions: vec![&env, String::from_str(&env, "First NFT")],
+        attributes: vec![&env, Vec::new(&env)],
+        transferable: vec![&env, false],
+    };
+    let batch_args = vec![&env, admin.into_val(&env), batch.into_val(&env)];
+    assert_eq!(client.try_call("batch_mint_nfts", batch_args), Err(NFTError::ContractPaused));
+    
+    // Other scopes keep working
+    client.transfer(&owner, &Address::generate(&env), &token_id).expect("Failed to transfer");
+    
+    client.set_paused(&admin, &PauseScope::Mint, &false).expect("Failed to unpause minting");
+    mint_token(&env, &client, &admin, &owner, false);
+}
+
+// Test the burn scope blocks burning only
+#[test]
+fn test_pause_burn_scope() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, false);
+    client.set_paused(&admin, &PauseScope::Burn, &true).expect("Failed to pause burning");
+    
+    let burn_args = vec![&env, owner.into_val(&env), token_id.into_val(&env)];
+    assert_eq!(client.try_call("burn_nft", burn_args), Err(NFTError::ContractPaused));
+    
+    // Other scopes keep working
+    mint_token(&env, &client, &admin, &owner, false);
+    
+    client.set_paused(&admin, &PauseScope::Burn, &false).expect("Failed to unpause burning");
+    client.burn_nft(&owner, &token_id).expect("Failed to burn");
+}
+
+// Test the transfer scope blocks transfers and approvals
+#[test]
+fn test_pause_transfer_scope() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, true);
+    client.set_paused(&admin, &PauseScope::Transfer, &true).expect("Failed to pause transfers");
+    
+    let transfer_args = vec![&env, owner.into_val(&env), other.into_val(&env), token_id.into_val(&env)];
+    assert_eq!(client.try_call("transfer", transfer_args), Err(NFTError::ContractPaused));
+    
+    let transfer_from_args = vec![
+        &env,
+        owner.into_val(&env),
+        owner.into_val(&env),
+        other.into_val(&env),
+        token_id.into_val(&env)
+    ];
+    assert_eq!(client.try_call("transfer_from", transfer_from_args), Err(NFTError::ContractPaused));
+    
+    let approve_args = vec![&env, owner.into_val(&env), Some(other.clone()).into_val(&env), token_id.into_val(&env)];
+    assert_eq!(client.try_call("approve", approve_args), Err(NFTError::ContractPaused));
+    
+    let operator_args = vec![&env, owner.into_val(&env), other.into_val(&env), true.into_val(&env)];
+    assert_eq!(client.try_call("set_approval_for_all", operator_args), Err(NFTError::ContractPaused));
+    
+    // Other scopes keep working
+    mint_token(&env, &client, &admin, &owner, true);
+    
+    client.set_paused(&admin, &PauseScope::Transfer, &false).expect("Failed to unpause transfers");
+    client.transfer(&owner, &other, &token_id).expect("Failed to transfer");
+}
+
+// Test the admin scope blocks configuration changes but not pause controls
+#[test]
+fn test_pause_admin_scope() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let minter = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &Address::generate(&env), false);
+    client.set_paused(&admin, &PauseScope::Admin, &true).expect("Failed to pause admin changes");
+    
+    let uri = String::from_str(&env, "https://example.com/");
+    let add_args = vec![&env, admin.into_val(&env), minter.into_val(&env)];
+    assert_eq!(client.try_call("add_authorized_minter", add_args), Err(NFTError::ContractPaused));
+    let remove_args = vec![&env, admin.into_val(&env), admin.into_val(&env)];
+    assert_eq!(client.try_call("remove_authorized_minter", remove_args), Err(NFTError::ContractPaused));
+    let base_args = vec![&env, admin.into_val(&env), uri.into_val(&env), uri.into_val(&env)];
+    assert_eq!(client.try_call("set_uri_base", base_args), Err(NFTError::ContractPaused));
+    let contract_uri_args = vec![&env, admin.into_val(&env), uri.into_val(&env)];
+    assert_eq!(client.try_call("set_contract_uri", contract_uri_args), Err(NFTError::ContractPaused));
+    let token_uri_args = vec![&env, admin.into_val(&env), token_id.into_val(&env), Some(uri.clone()).into_val(&env)];
+    assert_eq!(client.try_call("set_token_uri", token_uri_args), Err(NFTError::ContractPaused));
+    
+    // Other scopes keep working
+    mint_token(&env, &client, &admin, &admin, false);
+    
+    // Pause controls stay available while the admin scope is paused
+    client.set_paused(&admin, &PauseScope::Admin, &false).expect("Failed to unpause admin changes");
+    client.add_authorized_minter(&admin, &minter).expect("Failed to add minter");
 }
 
 // Test URI functionality
@@ -282,9 +494,143 @@ fn test_uri_functionality() {
     let uri = client.get_token_uri(&token_id);
     assert!(uri.is_some());
     
-    // Verify URI is returned correctly
+    // Verify URI is built from base, token ID and suffix
     let uri_val = uri.unwrap();
-    assert_eq!(uri_val, base_uri);
+    assert_eq!(uri_val, String::from_str(&env, "https://example.com/nfts/1.json"));
+    
+    // Unknown tokens have no URI
+    assert_eq!(client.get_token_uri(&(token_id + 1)), None);
+}
+
+// Test multi-digit token IDs in built URIs
+#[test]
+fn test_token_uri_multi_digit_ids() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    client.set_uri_base(
+        &admin,
+        &String::from_str(&env, "ipfs://collection/"),
+        &String::from_str(&env, "")
+    ).expect("Failed to set URI base");
+    
+    let mut last_id = 0;
+    for _ in 0..10 {
+        last_id = mint_token(&env, &client, &admin, &recipient, false);
+    }
+    
+    assert_eq!(last_id, 10);
+    assert_eq!(client.get_token_uri(&last_id), Some(String::from_str(&env, "ipfs://collection/10")));
+    assert_eq!(client.get_token_uri(&9), Some(String::from_str(&env, "ipfs://collection/9")));
+}
+
+// Test built URIs with legacy base values longer than the current caps
+#[test]
+fn test_token_uri_over_long_legacy_base() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &recipient, false);
+    
+    // Written directly, as a contract from before the length caps could have
+    let long_base = String::from_str(&env, &"a".repeat(crate::storage::MAX_URI_LENGTH as usize + 1));
+    env.as_contract(&contract_id, || {
+        crate::storage::set_uri_base(&env, long_base.clone(), String::from_str(&env, ".json"));
+    });
+    assert_eq!(client.get_token_uri(&token_id), None);
+    
+    let long_suffix = String::from_str(&env, &"s".repeat(crate::storage::MAX_URI_SUFFIX_LENGTH as usize + 1));
+    env.as_contract(&contract_id, || {
+        crate::storage::set_uri_base(&env, String::from_str(&env, "ipfs://base/"), long_suffix.clone());
+    });
+    assert_eq!(client.get_token_uri(&token_id), None);
+    
+    // Setting a valid base again restores built URIs
+    client.set_uri_base(&admin, &String::from_str(&env, "ipfs://base/"), &String::from_str(&env, ".json"))
+        .expect("Failed to set URI base");
+    assert_eq!(client.get_token_uri(&token_id), Some(String::from_str(&env, "ipfs://base/1.json")));
+}
+
+// Test issuer-set URI overrides
+#[test]
+fn test_token_uri_override() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    client.set_uri_base(
+        &admin,
+        &String::from_str(&env, "https://example.com/nfts/"),
+        &String::from_str(&env, ".json")
+    ).expect("Failed to set URI base");
+    let token_id = mint_token(&env, &client, &admin, &recipient, false);
+    
+    // The issuer overrides the URI
+    let custom = String::from_str(&env, "ipfs://custom-badge");
+    client.set_token_uri(&admin, &token_id, &Some(custom.clone())).expect("Failed to set token URI");
+    assert_eq!(client.get_token_uri(&token_id), Some(custom));
+    
+    // Clearing the override falls back to the built URI
+    client.set_token_uri(&admin, &token_id, &None).expect("Failed to clear token URI");
+    assert_eq!(client.get_token_uri(&token_id), Some(String::from_str(&env, "https://example.com/nfts/1.json")));
+}
+
+// Negative test: only the issuer can override a token URI
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_token_uri_override_not_issuer() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &recipient, false);
+    
+    let _ = client.set_token_uri(&recipient, &token_id, &Some(String::from_str(&env, "ipfs://fake")));
+}
+
+// Test collection-level contract URI
+#[test]
+fn test_contract_uri() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    assert_eq!(client.contract_uri(), None);
+    
+    let uri = String::from_str(&env, "https://example.com/collection.json");
+    client.set_contract_uri(&admin, &uri).expect("Failed to set contract URI");
+    assert_eq!(client.contract_uri(), Some(uri));
 }
 
 // Test pagination for NFTs by owner
@@ -500,95 +846,716 @@ fn test_burn_permissions() {
     // that non_owner is not the owner, which would cause the error
 }
 
-// Client for testing
-struct NFTCoreClient<'a> {
-    env: &'a Env,
-    contract_id: Address,
+// Helper: mint a token with empty attributes
+fn mint_token(env: &Env, client: &NFTCoreClient, admin: &Address, recipient: &Address, transferable: bool) -> u128 {
+    let attributes: Vec<(String, String)> = vec![env];
+    client.mint_nft(
+        admin,
+        recipient,
+        &String::from_str(env, "Test NFT"),
+        &String::from_str(env, "This is a test NFT"),
+        &attributes,
+        &transferable
+    ).expect("Failed to mint NFT")
 }
 
-impl<'a> NFTCoreClient<'a> {
-    fn new(env: &'a Env, contract_id: &Address) -> Self {
-        Self { 
-            env, 
-            contract_id: contract_id.clone(),
-        }
-    }
+// Test owner transfer of a transferable token
+#[test]
+fn test_transfer_nft() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let receiver = Address::generate(&env);
     
-    fn initialize(&self, admin: &Address) -> Result<(), NFTError> {
-        let args = vec![
-            self.env,
-            admin.clone().into_val(self.env)
-        ];
-        
-        self.env.invoke_contract(
-            &self.contract_id,
-            &Symbol::new(self.env, "initialize"),
-            args
-        )
-    }
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
     
-    fn mint_nft(
-        &self,
-        minter: &Address,
-        recipient: &Address,
-        title: &String,
-        description: &String,
-        attributes: &Vec<(String, String)>,
-        transferable: &bool
-    ) -> Result<u128, NFTError> {
-        let args = vec![
-            self.env,
-            minter.clone().into_val(self.env),
-            recipient.clone().into_val(self.env),
-            title.clone().into_val(self.env),
-            description.clone().into_val(self.env),
-            attributes.clone().into_val(self.env),
-            (*transferable).into_val(self.env)
-        ];
-        
-        self.env.invoke_contract(
-            &self.contract_id,
-            &Symbol::new(self.env, "mint_nft"),
-            args
-        )
-    }
+    // Authorize transactions for all addresses
+    env.mock_all_auths();
     
-    fn batch_mint_nfts(&self, minter: &Address, batch: &NFTMintBatch) -> Result<Vec<u128>, NFTError> {
-        let args = vec![
-            self.env,
-            minter.clone().into_val(self.env),
-            batch.clone().into_val(self.env)
-        ];
-        
-        self.env.invoke_contract(
-            &self.contract_id,
-            &Symbol::new(self.env, "batch_mint_nfts"),
-            args
-        )
-    }
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, true);
     
-    fn burn_nft(&self, owner: &Address, token_id: &u128) -> Result<(), NFTError> {
-        let args = vec![
-            self.env,
-            owner.clone().into_val(self.env),
-            (*token_id).into_val(self.env)
-        ];
-        
-        self.env.invoke_contract(
-            &self.contract_id,
-            &Symbol::new(self.env, "burn_nft"),
-            args
-        )
-    }
+    // Transfer to the receiver
+    let result = client.transfer(&owner, &receiver, &token_id);
+    assert!(result.is_ok());
     
-    fn get_nft(&self, token_id: &u128) -> Result<NFT, NFTError> {
-        let args = vec![
-            self.env,
-            (*token_id).into_val(self.env)
-        ];
-        
-        self.env.invoke_contract(
-            &self.contract_id,
+    // Verify ownership and both owner indexes were updated
+    let nft = client.get_nft(&token_id).expect("Failed to get NFT");
+    assert_eq!(nft.owner, receiver);
+    assert_eq!(client.get_nfts_by_owner(&owner).len(), 0);
+    assert_eq!(client.get_nfts_by_owner(&receiver).len(), 1);
+}
+
+// Negative test: soulbound tokens cannot be transferred
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_transfer_soulbound_nft() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, false);
+    
+    let _ = client.transfer(&owner, &Address::generate(&env), &token_id);
+}
+
+// Negative test: only the owner can transfer directly
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_transfer_not_owner() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let other = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, true);
+    
+    let _ = client.transfer(&other, &other, &token_id);
+}
+
+// Test single-token approval and transfer_from
+#[test]
+fn test_approve_and_transfer_from() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, true);
+    
+    // Approve the spender for this token
+    client.approve(&owner, &Some(spender.clone()), &token_id).expect("Failed to approve");
+    assert_eq!(client.get_approved(&token_id), Some(spender.clone()));
+    
+    // Spender moves the token
+    let result = client.transfer_from(&spender, &owner, &receiver, &token_id);
+    assert!(result.is_ok());
+    assert_eq!(client.get_nft(&token_id).expect("Failed to get NFT").owner, receiver);
+    
+    // The approval does not survive the transfer
+    assert_eq!(client.get_approved(&token_id), None);
+}
+
+// Test operator approval for all tokens
+#[test]
+fn test_operator_transfer_from() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let receiver = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token1 = mint_token(&env, &client, &admin, &owner, true);
+    let token2 = mint_token(&env, &client, &admin, &owner, true);
+    
+    client.set_approval_for_all(&owner, &operator, &true).expect("Failed to set operator");
+    assert!(client.is_approved_for_all(&owner, &operator));
+    
+    client.transfer_from(&operator, &owner, &receiver, &token1).expect("Failed to transfer token 1");
+    client.transfer_from(&operator, &owner, &receiver, &token2).expect("Failed to transfer token 2");
+    assert_eq!(client.get_nfts_by_owner(&receiver).len(), 2);
+    
+    // Revoking the operator removes the approval
+    client.set_approval_for_all(&owner, &operator, &false).expect("Failed to revoke operator");
+    assert!(!client.is_approved_for_all(&owner, &operator));
+}
+
+// Negative test: transfer_from requires an approval
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_transfer_from_not_approved() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, true);
+    
+    let _ = client.transfer_from(&spender, &owner, &spender, &token_id);
+}
+
+// Negative test: soulbound tokens cannot be approved
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_approve_soulbound_nft() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, false);
+    
+    let _ = client.approve(&owner, &Some(Address::generate(&env)), &token_id);
+}
+
+// Test upgrading between two WASM builds keeps all state
+#[test]
+fn test_upgrade_preserves_state() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    
+    // Deploy the first build
+    let contract_id = env.register(nft_core_wasm(), ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let token_id = mint_token(&env, &client, &admin, &owner, true);
+    client.approve(&owner, &Some(spender.clone()), &token_id).expect("Failed to approve");
+    client.set_paused(&admin, &PauseScope::Burn, &true).expect("Failed to pause burning");
+    
+    // Swap in the second build
+    let new_wasm_hash = env.deployer().upload_contract_wasm(nft_core_wasm_with_logs());
+    client.upgrade(&admin, &new_wasm_hash).expect("Failed to upgrade");
+    
+    // State written by the first build is intact
+    let nft = client.get_nft(&token_id).expect("Failed to get NFT");
+    assert_eq!(nft.owner, owner);
+    assert_eq!(client.get_nfts_by_owner(&owner).len(), 1);
+    assert_eq!(client.get_approved(&token_id), Some(spender.clone()));
+    assert!(client.is_authorized_minter(&admin));
+    assert!(client.is_paused(&PauseScope::Burn));
+    assert_eq!(client.get_contract_version(), 2);
+    
+    // The new build keeps operating on it
+    client.transfer_from(&spender, &owner, &spender, &token_id).expect("Failed to transfer");
+    assert_eq!(mint_token(&env, &client, &admin, &owner, false), token_id + 1);
+    
+    // Both builds share a layout, so there is nothing to migrate
+    let migrate_args = vec![&env, admin.into_val(&env)];
+    assert_eq!(client.try_call("migrate", migrate_args), Err(NFTError::InvalidVersion));
+}
+
+// Test migrating a version 1 deployment that was paused
+#[test]
+fn test_migrate_legacy_paused_layout() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    
+    // Version 1 kept its pause flag in the version slot
+    set_legacy_layout(&env, &contract_id, 1);
+    assert_eq!(client.get_contract_version(), 1);
+    
+    let version = client.migrate(&admin).expect("Failed to migrate");
+    assert_eq!(version, 2);
+    assert_eq!(client.get_contract_version(), 2);
+    assert!(client.is_paused(&PauseScope::Mint));
+    assert!(client.is_paused(&PauseScope::Burn));
+    assert!(client.is_paused(&PauseScope::Transfer));
+    assert!(client.is_paused(&PauseScope::Admin));
+    
+    // The version only moves forward
+    let migrate_args = vec![&env, admin.into_val(&env)];
+    assert_eq!(client.try_call("migrate", migrate_args), Err(NFTError::InvalidVersion));
+}
+
+// Test migrating a version 1 deployment that was not paused
+#[test]
+fn test_migrate_legacy_unpaused_layout() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    set_legacy_layout(&env, &contract_id, 0);
+    
+    assert_eq!(client.migrate(&admin).expect("Failed to migrate"), 2);
+    assert!(!client.is_paused(&PauseScope::Mint));
+    mint_token(&env, &client, &admin, &admin, false);
+}
+
+// Test migrating a version 1 deployment whose slot was set by the old upgrade_contract
+#[test]
+fn test_migrate_legacy_upgraded_layout() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    set_legacy_layout(&env, &contract_id, 3);
+    assert_eq!(client.get_contract_version(), 1);
+    
+    // A slot value other than 1 read as unpaused and is dropped by the migration
+    assert_eq!(client.migrate(&admin).expect("Failed to migrate"), 2);
+    assert!(!client.is_paused(&PauseScope::Admin));
+    assert!(!client.is_paused(&PauseScope::Mint));
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&DataKey::ContractVersion));
+    });
+    mint_token(&env, &client, &admin, &admin, false);
+}
+
+// Helper: rewrite a fresh deployment's storage into the version 1 layout
+fn set_legacy_layout(env: &Env, contract_id: &Address, slot: u32) {
+    env.as_contract(contract_id, || {
+        env.storage().instance().remove(&DataKey::LayoutVersion);
+        env.storage().instance().set(&DataKey::ContractVersion, &slot);
+    });
+}
+
+// Negative test: only the admin can upgrade
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_upgrade_not_admin() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let new_wasm_hash = env.deployer().upload_contract_wasm(nft_core_wasm());
+    
+    let _ = client.upgrade(&Address::generate(&env), &new_wasm_hash);
+}
+
+// Helper: create a collection with series defaults
+fn create_series(env: &Env, client: &NFTCoreClient, issuer: &Address, max_supply: Option<u32>) -> u64 {
+    let default_attributes: Vec<(String, String)> = vec![
+        env,
+        (String::from_str(env, "series"), String::from_str(env, "reforestation")),
+        (String::from_str(env, "year"), String::from_str(env, "2026"))
+    ];
+    let params = CollectionParams {
+        name: String::from_str(env, "2026 Reforestation Series"),
+        max_supply,
+        default_attributes,
+        base_uri: Some(String::from_str(env, "ipfs://reforest/")),
+        uri_suffix: String::from_str(env, ".json"),
+    };
+    client.create_collection(issuer, &params).expect("Failed to create collection")
+}
+
+// Helper: mint a token into a collection
+fn mint_series_token(
+    env: &Env,
+    client: &NFTCoreClient,
+    issuer: &Address,
+    collection_id: u64,
+    recipient: &Address,
+    attributes: &Vec<(String, String)>
+) -> u128 {
+    let params = CollectionMint {
+        collection_id,
+        recipient: recipient.clone(),
+        title: String::from_str(env, "Tree Planter"),
+        description: String::from_str(env, "Planted trees with the series"),
+        attributes: attributes.clone(),
+        transferable: false,
+    };
+    client.mint_into_collection(issuer, &params).expect("Failed to mint into collection")
+}
+
+// Test creating a collection and minting into it
+#[test]
+fn test_create_collection_and_mint() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    client.add_authorized_minter(&admin, &issuer).expect("Failed to add minter");
+    let collection_id = create_series(&env, &client, &issuer, Some(3));
+    
+    let collection = client.get_collection(&collection_id).expect("Failed to get collection");
+    assert_eq!(collection.issuer, issuer);
+    assert_eq!(collection.name, String::from_str(&env, "2026 Reforestation Series"));
+    assert_eq!(collection.max_supply, Some(3));
+    assert_eq!(collection.minted, 0);
+    
+    // Token attributes override the collection defaults
+    let overrides: Vec<(String, String)> = vec![
+        &env,
+        (String::from_str(&env, "year"), String::from_str(&env, "2027"))
+    ];
+    let token1 = mint_series_token(&env, &client, &issuer, collection_id, &recipient, &vec![&env]);
+    let token2 = mint_series_token(&env, &client, &issuer, collection_id, &recipient, &overrides);
+    
+    let attributes1 = client.get_nft(&token1).expect("Failed to get NFT").metadata.attributes;
+    assert_eq!(attributes1.get(String::from_str(&env, "series")), Some(String::from_str(&env, "reforestation")));
+    assert_eq!(attributes1.get(String::from_str(&env, "year")), Some(String::from_str(&env, "2026")));
+    let attributes2 = client.get_nft(&token2).expect("Failed to get NFT").metadata.attributes;
+    assert_eq!(attributes2.get(String::from_str(&env, "year")), Some(String::from_str(&env, "2027")));
+    
+    assert_eq!(client.get_collection(&collection_id).expect("Failed to get collection").minted, 2);
+    assert_eq!(client.get_token_collection(&token1), Some(collection_id));
+    
+    // Tokens minted outside the collection are not listed
+    let loose_token = mint_token(&env, &client, &admin, &recipient, false);
+    assert_eq!(client.get_token_collection(&loose_token), None);
+    
+    // Paginated listing
+    assert_eq!(client.get_tokens_by_collection(&collection_id, &0, &10).len(), 2);
+    let page = client.get_tokens_by_collection(&collection_id, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, token2);
+    assert_eq!(client.get_tokens_by_collection(&collection_id, &2, &10).len(), 0);
+    
+    // The collection base URI is used for its tokens only
+    assert_eq!(client.get_token_uri(&token1), Some(String::from_str(&env, "ipfs://reforest/1.json")));
+    assert_eq!(client.get_token_uri(&loose_token), None);
+}
+
+// Negative test: minting stops at the collection's max supply
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+fn test_collection_supply_enforced() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let collection_id = create_series(&env, &client, &admin, Some(1));
+    let token_id = mint_series_token(&env, &client, &admin, collection_id, &recipient, &vec![&env]);
+    
+    // Burning does not free supply
+    client.burn_nft(&recipient, &token_id).expect("Failed to burn");
+    assert_eq!(client.get_tokens_by_collection(&collection_id, &0, &10).len(), 0);
+    assert_eq!(client.get_token_collection(&token_id), None);
+    
+    mint_series_token(&env, &client, &admin, collection_id, &recipient, &vec![&env]);
+}
+
+// Negative test: only the collection issuer can mint into it
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_mint_into_collection_not_issuer() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let other_minter = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    client.add_authorized_minter(&admin, &other_minter).expect("Failed to add minter");
+    let collection_id = create_series(&env, &client, &admin, None);
+    
+    mint_series_token(&env, &client, &other_minter, collection_id, &other_minter, &vec![&env]);
+}
+
+// Negative test: minting into an unknown collection
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+fn test_mint_into_missing_collection() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    
+    mint_series_token(&env, &client, &admin, 7, &admin, &vec![&env]);
+}
+
+// Negative test: only authorized minters can create collections
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_create_collection_unauthorized() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    
+    create_series(&env, &client, &Address::generate(&env), None);
+}
+
+// Collection listings span several storage pages and cap the requested limit
+#[test]
+fn test_collection_tokens_paged() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    
+    let contract_id = env.register(NFTCore {}, ());
+    let client = NFTCoreClient::new(&env, &contract_id);
+    
+    env.mock_all_auths();
+    
+    client.initialize(&admin).expect("Failed to initialize");
+    let collection_id = create_series(&env, &client, &admin, None);
+    
+    // Fill the first page up to its last two positions with ids of tokens that no longer exist
+    let seeded = COLLECTION_PAGE_SIZE - 2;
+    env.as_contract(&contract_id, || {
+        let mut collection: Collection = env.storage().persistent().get(&DataKey::Collection(collection_id)).unwrap();
+        let mut placeholders: Vec<u128> = Vec::new(&env);
+        for i in 0..seeded {
+            placeholders.push_back(u128::MAX - i as u128);
+        }
+        collection.minted = seeded;
+        env.storage().persistent().set(&DataKey::Collection(collection_id), &collection);
+        env.storage().persistent().set(&DataKey::CollectionTokens(collection_id, 0), &placeholders);
+    });
+    
+    let mut token_ids: Vec<u128> = Vec::new(&env);
+    for _ in 0..6 {
+        token_ids.push_back(mint_series_token(&env, &client, &admin, collection_id, &recipient, &vec![&env]));
+    }
+    
+    // Tokens are split across pages by mint position
+    env.as_contract(&contract_id, || {
+        let first: Vec<u128> = env.storage().persistent().get(&DataKey::CollectionTokens(collection_id, 0)).unwrap();
+        let second: Vec<u128> = env.storage().persistent().get(&DataKey::CollectionTokens(collection_id, 1)).unwrap();
+        assert_eq!(first.len(), COLLECTION_PAGE_SIZE);
+        assert_eq!(second.len(), 4);
+    });
+    
+    // A listing crossing the page boundary keeps mint order
+    let page = client.get_tokens_by_collection(&collection_id, &seeded, &10);
+    assert_eq!(page.len(), 6);
+    for (i, nft) in page.iter().enumerate() {
+        assert_eq!(nft.id, token_ids.get(i as u32).unwrap());
+    }
+    
+    // The limit is clamped, so only the first two minted tokens are reached
+    let start = seeded + 2 - MAX_COLLECTION_QUERY_LIMIT;
+    assert_eq!(client.get_tokens_by_collection(&collection_id, &start, &u32::MAX).len(), 2);
+    
+    // Burned tokens keep their position but are skipped
+    client.burn_nft(&recipient, &token_ids.get(2).unwrap()).expect("Failed to burn");
+    let page = client.get_tokens_by_collection(&collection_id, &COLLECTION_PAGE_SIZE, &10);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().id, token_ids.get(3).unwrap());
+    assert_eq!(client.get_tokens_by_collection(&collection_id, &(seeded + 6), &10).len(), 0);
+}
+
+// Client for testing
+struct NFTCoreClient<'a> {
+    env: &'a Env,
+    contract_id: Address,
+}
+
+impl<'a> NFTCoreClient<'a> {
+    fn new(env: &'a Env, contract_id: &Address) -> Self {
+        Self { 
+            env, 
+            contract_id: contract_id.clone(),
+        }
+    }
+    
+    fn initialize(&self, admin: &Address) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            admin.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "initialize"),
+            args
+        )
+    }
+    
+    fn mint_nft(
+        &self,
+        minter: &Address,
+        recipient: &Address,
+        title: &String,
+        description: &String,
+        attributes: &Vec<(String, String)>,
+        transferable: &bool
+    ) -> Result<u128, NFTError> {
+        let args = vec![
+            self.env,
+            minter.clone().into_val(self.env),
+            recipient.clone().into_val(self.env),
+            title.clone().into_val(self.env),
+            description.clone().into_val(self.env),
+            attributes.clone().into_val(self.env),
+            (*transferable).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "mint_nft"),
+            args
+        )
+    }
+    
+    fn batch_mint_nfts(&self, minter: &Address, batch: &NFTMintBatch) -> Result<Vec<u128>, NFTError> {
+        let args = vec![
+            self.env,
+            minter.clone().into_val(self.env),
+            batch.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "batch_mint_nfts"),
+            args
+        )
+    }
+    
+    fn create_collection(&self, issuer: &Address, params: &CollectionParams) -> Result<u64, NFTError> {
+        let args = vec![
+            self.env,
+            issuer.clone().into_val(self.env),
+            params.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "create_collection"),
+            args
+        )
+    }
+    
+    fn mint_into_collection(&self, minter: &Address, params: &CollectionMint) -> Result<u128, NFTError> {
+        let args = vec![
+            self.env,
+            minter.clone().into_val(self.env),
+            params.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "mint_into_collection"),
+            args
+        )
+    }
+    
+    fn get_collection(&self, collection_id: &u64) -> Result<Collection, NFTError> {
+        let args = vec![
+            self.env,
+            (*collection_id).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "get_collection"),
+            args
+        )
+    }
+    
+    fn get_tokens_by_collection(&self, collection_id: &u64, start_pos: &u32, limit: &u32) -> Vec<NFT> {
+        let args = vec![
+            self.env,
+            (*collection_id).into_val(self.env),
+            (*start_pos).into_val(self.env),
+            (*limit).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "get_tokens_by_collection"),
+            args
+        )
+    }
+    
+    fn get_token_collection(&self, token_id: &u128) -> Option<u64> {
+        let args = vec![
+            self.env,
+            (*token_id).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "get_token_collection"),
+            args
+        )
+    }
+    
+    fn burn_nft(&self, owner: &Address, token_id: &u128) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            owner.clone().into_val(self.env),
+            (*token_id).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "burn_nft"),
+            args
+        )
+    }
+    
+    fn get_nft(&self, token_id: &u128) -> Result<NFT, NFTError> {
+        let args = vec![
+            self.env,
+            (*token_id).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
             &Symbol::new(self.env, "get_nft"),
             args
         )
@@ -622,6 +1589,94 @@ impl<'a> NFTCoreClient<'a> {
         )
     }
     
+    fn transfer(&self, from: &Address, to: &Address, token_id: &u128) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            from.clone().into_val(self.env),
+            to.clone().into_val(self.env),
+            (*token_id).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "transfer"),
+            args
+        )
+    }
+    
+    fn transfer_from(&self, spender: &Address, from: &Address, to: &Address, token_id: &u128) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            spender.clone().into_val(self.env),
+            from.clone().into_val(self.env),
+            to.clone().into_val(self.env),
+            (*token_id).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "transfer_from"),
+            args
+        )
+    }
+    
+    fn approve(&self, caller: &Address, approved: &Option<Address>, token_id: &u128) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            caller.clone().into_val(self.env),
+            approved.clone().into_val(self.env),
+            (*token_id).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "approve"),
+            args
+        )
+    }
+    
+    fn set_approval_for_all(&self, owner: &Address, operator: &Address, approved: &bool) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            owner.clone().into_val(self.env),
+            operator.clone().into_val(self.env),
+            (*approved).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "set_approval_for_all"),
+            args
+        )
+    }
+    
+    fn get_approved(&self, token_id: &u128) -> Option<Address> {
+        let args = vec![
+            self.env,
+            (*token_id).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "get_approved"),
+            args
+        )
+    }
+    
+    fn is_approved_for_all(&self, owner: &Address, operator: &Address) -> bool {
+        let args = vec![
+            self.env,
+            owner.clone().into_val(self.env),
+            operator.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "is_approved_for_all"),
+            args
+        )
+    }
+    
     fn add_authorized_minter(&self, admin: &Address, minter: &Address) -> Result<(), NFTError> {
         let args = vec![
             self.env,
@@ -703,6 +1758,112 @@ impl<'a> NFTCoreClient<'a> {
         )
     }
     
+    fn set_token_uri(&self, issuer: &Address, token_id: &u128, uri: &Option<String>) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            issuer.clone().into_val(self.env),
+            (*token_id).into_val(self.env),
+            uri.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "set_token_uri"),
+            args
+        )
+    }
+    
+    fn set_contract_uri(&self, admin: &Address, uri: &String) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            admin.clone().into_val(self.env),
+            uri.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "set_contract_uri"),
+            args
+        )
+    }
+    
+    fn contract_uri(&self) -> Option<String> {
+        let args = vec![self.env];
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "contract_uri"),
+            args
+        )
+    }
+    
+    fn set_paused(&self, admin: &Address, scope: &PauseScope, paused: &bool) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            admin.clone().into_val(self.env),
+            (*scope).into_val(self.env),
+            (*paused).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "set_paused"),
+            args
+        )
+    }
+    
+    fn is_paused(&self, scope: &PauseScope) -> bool {
+        let args = vec![
+            self.env,
+            (*scope).into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "is_paused"),
+            args
+        )
+    }
+    
+    fn upgrade(&self, admin: &Address, new_wasm_hash: &BytesN<32>) -> Result<(), NFTError> {
+        let args = vec![
+            self.env,
+            admin.clone().into_val(self.env),
+            new_wasm_hash.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "upgrade"),
+            args
+        )
+    }
+    
+    fn migrate(&self, admin: &Address) -> Result<u32, NFTError> {
+        let args = vec![
+            self.env,
+            admin.clone().into_val(self.env)
+        ];
+        
+        self.env.invoke_contract(
+            &self.contract_id,
+            &Symbol::new(self.env, "migrate"),
+            args
+        )
+    }
+    
+    // Invoke a mutating function, returning its contract error instead of panicking
+    fn try_call(&self, function: &str, args: Vec<Val>) -> Result<(), NFTError> {
+        match self.env.try_invoke_contract::<Val, NFTError>(
+            &self.contract_id,
+            &Symbol::new(self.env, function),
+            args
+        ) {
+            Ok(_) => Ok(()),
+            Err(Ok(error)) => Err(error),
+            Err(Err(error)) => panic!("Invocation failed: {:?}", error),
+        }
+    }
+    
     fn get_contract_version(&self) -> u32 {
         let args = vec![self.env];
         self.env.invoke_contract(
@@ -0,0 +1,156 @@
+use crate::admin::verify_not_paused;
+use crate::storage::{
+    bump_instance, bump_nft, get_approved, get_nft, is_approved_for_all, remove_approval, remove_owner_token,
+    save_nft, set_approval, set_operator_approval,
+};
+use crate::types::{NFT, NFTError, PauseScope};
+use soroban_sdk::{Address, Env, log};
+
+// Transfer a token owned by the caller
+// # Security considerations
+// - Only the owner can transfer directly
+// - Soulbound (non-transferable) tokens can never move
+// - Any single-token approval is cleared on transfer
+
+pub fn transfer(env: &Env, from: Address, to: Address, token_id: u128) -> Result<(), NFTError> {
+    verify_not_paused(env, PauseScope::Transfer)?;
+
+    let nft = get_nft(env, token_id)?;
+
+    // Verify ownership
+    if nft.owner != from {
+        return Err(NFTError::NotTokenOwner);
+    }
+
+    // Require owner authorization
+    from.require_auth();
+
+    move_token(env, nft, to)
+}
+
+// Transfer a token on the owner's behalf
+// # Security considerations
+// - The spender must be the owner, the token's approved address or an operator of the owner
+// - Requires authorization from the spender
+
+pub fn transfer_from(
+    env: &Env,
+    spender: Address,
+    from: Address,
+    to: Address,
+    token_id: u128,
+) -> Result<(), NFTError> {
+    verify_not_paused(env, PauseScope::Transfer)?;
+
+    let nft = get_nft(env, token_id)?;
+
+    if nft.owner != from {
+        return Err(NFTError::NotTokenOwner);
+    }
+
+    if !is_owner_or_approved(env, &spender, &nft) {
+        return Err(NFTError::Unauthorized);
+    }
+
+    // Require spender authorization
+    spender.require_auth();
+
+    move_token(env, nft, to)
+}
+
+// Approve an address to transfer a single token, or clear the approval with None
+// # Security considerations
+// - Only the owner or one of its operators can approve
+// - Soulbound tokens cannot be approved
+
+pub fn approve(env: &Env, caller: Address, approved: Option<Address>, token_id: u128) -> Result<(), NFTError> {
+    verify_not_paused(env, PauseScope::Transfer)?;
+
+    let nft = get_nft(env, token_id)?;
+
+    if nft.owner != caller && !is_approved_for_all(env, &nft.owner, &caller) {
+        return Err(NFTError::Unauthorized);
+    }
+
+    if !nft.transferable {
+        return Err(NFTError::TokenNotTransferable);
+    }
+
+    // Require caller authorization
+    caller.require_auth();
+
+    match &approved {
+        Some(address) => set_approval(env, token_id, address),
+        None => remove_approval(env, token_id),
+    }
+
+    // Emit approval event
+    env.events().publish(
+        ("approve", token_id),
+        (nft.owner.clone(), approved)
+    );
+
+    Ok(())
+}
+
+// Grant or revoke an operator's right to transfer all of the owner's tokens
+
+pub fn set_approval_for_all(env: &Env, owner: Address, operator: Address, approved: bool) -> Result<(), NFTError> {
+    verify_not_paused(env, PauseScope::Transfer)?;
+
+    if owner == operator {
+        return Err(NFTError::InvalidAddress);
+    }
+
+    // Require owner authorization
+    owner.require_auth();
+
+    set_operator_approval(env, &owner, &operator, approved);
+
+    // Emit operator approval event
+    env.events().publish(
+        ("approve_all", owner.clone()),
+        (operator, approved)
+    );
+
+    Ok(())
+}
+
+// Helper: the owner, the token's approved address and the owner's operators may transfer
+fn is_owner_or_approved(env: &Env, spender: &Address, nft: &NFT) -> bool {
+    *spender == nft.owner
+        || get_approved(env, nft.id).as_ref() == Some(spender)
+        || is_approved_for_all(env, &nft.owner, spender)
+}
+
+// Helper: move a token to a new owner, updating both owner indexes
+fn move_token(env: &Env, mut nft: NFT, to: Address) -> Result<(), NFTError> {
+    if !nft.transferable {
+        return Err(NFTError::TokenNotTransferable);
+    }
+
+    if nft.owner == to {
+        return Err(NFTError::InvalidRecipient);
+    }
+
+    let from = nft.owner.clone();
+    remove_owner_token(env, &from, nft.id);
+    remove_approval(env, nft.id);
+
+    nft.owner = to.clone();
+    save_nft(env, &nft);
+
+    // Extend storage lifetime
+    bump_instance(env);
+    bump_nft(env, nft.id);
+
+    // Emit transfer event
+    env.events().publish(
+        ("transfer", nft.id),
+        (from.clone(), to.clone())
+    );
+
+    log!(env, "NFT transferred: {} from {} to {}", nft.id, from, to);
+
+    Ok(())
+}
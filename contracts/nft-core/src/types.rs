@@ -7,11 +7,38 @@ pub enum DataKey {
     TokenCount,                 // Counter for token IDs
     Admin,                      // Admin address
     AuthorizedMinters,          // List of authorized minters
-    ContractVersion,            // Contract version for upgrades
+    ContractVersion,            // Layout 1 only: slot shared by the pause flag and upgrade_contract
+    LayoutVersion,              // Storage layout version, written from layout 2 on
     URIBase,                    // Base URI for external metadata
-    Paused,                     // Contract pause status
+    Paused(PauseScope),         // Pause status of one group of operations
+    Approval(u128),             // Maps token ID to the address approved to transfer it
+    OperatorApproval(Address, Address), // Maps (owner, operator) to approval for all tokens
+    TokenURI(u128),             // Maps token ID to an issuer-set URI overriding the base URI
+    ContractURI,                // URI of collection-level metadata
+    Collection(u64),            // Maps collection ID to Collection
+    CollectionCount,            // Counter for collection IDs
+    CollectionTokens(u64, u32), // Maps collection ID and page number to token IDs in mint order
+    TokenCollection(u128),      // Maps token ID to the collection it was minted into
 }
 
+// Groups of operations that can be paused independently
+#[contracttype]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u32)]
+pub enum PauseScope {
+    Mint = 0,       // mint_nft, batch_mint_nfts, create_collection, mint_into_collection
+    Burn = 1,       // burn_nft
+    Transfer = 2,   // transfer, transfer_from, approve, set_approval_for_all
+    Admin = 3,      // Minter, URI and token URI changes
+}
+
+pub const ALL_PAUSE_SCOPES: [PauseScope; 4] = [
+    PauseScope::Mint,
+    PauseScope::Burn,
+    PauseScope::Transfer,
+    PauseScope::Admin,
+];
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct NFT {
@@ -32,6 +59,44 @@ pub struct NFTMetadata {
     pub attributes: Map<String, String>,
 }
 
+// Named series of tokens minted by a single issuer
+#[contracttype]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Collection {
+    pub id: u64,
+    pub issuer: Address,
+    pub name: String,
+    pub max_supply: Option<u32>,            // None for an unlimited series
+    pub minted: u32,                        // Tokens ever minted; burning does not free supply
+    pub default_attributes: Map<String, String>, // Applied to every token unless overridden at mint
+    pub base_uri: Option<String>,           // Replaces the contract base URI for this collection's tokens
+    pub uri_suffix: String,
+    pub created_at: u64,
+}
+
+// Settings of a new collection
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct CollectionParams {
+    pub name: String,
+    pub max_supply: Option<u32>,            // None for an unlimited series
+    pub default_attributes: Vec<(String, String)>,
+    pub base_uri: Option<String>,
+    pub uri_suffix: String,
+}
+
+// A token to mint into an existing collection
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct CollectionMint {
+    pub collection_id: u64,
+    pub recipient: Address,
+    pub title: String,
+    pub description: String,
+    pub attributes: Vec<(String, String)>, // Override the collection defaults with the same key
+    pub transferable: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct NFTMintBatch {
@@ -72,4 +137,7 @@ pub enum NFTError {
     InvalidRecipient = 18,
     BatchTooLarge = 19,
     ContractError = 20,
+    InvalidVersion = 21,
+    CollectionNotFound = 22,
+    CollectionSupplyExceeded = 23,
 }
\ No newline at end of file
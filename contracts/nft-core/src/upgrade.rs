@@ -0,0 +1,82 @@
+use crate::admin::verify_admin;
+use crate::storage::{bump_instance, get_contract_version, set_contract_version, set_paused, take_legacy_version_slot};
+use crate::types::{NFTError, ALL_PAUSE_SCOPES};
+use soroban_sdk::{Address, BytesN, Env, log};
+
+// Storage layout version of this code
+// - 1: no LayoutVersion entry. The pause flag and the old `upgrade_contract`
+//   shared the ContractVersion slot, and the contract was paused only while it
+//   held 1: 0 or any version number written by `upgrade_contract` read as unpaused
+// - 2: per-scope pause flags under DataKey::Paused, version under DataKey::LayoutVersion
+pub const CURRENT_CONTRACT_VERSION: u32 = 2;
+
+// Replace the contract code with an uploaded WASM
+// # Security considerations
+// - Only the admin can upgrade
+// - Never blocked by pausing so a paused contract can always be fixed
+// - Storage is left untouched; call `migrate` once the new code is live
+
+pub fn upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), NFTError> {
+    // Verify admin authorization
+    verify_admin(env, &admin)?;
+
+    env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+    // Emit upgrade event
+    env.events().publish(
+        ("upgrade", admin.clone()),
+        new_wasm_hash
+    );
+
+    log!(env, "Contract code upgraded by admin: {}", admin);
+
+    Ok(())
+}
+
+// Run every migration between the stored version and CURRENT_CONTRACT_VERSION
+// # Security considerations
+// - Only the admin can migrate
+// - The version only moves forward; migrating an up-to-date or newer layout fails
+
+pub fn migrate(env: &Env, admin: Address) -> Result<u32, NFTError> {
+    // Verify admin authorization
+    verify_admin(env, &admin)?;
+
+    let from_version = get_contract_version(env);
+    if from_version >= CURRENT_CONTRACT_VERSION {
+        return Err(NFTError::InvalidVersion);
+    }
+
+    let mut version = from_version;
+    while version < CURRENT_CONTRACT_VERSION {
+        version = migrate_step(env, version)?;
+    }
+
+    set_contract_version(env, version);
+    bump_instance(env);
+
+    // Emit migration event
+    env.events().publish(
+        ("migrate", from_version),
+        version
+    );
+
+    log!(env, "Contract migrated from version {} to {}", from_version, version);
+
+    Ok(version)
+}
+
+// Helper: apply the migration out of `version`, returning the version it produces
+fn migrate_step(env: &Env, version: u32) -> Result<u32, NFTError> {
+    match version {
+        // v1 -> v2: carry a legacy pause over to every scope and drop the shared slot
+        1 => {
+            let was_paused = take_legacy_version_slot(env) == Some(1);
+            for scope in ALL_PAUSE_SCOPES {
+                set_paused(env, scope, was_paused);
+            }
+            Ok(2)
+        }
+        _ => Err(NFTError::InvalidVersion),
+    }
+}
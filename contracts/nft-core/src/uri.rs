@@ -0,0 +1,41 @@
+use crate::admin::verify_not_paused;
+use crate::storage::{get_nft, remove_token_uri_override, set_token_uri_override, MAX_URI_LENGTH};
+use crate::types::{NFTError, PauseScope};
+use soroban_sdk::{Address, Env, String};
+
+// Set or clear (with None) a URI overriding the base URI for one token
+// # Security considerations
+// - Only the token's issuer can change its URI
+// - URI length is bounded like the base URI
+
+pub fn set_token_uri(env: &Env, issuer: Address, token_id: u128, uri: Option<String>) -> Result<(), NFTError> {
+    verify_not_paused(env, PauseScope::Admin)?;
+
+    let nft = get_nft(env, token_id)?;
+
+    // Verify the caller issued the token
+    if nft.metadata.issuer != issuer {
+        return Err(NFTError::Unauthorized);
+    }
+
+    // Require issuer authorization
+    issuer.require_auth();
+
+    match &uri {
+        Some(value) => {
+            if value.len() > MAX_URI_LENGTH {
+                return Err(NFTError::MetadataTooLarge);
+            }
+            set_token_uri_override(env, token_id, value);
+        }
+        None => remove_token_uri_override(env, token_id),
+    }
+
+    // Emit URI update event
+    env.events().publish(
+        ("token_uri", token_id),
+        (issuer.clone(), uri)
+    );
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::error::ContractError;
+use crate::events;
+use crate::storage;
+
+pub const MAX_CO_ADMINS: u32 = 10;
+
+/// First step of an admin transfer. Proposing again replaces the previous
+/// candidate; the transfer only takes effect once the candidate accepts.
+pub fn propose_admin(env: &Env, admin: &Address, new_admin: &Address) -> Result<(), ContractError> {
+    storage::check_primary_admin(env, admin)?;
+
+    storage::set_pending_admin(env, new_admin);
+    events::admin_proposed(env, admin, new_admin);
+
+    Ok(())
+}
+
+pub fn accept_admin(env: &Env, new_admin: &Address) -> Result<(), ContractError> {
+    new_admin.require_auth();
+
+    match storage::get_pending_admin(env) {
+        Some(pending) if pending == *new_admin => {}
+        Some(_) => return Err(ContractError::NotAuthorized),
+        None => return Err(ContractError::NoPendingAdmin),
+    }
+
+    let previous = storage::get_admin(env);
+    storage::set_admin(env, new_admin);
+    storage::remove_pending_admin(env);
+
+    // The new admin should not also linger in the co-admin list
+    let mut co_admins = storage::get_co_admins(env);
+    if let Some(index) = co_admins.first_index_of(new_admin) {
+        co_admins.remove(index);
+        storage::set_co_admins(env, &co_admins);
+    }
+
+    events::admin_changed(env, &previous, new_admin);
+
+    Ok(())
+}
+
+pub fn add_co_admin(env: &Env, admin: &Address, co_admin: &Address) -> Result<(), ContractError> {
+    storage::check_primary_admin(env, admin)?;
+    if co_admin == admin {
+        return Err(ContractError::InvalidAddress);
+    }
+
+    let mut co_admins = storage::get_co_admins(env);
+    if !co_admins.contains(co_admin) {
+        if co_admins.len() >= MAX_CO_ADMINS {
+            return Err(ContractError::TooManyCoAdmins);
+        }
+        co_admins.push_back(co_admin.clone());
+        storage::set_co_admins(env, &co_admins);
+        events::co_admin_updated(env, co_admin, true);
+    }
+
+    Ok(())
+}
+
+pub fn remove_co_admin(env: &Env, admin: &Address, co_admin: &Address) -> Result<(), ContractError> {
+    storage::check_primary_admin(env, admin)?;
+
+    let mut co_admins = storage::get_co_admins(env);
+    let index = co_admins.first_index_of(co_admin).ok_or(ContractError::CoAdminNotFound)?;
+    co_admins.remove(index);
+    storage::set_co_admins(env, &co_admins);
+    events::co_admin_updated(env, co_admin, false);
+
+    Ok(())
+}
+
+pub fn get_co_admins(env: &Env) -> Vec<Address> {
+    storage::get_co_admins(env)
+}
+
+/// Pausing blocks every state change except admin management; reads keep working.
+pub fn set_paused(env: &Env, admin: &Address, paused: bool) -> Result<(), ContractError> {
+    storage::check_admin(env, admin)?;
+
+    storage::set_paused(env, paused);
+    events::pause_changed(env, admin, paused);
+
+    Ok(())
+}
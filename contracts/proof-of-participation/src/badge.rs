@@ -0,0 +1,190 @@
+use soroban_sdk::{Address, Env, String, Vec, contractclient, contracttype};
+
+use crate::error::ContractError;
+use crate::events;
+use crate::participation_storage::{self, Participation};
+use crate::storage::{self, DataKey, ParticipationKey, bump_instance_ttl, bump_persistent_ttl};
+
+const BADGE_DESCRIPTION: &str = "Proof of participation";
+const TASK_ID_ATTRIBUTE: &str = "task_id";
+const ORGANIZATION_ATTRIBUTE: &str = "organization";
+
+// nft-core contract minting soulbound badges for participations. With
+// `auto_mint` set, badges are minted at registration; otherwise volunteers
+// claim them through `claim_participation_badge`. A (volunteer, task) pair gets
+// at most one badge: it outlives a revocation and is reattached if the
+// participation is registered again.
+//
+// Badges cannot be burned without the volunteer's signature, so a revoked
+// participation's badge stays in the volunteer's wallet. Verifiers check it
+// through `verify_participation_badge`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BadgeConfig {
+    pub nft_contract: Option<Address>,
+    pub auto_mint: bool,
+}
+
+/// Subset of the nft-core interface used for badges. This contract must be
+/// one of nft-core's authorized minters.
+#[allow(dead_code)]
+#[contractclient(name = "NftCoreClient")]
+pub trait NftCoreInterface {
+    fn mint_nft(
+        env: Env,
+        minter: Address,
+        recipient: Address,
+        title: String,
+        description: String,
+        attributes: Vec<(String, String)>,
+        transferable: bool,
+    ) -> u128;
+}
+
+pub fn get_badge_config(env: &Env) -> BadgeConfig {
+    env.storage().instance().get(&DataKey::BadgeConfig).unwrap_or(BadgeConfig {
+        nft_contract: None,
+        auto_mint: false,
+    })
+}
+
+pub fn set_badge_config(env: &Env, admin: &Address, config: &BadgeConfig) -> Result<(), ContractError> {
+    storage::check_admin(env, admin)?;
+
+    env.storage().instance().set(&DataKey::BadgeConfig, config);
+    bump_instance_ttl(env);
+    events::badge_config_updated(env, config);
+
+    Ok(())
+}
+
+/// Mints a badge for a newly registered participation when auto-minting is
+/// enabled. Like the other hooks, a failed mint never blocks the registration;
+/// the volunteer can claim the badge later instead.
+pub fn on_participation_registered(env: &Env, volunteer: &Address, task_id: &String) {
+    let config = get_badge_config(env);
+    let Some(nft_contract) = config.nft_contract else {
+        return;
+    };
+    if !config.auto_mint {
+        return;
+    }
+    let Some(participation) = participation_storage::get_participation(env, volunteer, task_id) else {
+        return;
+    };
+
+    if mint_badge(env, &nft_contract, participation).is_none() {
+        events::hook_failed(env, &nft_contract, volunteer);
+    }
+}
+
+/// Mints the badge for an existing participation on the volunteer's request.
+/// Claiming an already minted badge returns its token id.
+pub fn claim_participation_badge(env: &Env, volunteer: &Address, task_id: &String) -> Result<u128, ContractError> {
+    storage::require_not_paused(env)?;
+
+    volunteer.require_auth();
+
+    let participation = participation_storage::get_participation(env, volunteer, task_id)
+        .ok_or(ContractError::ParticipationNotFound)?;
+    if let Some(token_id) = participation.badge_token_id {
+        return Ok(token_id);
+    }
+    if let Some(token_id) = get_badge_token(env, volunteer, task_id) {
+        attach_badge(env, participation, token_id);
+        return Ok(token_id);
+    }
+
+    let nft_contract = get_badge_config(env).nft_contract.ok_or(ContractError::BadgeUnavailable)?;
+    mint_badge(env, &nft_contract, participation).ok_or(ContractError::BadgeUnavailable)
+}
+
+/// Flags the badge of a participation that is being revoked. The token stays
+/// with the volunteer but no longer verifies.
+pub fn on_participation_revoked(env: &Env, participation: &Participation) {
+    if let Some(token_id) = participation.badge_token_id {
+        events::badge_revoked(env, &participation.volunteer, &participation.task_id, token_id);
+    }
+}
+
+/// Resolves a badge token to the participation it was minted for. Badges of
+/// revoked participations do not verify.
+pub fn verify_participation_badge(env: &Env, token_id: u128) -> Result<Participation, ContractError> {
+    let p_key: ParticipationKey = env
+        .storage()
+        .persistent()
+        .get(&DataKey::BadgeParticipation(token_id))
+        .ok_or(ContractError::ParticipationNotFound)?;
+    participation_storage::get_participation(env, &p_key.volunteer, &p_key.task_id)
+        .ok_or(ContractError::ParticipationNotFound)
+}
+
+fn get_badge_token(env: &Env, volunteer: &Address, task_id: &String) -> Option<u128> {
+    let p_key = ParticipationKey {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+    };
+    env.storage().persistent().get(&DataKey::ParticipationBadge(p_key))
+}
+
+fn store_badge_token(env: &Env, participation: &Participation, token_id: u128) {
+    let p_key = ParticipationKey {
+        volunteer: participation.volunteer.clone(),
+        task_id: participation.task_id.clone(),
+    };
+    let badge_key = DataKey::ParticipationBadge(p_key.clone());
+    env.storage().persistent().set(&badge_key, &token_id);
+    bump_persistent_ttl(env, &badge_key);
+
+    let token_key = DataKey::BadgeParticipation(token_id);
+    env.storage().persistent().set(&token_key, &p_key);
+    bump_persistent_ttl(env, &token_key);
+}
+
+fn attach_badge(env: &Env, mut participation: Participation, token_id: u128) {
+    participation.badge_token_id = Some(token_id);
+    participation_storage::update_participation(env, &participation);
+}
+
+// Mints a non-transferable token to the volunteer and records its id on the
+// participation, or reattaches the badge minted for an earlier registration of
+// the same task. Returns None if nft-core rejects the mint.
+fn mint_badge(env: &Env, nft_contract: &Address, participation: Participation) -> Option<u128> {
+    if let Some(token_id) = get_badge_token(env, &participation.volunteer, &participation.task_id) {
+        attach_badge(env, participation, token_id);
+        return Some(token_id);
+    }
+
+    let mut attributes = Vec::new(env);
+    attributes.push_back((String::from_str(env, TASK_ID_ATTRIBUTE), participation.task_id.clone()));
+    attributes.push_back((
+        String::from_str(env, ORGANIZATION_ATTRIBUTE),
+        participation.organization.to_string(),
+    ));
+
+    // nft-core rejects empty titles; tasks created without a name use their id
+    let title = if participation.task_name.is_empty() {
+        &participation.task_id
+    } else {
+        &participation.task_name
+    };
+
+    let client = NftCoreClient::new(env, nft_contract);
+    let token_id = match client.try_mint_nft(
+        &env.current_contract_address(),
+        &participation.volunteer,
+        title,
+        &String::from_str(env, BADGE_DESCRIPTION),
+        &attributes,
+        &false,
+    ) {
+        Ok(Ok(token_id)) => token_id,
+        _ => return None,
+    };
+
+    store_badge_token(env, &participation, token_id);
+    events::badge_minted(env, &participation.volunteer, &participation.task_id, token_id);
+    attach_badge(env, participation, token_id);
+
+    Some(token_id)
+}
@@ -7,6 +7,10 @@ pub enum ContractError {
     // --- General Errors ---
     AlreadyInitialized = 1,
     NotAuthorized = 2, // Also used for non-admin actions
+    ContractPaused = 3,
+    NoPendingAdmin = 4,
+    TooManyCoAdmins = 5,
+    CoAdminNotFound = 6,
 
     // --- Input Validation Errors ---
     TaskNameTooLong = 10,
@@ -19,13 +23,44 @@ pub enum ContractError {
     OrganizationNameTooLong = 17,
     OrganizationNameEmpty = 18,
     InvalidAddress = 19,
+    ReasonTooLong = 20, // Revocation or rejection reason
+    ReasonEmpty = 21,
+    TaskDescriptionTooLong = 22,
+    InvalidTaskCapacity = 23,
+    InvalidBatchSize = 24, // Empty or above MAX_BATCH_SIZE
+    EvidenceUriTooLong = 25,
+    WebsiteTooLong = 26,
 
     // --- Organization Errors ---
     OrganizationAlreadyRegistered = 101,
     OrganizationNotRegistered = 102,
     TooManyOrganizations = 103,
+    DelegateNotFound = 104,
+    TooManyDelegates = 105,
+    ApplicationAlreadyPending = 106,
+    ApplicationNotFound = 107,
+    TooManyPendingApplications = 108,
+    OrganizationDeactivated = 109,
 
     // --- Participation Errors ---
     ParticipationAlreadyRegistered = 201,
     ParticipationNotFound = 202,
+    AttestationAlreadyPending = 203,
+    AttestationNotFound = 204,
+    TooManyPendingAttestations = 205,
+    AlreadyCheckedOut = 206,
+    InvalidCheckOutTime = 207,
+    ParticipationIndexFull = 208,
+    ProofNotFound = 209,
+    EvidenceLimitReached = 210,
+    BadgeUnavailable = 211, // Badges not configured or the mint was rejected
+
+    // --- Task Errors ---
+    TaskAlreadyExists = 301,
+    TaskNotFound = 302,
+    TaskNotOwnedByOrganization = 303,
+    TaskNotOpen = 304,
+    TaskCapacityReached = 305,
+    TaskOutsideTimeWindow = 306,
+    TooManyTasks = 307,
 }
\ No newline at end of file
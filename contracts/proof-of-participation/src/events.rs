@@ -1,9 +1,83 @@
 use soroban_sdk::{Address, Env, String, Symbol};
 
+use crate::badge::BadgeConfig;
+use crate::hooks::HookConfig;
+use crate::organization_storage::{ApplicationStatus, OrganizationProfile, OrganizationRole, OrganizationStatus};
+use crate::participation_storage::{Evidence, ParticipationRole};
+use crate::task_storage::TaskStatus;
+
+const ADMIN_PROPOSED_TOPIC: &str = "pop_admin_proposed_v1";
+const ADMIN_CHANGED_TOPIC: &str = "pop_admin_changed_v1";
+const CO_ADMIN_TOPIC: &str = "pop_co_admin_v1";
+const PAUSE_TOPIC: &str = "pop_paused_v1";
+const HOOK_CONFIG_TOPIC: &str = "pop_hook_config_v1";
+const HOOK_FAILED_TOPIC: &str = "pop_hook_failed_v1";
+const BADGE_CONFIG_TOPIC: &str = "pop_badge_config_v1";
+const BADGE_MINTED_TOPIC: &str = "pop_badge_minted_v1";
+const BADGE_REVOKED_TOPIC: &str = "pop_badge_revoked_v1";
 const ORG_REGISTERED_TOPIC: &str = "pop_org_registered_v1";
-const ORG_REMOVED_TOPIC: &str = "pop_org_removed_v1";
+const ORG_STATUS_TOPIC: &str = "pop_org_status_v1";
+const ORG_PROFILE_TOPIC: &str = "pop_org_profile_v1";
+const ORG_DELEGATE_TOPIC: &str = "pop_org_delegate_v1";
+const ORG_APPLICATION_TOPIC: &str = "pop_org_application_v1";
 const PART_REGISTERED_TOPIC: &str = "pop_part_registered_v1";
+const PART_REVOKED_TOPIC: &str = "pop_part_revoked_v1";
+const PART_PROPOSED_TOPIC: &str = "pop_part_proposed_v1";
+const PART_ACCEPTED_TOPIC: &str = "pop_part_accepted_v1";
+const PART_REJECTED_TOPIC: &str = "pop_part_rejected_v1";
+const PART_CHECKED_IN_TOPIC: &str = "pop_part_checked_in_v1";
+const PART_CHECKED_OUT_TOPIC: &str = "pop_part_checked_out_v1";
+const PART_ROLE_TOPIC: &str = "pop_part_role_v1";
+const PART_EVIDENCE_TOPIC: &str = "pop_part_evidence_v1";
+const TASK_CREATED_TOPIC: &str = "pop_task_created_v1";
+const TASK_STATUS_TOPIC: &str = "pop_task_status_v1";
+
+
+pub fn admin_proposed(env: &Env, admin: &Address, new_admin: &Address) {
+    let topics = (Symbol::new(env, ADMIN_PROPOSED_TOPIC), admin.clone());
+    env.events().publish(topics, new_admin.clone());
+}
+
+pub fn admin_changed(env: &Env, previous: &Address, new_admin: &Address) {
+    let topics = (Symbol::new(env, ADMIN_CHANGED_TOPIC), previous.clone());
+    env.events().publish(topics, new_admin.clone());
+}
+
+pub fn co_admin_updated(env: &Env, co_admin: &Address, added: bool) {
+    let topics = (Symbol::new(env, CO_ADMIN_TOPIC), co_admin.clone());
+    env.events().publish(topics, added);
+}
 
+pub fn pause_changed(env: &Env, admin: &Address, paused: bool) {
+    let topics = (Symbol::new(env, PAUSE_TOPIC), admin.clone());
+    env.events().publish(topics, paused);
+}
+
+pub fn hook_config_updated(env: &Env, config: &HookConfig) {
+    let topics = (Symbol::new(env, HOOK_CONFIG_TOPIC),);
+    env.events().publish(topics, config.clone());
+}
+
+/// Emitted when a downstream hook call fails; the triggering action still succeeds.
+pub fn hook_failed(env: &Env, target: &Address, volunteer: &Address) {
+    let topics = (Symbol::new(env, HOOK_FAILED_TOPIC), target.clone());
+    env.events().publish(topics, volunteer.clone());
+}
+
+pub fn badge_config_updated(env: &Env, config: &BadgeConfig) {
+    let topics = (Symbol::new(env, BADGE_CONFIG_TOPIC),);
+    env.events().publish(topics, config.clone());
+}
+
+pub fn badge_minted(env: &Env, volunteer: &Address, task_id: &String, token_id: u128) {
+    let topics = (Symbol::new(env, BADGE_MINTED_TOPIC), volunteer.clone());
+    env.events().publish(topics, (task_id.clone(), token_id));
+}
+
+pub fn badge_revoked(env: &Env, volunteer: &Address, task_id: &String, token_id: u128) {
+    let topics = (Symbol::new(env, BADGE_REVOKED_TOPIC), volunteer.clone());
+    env.events().publish(topics, (task_id.clone(), token_id));
+}
 
 pub fn organization_registered(env: &Env, organization: &Address, name: &String) {
     let topics = (Symbol::new(env, ORG_REGISTERED_TOPIC), organization.clone()); 
@@ -11,9 +85,31 @@ pub fn organization_registered(env: &Env, organization: &Address, name: &String)
 }
 
 
-pub fn organization_removed(env: &Env, organization: &Address) {
-    let topics = (Symbol::new(env, ORG_REMOVED_TOPIC), organization.clone());
-    env.events().publish(topics, ());
+pub fn organization_status_changed(env: &Env, organization: &Address, status: OrganizationStatus, timestamp: u64) {
+    let topics = (Symbol::new(env, ORG_STATUS_TOPIC), organization.clone());
+    env.events().publish(topics, (status, timestamp));
+}
+
+pub fn organization_profile_updated(env: &Env, organization: &Address, profile: &OrganizationProfile) {
+    let topics = (Symbol::new(env, ORG_PROFILE_TOPIC), organization.clone());
+    env.events().publish(topics, profile.clone());
+}
+
+/// `role` is None when the delegate was removed.
+pub fn organization_delegate_set(env: &Env, organization: &Address, delegate: &Address, role: Option<OrganizationRole>) {
+    let topics = (Symbol::new(env, ORG_DELEGATE_TOPIC), organization.clone(), delegate.clone());
+    env.events().publish(topics, role);
+}
+
+/// `reason` is only set for rejections.
+pub fn organization_application_updated(
+    env: &Env,
+    organization: &Address,
+    status: ApplicationStatus,
+    reason: &Option<String>,
+) {
+    let topics = (Symbol::new(env, ORG_APPLICATION_TOPIC), organization.clone());
+    env.events().publish(topics, (status, reason.clone()));
 }
 
 pub fn participation_registered(
@@ -35,4 +131,144 @@ pub fn participation_registered(
     let data = (task_name.clone(), timestamp, metadata.clone());
 
     env.events().publish(topics, data);
-}
\ No newline at end of file
+}
+
+pub fn participation_revoked(
+    env: &Env,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    revoked_by: &Address,
+    reason: &String,
+    timestamp: u64,
+) {
+    let topics = (
+        Symbol::new(env, PART_REVOKED_TOPIC),
+        organization.clone(),
+        volunteer.clone(),
+        task_id.clone(),
+    );
+
+    let data = (revoked_by.clone(), reason.clone(), timestamp);
+
+    env.events().publish(topics, data);
+}
+
+pub fn participation_proposed(
+    env: &Env,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    task_name: &String,
+    timestamp: u64,
+) {
+    let topics = (
+        Symbol::new(env, PART_PROPOSED_TOPIC),
+        organization.clone(),
+        volunteer.clone(),
+        task_id.clone(),
+    );
+    env.events().publish(topics, (task_name.clone(), timestamp));
+}
+
+pub fn participation_accepted(env: &Env, organization: &Address, volunteer: &Address, task_id: &String) {
+    let topics = (
+        Symbol::new(env, PART_ACCEPTED_TOPIC),
+        organization.clone(),
+        volunteer.clone(),
+        task_id.clone(),
+    );
+    env.events().publish(topics, ());
+}
+
+pub fn participation_rejected(env: &Env, organization: &Address, volunteer: &Address, task_id: &String) {
+    let topics = (
+        Symbol::new(env, PART_REJECTED_TOPIC),
+        organization.clone(),
+        volunteer.clone(),
+        task_id.clone(),
+    );
+    env.events().publish(topics, ());
+}
+
+pub fn task_created(
+    env: &Env,
+    organization: &Address,
+    task_id: &String,
+    name: &String,
+    start_time: u64,
+    end_time: u64,
+) {
+    let topics = (Symbol::new(env, TASK_CREATED_TOPIC), organization.clone(), task_id.clone());
+    env.events().publish(topics, (name.clone(), start_time, end_time));
+}
+
+pub fn task_status_updated(env: &Env, organization: &Address, task_id: &String, status: TaskStatus) {
+    let topics = (Symbol::new(env, TASK_STATUS_TOPIC), organization.clone(), task_id.clone());
+    env.events().publish(topics, status);
+}
+
+pub fn participation_checked_in(
+    env: &Env,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    check_in: u64,
+) {
+    let topics = (
+        Symbol::new(env, PART_CHECKED_IN_TOPIC),
+        organization.clone(),
+        volunteer.clone(),
+        task_id.clone(),
+    );
+    env.events().publish(topics, check_in);
+}
+
+pub fn participation_checked_out(
+    env: &Env,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    check_out: u64,
+    duration: u64,
+) {
+    let topics = (
+        Symbol::new(env, PART_CHECKED_OUT_TOPIC),
+        organization.clone(),
+        volunteer.clone(),
+        task_id.clone(),
+    );
+    env.events().publish(topics, (check_out, duration));
+}
+
+pub fn participation_role_updated(
+    env: &Env,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    role: ParticipationRole,
+) {
+    let topics = (
+        Symbol::new(env, PART_ROLE_TOPIC),
+        organization.clone(),
+        volunteer.clone(),
+        task_id.clone(),
+    );
+    env.events().publish(topics, role);
+}
+
+pub fn evidence_added(
+    env: &Env,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    evidence: &Evidence,
+) {
+    let topics = (
+        Symbol::new(env, PART_EVIDENCE_TOPIC),
+        organization.clone(),
+        volunteer.clone(),
+        task_id.clone(),
+    );
+    env.events().publish(topics, evidence.clone());
+}
@@ -0,0 +1,141 @@
+use soroban_sdk::{Address, Env, Symbol, Vec, contractclient, contracttype, symbol_short};
+
+use crate::error::ContractError;
+use crate::events;
+use crate::index_storage::{self, IndexId};
+use crate::storage::{self, DataKey, bump_instance_ttl};
+
+// Reputation credited per registered participation
+pub const REPUTATION_PER_PARTICIPATION: u32 = 5;
+// Upper bound on goals inspected per hook call to keep registrations cheap
+pub const MAX_HOOK_GOALS: u32 = 20;
+
+const REPUTATION_CATEGORY: Symbol = symbol_short!("particip");
+
+// Optional downstream contracts notified about new participations
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct HookConfig {
+    pub goal_tracker: Option<Address>,
+    pub reputation_system: Option<Address>,
+}
+
+// Mirrors goal-tracker's `GoalType`; the discriminants must stay in sync
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+#[contracttype]
+pub enum GoalType {
+    TasksCompleted = 0,
+    HoursVolunteered = 1,
+    CertificationsEarned = 2,
+}
+
+// Mirrors goal-tracker's `Goal`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Goal {
+    pub id: u64,
+    pub volunteer: Address,
+    pub goal_type: GoalType,
+    pub target_amount: u64,
+    pub current_amount: u64,
+    pub is_completed: bool,
+}
+
+/// Subset of the goal-tracker interface used by the hooks. This contract must
+/// be configured as the goal tracker's updater.
+#[allow(dead_code)]
+#[contractclient(name = "GoalTrackerClient")]
+pub trait GoalTrackerInterface {
+    fn get_goals_by_user(env: Env, volunteer: Address) -> Vec<u64>;
+    fn get_goal(env: Env, goal_id: u64) -> Goal;
+    fn update_progress(env: Env, goal_id: u64, amount_to_add: u64) -> bool;
+}
+
+/// Subset of the reputation-system interface used by the hooks. This contract
+/// must be registered there as an organization.
+#[allow(dead_code)]
+#[contractclient(name = "ReputationSystemClient")]
+pub trait ReputationSystemInterface {
+    fn endorse_volunteer(env: Env, org: Address, volunteer: Address, score: u32, category: Symbol);
+}
+
+pub fn get_hook_config(env: &Env) -> HookConfig {
+    env.storage().instance().get(&DataKey::HookConfig).unwrap_or(HookConfig {
+        goal_tracker: None,
+        reputation_system: None,
+    })
+}
+
+pub fn set_hook_config(env: &Env, admin: &Address, config: &HookConfig) -> Result<(), ContractError> {
+    storage::check_admin(env, admin)?;
+
+    env.storage().instance().set(&DataKey::HookConfig, config);
+    bump_instance_ttl(env);
+    events::hook_config_updated(env, config);
+
+    Ok(())
+}
+
+/// Credits downstream contracts for a newly registered participation. Every
+/// call is a `try_` call, so a failing or misconfigured hook never blocks the
+/// registration itself.
+pub fn on_participation_registered(env: &Env, volunteer: &Address) {
+    let config = get_hook_config(env);
+
+    if let Some(goal_tracker) = config.goal_tracker {
+        update_goals(env, &goal_tracker, volunteer, GoalType::TasksCompleted, 1);
+    }
+
+    if let Some(reputation_system) = config.reputation_system {
+        // Endorsements overwrite the previous score from the same organization,
+        // so the credit is derived from the volunteer's total participations.
+        let count = index_storage::len(env, &IndexId::Volunteer(volunteer.clone()));
+        let score = count.saturating_mul(REPUTATION_PER_PARTICIPATION);
+        let client = ReputationSystemClient::new(env, &reputation_system);
+        let result = client.try_endorse_volunteer(
+            &env.current_contract_address(),
+            volunteer,
+            &score,
+            &REPUTATION_CATEGORY,
+        );
+        if result.is_err() {
+            events::hook_failed(env, &reputation_system, volunteer);
+        }
+    }
+}
+
+/// Credits whole volunteered hours to the volunteer's HoursVolunteered goals.
+pub fn on_hours_recorded(env: &Env, volunteer: &Address, hours: u64) {
+    if hours == 0 {
+        return;
+    }
+    if let Some(goal_tracker) = get_hook_config(env).goal_tracker {
+        update_goals(env, &goal_tracker, volunteer, GoalType::HoursVolunteered, hours);
+    }
+}
+
+fn update_goals(env: &Env, goal_tracker: &Address, volunteer: &Address, goal_type: GoalType, amount: u64) {
+    let client = GoalTrackerClient::new(env, goal_tracker);
+
+    let goal_ids = match client.try_get_goals_by_user(volunteer) {
+        Ok(Ok(ids)) => ids,
+        _ => {
+            events::hook_failed(env, goal_tracker, volunteer);
+            return;
+        }
+    };
+
+    for goal_id in goal_ids.iter().take(MAX_HOOK_GOALS as usize) {
+        let goal = match client.try_get_goal(&goal_id) {
+            Ok(Ok(goal)) => goal,
+            _ => continue,
+        };
+        if goal.goal_type != goal_type || goal.is_completed {
+            continue;
+        }
+        if !matches!(client.try_update_progress(&goal_id, &amount), Ok(Ok(_))) {
+            events::hook_failed(env, goal_tracker, volunteer);
+        }
+    }
+}
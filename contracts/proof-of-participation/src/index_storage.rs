@@ -0,0 +1,146 @@
+use soroban_sdk::{Address, Env, IntoVal, Map, String, TryFromVal, Val, Vec, contracttype};
+
+use crate::error::ContractError;
+use crate::storage::{DataKey, bump_persistent_ttl, get_vec_from_persistent_storage};
+
+// Number of entries held by a single index page before a new page is opened
+pub const INDEX_PAGE_SIZE: u32 = 100;
+
+// Identifies one of the append-only participation indexes
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum IndexId {
+    Volunteer(Address),     // ParticipationKeys of a volunteer
+    Task(String),           // Volunteer addresses of a task
+    Organization(Address),  // ParticipationKeys issued by an organization
+}
+
+// Bookkeeping for a paged index. `page_lens` holds the live entry count of
+// every non-empty page so queries can skip straight to the page containing an
+// offset. Page numbers are never reused while the index has entries, so the
+// page recorded for an entry stays valid after other pages are emptied.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct IndexMeta {
+    pub len: u32,
+    pub page_lens: Map<u32, u32>,
+    pub next_page: u32,
+}
+
+fn get_meta(env: &Env, id: &IndexId) -> IndexMeta {
+    env.storage()
+        .persistent()
+        .get(&DataKey::IndexMeta(id.clone()))
+        .unwrap_or(IndexMeta { len: 0, page_lens: Map::new(env), next_page: 0 })
+}
+
+// An index without entries drops its bookkeeping entirely
+fn set_meta(env: &Env, id: &IndexId, meta: &IndexMeta) {
+    let key = DataKey::IndexMeta(id.clone());
+    if meta.len == 0 {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, meta);
+    bump_persistent_ttl(env, &key);
+}
+
+/// Number of live entries in the index.
+pub fn len(env: &Env, id: &IndexId) -> u32 {
+    get_meta(env, id).len
+}
+
+/// Appends an item to the last page of the index, opening a new page when it
+/// is full. Returns the page number the item was written to.
+pub fn push<T>(env: &Env, id: &IndexId, item: &T) -> Result<u32, ContractError>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+{
+    let mut meta = get_meta(env, id);
+    meta.len = meta.len.checked_add(1).ok_or(ContractError::ParticipationIndexFull)?;
+
+    let page = match meta.page_lens.keys().last() {
+        Some(last) if meta.page_lens.get(last).unwrap_or(0) < INDEX_PAGE_SIZE => last,
+        _ => {
+            let page = meta.next_page;
+            meta.next_page = page.checked_add(1).ok_or(ContractError::ParticipationIndexFull)?;
+            page
+        }
+    };
+
+    let page_key = DataKey::IndexPage(id.clone(), page);
+    let mut items: Vec<T> = get_vec_from_persistent_storage(env, &page_key);
+    items.push_back(item.clone());
+    env.storage().persistent().set(&page_key, &items);
+    bump_persistent_ttl(env, &page_key);
+
+    meta.page_lens.set(page, items.len());
+    set_meta(env, id, &meta);
+
+    Ok(page)
+}
+
+/// Removes the first occurrence of `item` from the given page. Returns false
+/// if the page does not contain it.
+pub fn remove<T>(env: &Env, id: &IndexId, page: u32, item: &T) -> bool
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val>,
+{
+    let page_key = DataKey::IndexPage(id.clone(), page);
+    let mut items: Vec<T> = get_vec_from_persistent_storage(env, &page_key);
+    let Some(index) = items.first_index_of(item) else {
+        return false;
+    };
+    items.remove(index);
+
+    // Emptied pages are dropped from storage and from the page list
+    let mut meta = get_meta(env, id);
+    meta.len = meta.len.saturating_sub(1);
+    if items.is_empty() {
+        env.storage().persistent().remove(&page_key);
+        meta.page_lens.remove(page);
+    } else {
+        env.storage().persistent().set(&page_key, &items);
+        bump_persistent_ttl(env, &page_key);
+        meta.page_lens.set(page, items.len());
+    }
+    set_meta(env, id, &meta);
+
+    true
+}
+
+/// Returns up to `limit` entries starting at `offset`, reading only the pages
+/// that overlap the requested range.
+pub fn get_range<T>(env: &Env, id: &IndexId, offset: u32, limit: u32) -> Result<Vec<T>, ContractError>
+where
+    T: IntoVal<Env, Val> + TryFromVal<Env, Val> + Clone,
+{
+    if limit == 0 {
+        return Err(ContractError::InvalidPaginationArguments);
+    }
+
+    let meta = get_meta(env, id);
+    let mut result = Vec::new(env);
+    let mut skip = offset;
+
+    for (page, page_len) in meta.page_lens.iter() {
+        if result.len() >= limit {
+            break;
+        }
+        if skip >= page_len {
+            skip -= page_len;
+            continue;
+        }
+
+        let items: Vec<T> = get_vec_from_persistent_storage(env, &DataKey::IndexPage(id.clone(), page));
+        for item in items.iter().skip(skip as usize) {
+            if result.len() >= limit {
+                break;
+            }
+            result.push_back(item);
+        }
+        skip = 0;
+    }
+
+    Ok(result)
+}
@@ -3,14 +3,20 @@
 mod error;
 mod events;
 mod storage;
+mod admin;
+mod hooks;
+mod badge;
+mod index_storage;
 mod organization_storage;
 mod participation_storage;
 mod organization;
 mod participation;
+mod task_storage;
+mod task;
 mod test;
 
 use soroban_sdk::{
-    contract, contractimpl, Address, Env, String, Vec 
+    contract, contractimpl, Address, BytesN, Env, String, Vec 
 };
 
 pub use error::*;
@@ -34,6 +40,85 @@ impl ProofOfParticipationContract {
         Ok(())
     }
 
+    pub fn propose_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        admin::propose_admin(&env, &admin, &new_admin)
+    }
+
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
+        admin::accept_admin(&env, &new_admin)
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        storage::get_admin(&env)
+    }
+
+    pub fn add_co_admin(env: Env, admin: Address, co_admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        admin::add_co_admin(&env, &admin, &co_admin)
+    }
+
+    pub fn remove_co_admin(env: Env, admin: Address, co_admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        admin::remove_co_admin(&env, &admin, &co_admin)
+    }
+
+    pub fn get_co_admins(env: Env) -> Vec<Address> {
+        admin::get_co_admins(&env)
+    }
+
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        admin::set_paused(&env, &admin, true)
+    }
+
+    pub fn unpause(env: Env, admin: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        admin::set_paused(&env, &admin, false)
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        storage::is_paused(&env)
+    }
+
+    pub fn set_hook_config(
+        env: Env,
+        admin: Address,
+        goal_tracker: Option<Address>,
+        reputation_system: Option<Address>,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        let config = hooks::HookConfig { goal_tracker, reputation_system };
+        hooks::set_hook_config(&env, &admin, &config)
+    }
+
+    pub fn get_hook_config(env: Env) -> hooks::HookConfig {
+        hooks::get_hook_config(&env)
+    }
+
+    pub fn set_badge_config(
+        env: Env,
+        admin: Address,
+        nft_contract: Option<Address>,
+        auto_mint: bool,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        let config = badge::BadgeConfig { nft_contract, auto_mint };
+        badge::set_badge_config(&env, &admin, &config)
+    }
+
+    pub fn get_badge_config(env: Env) -> badge::BadgeConfig {
+        badge::get_badge_config(&env)
+    }
+
+    pub fn claim_participation_badge(env: Env, volunteer: Address, task_id: String) -> Result<u128, ContractError> {
+        badge::claim_participation_badge(&env, &volunteer, &task_id)
+    }
+
+    pub fn verify_participation_badge(env: Env, token_id: u128) -> Result<participation_storage::Participation, ContractError> {
+        badge::verify_participation_badge(&env, token_id)
+    }
+
     pub fn register_organization(
         env: Env,
         admin: Address,
@@ -45,21 +130,161 @@ impl ProofOfParticipationContract {
         organization::register_organization(&env, &admin, &organization, &name)
     }
 
-    pub fn remove_organization(
+    pub fn deactivate_organization(
+        env: Env,
+        admin: Address,
+        organization: Address,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        organization::deactivate_organization(&env, &admin, &organization)
+    }
+
+    pub fn reactivate_organization(
         env: Env,
         admin: Address,
         organization: Address,
     ) -> Result<(), ContractError> {
         admin.require_auth();
-        organization::remove_organization(&env, &admin, &organization)
+        organization::reactivate_organization(&env, &admin, &organization)
     }
 
     pub fn is_organization(env: Env, organization: Address) -> bool {
         organization::is_organization(&env, &organization)
     }
 
-    pub fn get_all_organizations(env: Env) -> Vec<Address> {
-        organization::get_all_organizations(&env)
+    pub fn get_all_organizations(
+        env: Env,
+        status: Option<organization_storage::OrganizationStatus>,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<organization_storage::OrganizationQueryResult, ContractError> {
+        organization::get_all_organizations(&env, status, cursor, limit)
+    }
+
+    pub fn apply_for_registration(
+        env: Env,
+        organization: Address,
+        name: String,
+        website: Option<String>,
+        contact_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        organization::apply_for_registration(&env, &organization, &name, website, contact_hash)
+    }
+
+    pub fn approve_application(env: Env, admin: Address, organization: Address) -> Result<(), ContractError> {
+        admin.require_auth();
+        organization::approve_application(&env, &admin, &organization)
+    }
+
+    pub fn reject_application(
+        env: Env,
+        admin: Address,
+        organization: Address,
+        reason: String,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        organization::reject_application(&env, &admin, &organization, &reason)
+    }
+
+    pub fn get_application(
+        env: Env,
+        organization: Address,
+    ) -> Result<organization_storage::OrganizationApplication, ContractError> {
+        organization::get_application(&env, &organization)
+    }
+
+    pub fn get_pending_applications(
+        env: Env,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<organization_storage::OrganizationApplication>, ContractError> {
+        organization::get_pending_applications(&env, offset, limit)
+    }
+
+    pub fn update_organization_profile(
+        env: Env,
+        caller: Address,
+        organization: Address,
+        name: String,
+        website: Option<String>,
+        contact_hash: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        organization::update_organization_profile(&env, &caller, &organization, &name, website, contact_hash)
+    }
+
+    pub fn set_organization_verified(
+        env: Env,
+        admin: Address,
+        organization: Address,
+        verified: bool,
+    ) -> Result<(), ContractError> {
+        admin.require_auth();
+        organization::set_organization_verified(&env, &admin, &organization, verified)
+    }
+
+    pub fn get_organization_profile(
+        env: Env,
+        organization: Address,
+    ) -> Result<organization_storage::OrganizationProfile, ContractError> {
+        organization::get_organization_profile(&env, &organization)
+    }
+
+    pub fn add_organization_delegate(
+        env: Env,
+        caller: Address,
+        organization: Address,
+        delegate: Address,
+        role: organization_storage::OrganizationRole,
+    ) -> Result<(), ContractError> {
+        organization::add_organization_delegate(&env, &caller, &organization, &delegate, role)
+    }
+
+    pub fn remove_organization_delegate(
+        env: Env,
+        caller: Address,
+        organization: Address,
+        delegate: Address,
+    ) -> Result<(), ContractError> {
+        organization::remove_organization_delegate(&env, &caller, &organization, &delegate)
+    }
+
+    pub fn get_organization_delegates(
+        env: Env,
+        organization: Address,
+    ) -> Result<Vec<organization_storage::OrganizationDelegate>, ContractError> {
+        organization::get_organization_delegates(&env, &organization)
+    }
+
+    /// Creates a task, signed by the organization or one of its coordinators.
+    pub fn create_task(
+        env: Env,
+        signer: Address,
+        organization: Address,
+        params: task_storage::TaskParams,
+    ) -> Result<(), ContractError> {
+        task::create_task(&env, &signer, &organization, params)
+    }
+
+    pub fn update_task_status(
+        env: Env,
+        organization: Address,
+        task_id: String,
+        status: task_storage::TaskStatus,
+    ) -> Result<(), ContractError> {
+        task::update_task_status(&env, &organization, &task_id, status)
+    }
+
+    pub fn get_task(env: Env, task_id: String) -> Result<task_storage::Task, ContractError> {
+        task::get_task(&env, &task_id)
+    }
+
+    pub fn get_organization_tasks(
+        env: Env,
+        organization: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<task_storage::Task>, ContractError> {
+        task::get_organization_tasks(&env, &organization, offset, limit)
     }
 
     pub fn register_participation(
@@ -70,7 +295,136 @@ impl ProofOfParticipationContract {
         task_name: String,
         metadata: Option<String>,
     ) -> Result<(), ContractError> {
-        participation::register_participation(&env, &organization, &volunteer, &task_id, &task_name, metadata)
+        participation::register_participation(&env, &organization, &organization, &volunteer, &task_id, &task_name, metadata)
+    }
+
+    /// Registers a participation on the organization's behalf, signed by one of its delegates.
+    pub fn delegate_register_participation(
+        env: Env,
+        delegate: Address,
+        organization: Address,
+        volunteer: Address,
+        task_id: String,
+        task_name: String,
+        metadata: Option<String>,
+    ) -> Result<(), ContractError> {
+        participation::register_participation(&env, &delegate, &organization, &volunteer, &task_id, &task_name, metadata)
+    }
+
+    /// Registers a task for many volunteers at once, signed by the organization
+    /// or one of its coordinators.
+    pub fn register_participations_batch(
+        env: Env,
+        signer: Address,
+        organization: Address,
+        task_id: String,
+        task_name: String,
+        volunteers: Vec<Address>,
+        metadata: Option<String>,
+    ) -> Result<Vec<participation_storage::BatchEntryResult>, ContractError> {
+        participation::register_participations_batch(&env, &signer, &organization, &task_id, &task_name, &volunteers, metadata)
+    }
+
+    pub fn propose_participation(
+        env: Env,
+        signer: Address,
+        organization: Address,
+        volunteer: Address,
+        task_id: String,
+        task_name: String,
+        metadata: Option<String>,
+    ) -> Result<(), ContractError> {
+        participation::propose_participation(&env, &signer, &organization, &volunteer, &task_id, &task_name, metadata)
+    }
+
+    pub fn accept_participation(
+        env: Env,
+        volunteer: Address,
+        task_id: String,
+    ) -> Result<(), ContractError> {
+        participation::accept_participation(&env, &volunteer, &task_id)
+    }
+
+    pub fn reject_participation(
+        env: Env,
+        volunteer: Address,
+        task_id: String,
+    ) -> Result<(), ContractError> {
+        participation::reject_participation(&env, &volunteer, &task_id)
+    }
+
+    pub fn get_pending_attestations(
+        env: Env,
+        volunteer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<participation_storage::Participation>, ContractError> {
+        participation::get_pending_attestations(&env, &volunteer, offset, limit)
+    }
+
+    /// Check-in, check-out and role changes may be signed by the organization
+    /// or one of its coordinators, as with registration.
+    pub fn record_check_in(
+        env: Env,
+        signer: Address,
+        organization: Address,
+        volunteer: Address,
+        task_id: String,
+        check_in: u64,
+    ) -> Result<(), ContractError> {
+        participation::record_check_in(&env, &signer, &organization, &volunteer, &task_id, check_in)
+    }
+
+    pub fn record_check_out(
+        env: Env,
+        signer: Address,
+        organization: Address,
+        volunteer: Address,
+        task_id: String,
+        check_out: u64,
+    ) -> Result<(), ContractError> {
+        participation::record_check_out(&env, &signer, &organization, &volunteer, &task_id, check_out)
+    }
+
+    pub fn set_participation_role(
+        env: Env,
+        signer: Address,
+        organization: Address,
+        volunteer: Address,
+        task_id: String,
+        role: participation_storage::ParticipationRole,
+    ) -> Result<(), ContractError> {
+        participation::set_participation_role(&env, &signer, &organization, &volunteer, &task_id, role)
+    }
+
+    pub fn get_volunteer_hours(env: Env, volunteer: Address) -> u64 {
+        participation::get_volunteer_hours(&env, &volunteer)
+    }
+
+    pub fn get_organization_hours(env: Env, organization: Address) -> u64 {
+        participation::get_organization_hours(&env, &organization)
+    }
+
+    pub fn get_task_hours(env: Env, task_id: String) -> u64 {
+        participation::get_task_hours(&env, &task_id)
+    }
+
+    pub fn revoke_participation(
+        env: Env,
+        caller: Address,
+        volunteer: Address,
+        task_id: String,
+        reason: String,
+    ) -> Result<(), ContractError> {
+        participation::revoke_participation(&env, &caller, &volunteer, &task_id, &reason)
+    }
+
+    pub fn get_revocation(
+        env: Env,
+        volunteer: Address,
+        task_id: String,
+    ) -> Result<participation_storage::Revocation, ContractError> {
+        participation::get_revocation(&env, &volunteer, &task_id)
     }
 
     pub fn verify_participation(
@@ -89,6 +443,49 @@ impl ProofOfParticipationContract {
         participation::get_participation_details(&env, &volunteer, &task_id)
     }
 
+    pub fn add_evidence(
+        env: Env,
+        caller: Address,
+        volunteer: Address,
+        task_id: String,
+        kind: participation_storage::EvidenceKind,
+        content_hash: BytesN<32>,
+        uri: Option<String>,
+    ) -> Result<(), ContractError> {
+        participation::add_evidence(&env, &caller, &volunteer, &task_id, kind, content_hash, uri)
+    }
+
+    pub fn get_evidence(
+        env: Env,
+        volunteer: Address,
+        task_id: String,
+    ) -> Result<Vec<participation_storage::Evidence>, ContractError> {
+        participation::get_evidence(&env, &volunteer, &task_id)
+    }
+
+    pub fn get_issuer_status(
+        env: Env,
+        volunteer: Address,
+        task_id: String,
+    ) -> Result<organization_storage::OrganizationStatus, ContractError> {
+        participation::get_issuer_status(&env, &volunteer, &task_id)
+    }
+
+    pub fn get_participation_proof(
+        env: Env,
+        volunteer: Address,
+        task_id: String,
+    ) -> Result<BytesN<32>, ContractError> {
+        participation::get_participation_proof(&env, &volunteer, &task_id)
+    }
+
+    pub fn verify_participation_proof(
+        env: Env,
+        digest: BytesN<32>,
+    ) -> Result<participation_storage::Participation, ContractError> {
+        participation::verify_participation_proof(&env, &digest)
+    }
+
     pub fn get_volunteer_participations(
         env: Env,
         volunteer: Address,
@@ -115,4 +512,30 @@ impl ProofOfParticipationContract {
     ) -> Result<Vec<participation_storage::Participation>, ContractError> {
         participation::get_organization_participations(&env, &organization, offset, limit)
     }
+
+    /// Filtered variant of `get_volunteer_participations`. Start with cursor 0
+    /// and pass back `next_cursor` until it is None; a page may hold fewer than
+    /// `limit` matches when the scan budget runs out first. Pass limit 0 to
+    /// count matches only and sum `matched` across pages for a total.
+    pub fn query_volunteer_participations(
+        env: Env,
+        volunteer: Address,
+        filter: participation_storage::ParticipationFilter,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<participation_storage::ParticipationQueryResult, ContractError> {
+        participation::query_volunteer_participations(&env, &volunteer, &filter, cursor, limit)
+    }
+
+    /// Filtered variant of `get_organization_participations`, paged with a
+    /// cursor like `query_volunteer_participations`.
+    pub fn query_org_participations(
+        env: Env,
+        organization: Address,
+        filter: participation_storage::ParticipationFilter,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<participation_storage::ParticipationQueryResult, ContractError> {
+        participation::query_org_participations(&env, &organization, &filter, cursor, limit)
+    }
 }
\ No newline at end of file
@@ -1,9 +1,14 @@
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
 use crate::error::ContractError;
 use crate::events;
 use crate::storage;
-use crate::organization_storage;
+use crate::organization_storage::{
+    self, ApplicationStatus, OrganizationApplication, OrganizationDelegate, OrganizationProfile, OrganizationQueryResult,
+    OrganizationRole, OrganizationStatus, APPLICATION_COOLDOWN, MAX_ORGANIZATION_NAME_LEN, MAX_REJECTION_REASON_LEN,
+    MAX_WEBSITE_LEN,
+};
+use crate::participation_storage::{MAX_PAGINATION_LIMIT, MAX_QUERY_SCAN, paginate_vec};
 
 
 pub fn register_organization(
@@ -12,6 +17,8 @@ pub fn register_organization(
     organization: &Address,
     name: &String,
 ) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
     storage::check_admin(env, admin)?;
 
     // Validate address
@@ -30,35 +37,374 @@ pub fn register_organization(
     Ok(())
 }
 
-pub fn remove_organization(
+/// Deactivates an organization. Its participations stay verifiable, but it
+/// can no longer register participations, tasks or delegates.
+pub fn deactivate_organization(
     env: &Env,
     admin: &Address,
     organization: &Address,
 ) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
     storage::check_admin(env, admin)?;
+    verify_organization(env, organization)?;
 
-    if !organization_storage::is_organization_registered(env, organization) {
-        return Err(ContractError::OrganizationNotRegistered);
+    set_organization_status(env, organization, OrganizationStatus::Deactivated)
+}
+
+pub fn reactivate_organization(
+    env: &Env,
+    admin: &Address,
+    organization: &Address,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    storage::check_admin(env, admin)?;
+    match organization_storage::get_organization_status(env, organization) {
+        None => return Err(ContractError::OrganizationNotRegistered),
+        Some(OrganizationStatus::Active) => return Err(ContractError::OrganizationAlreadyRegistered),
+        Some(OrganizationStatus::Deactivated) => {}
     }
 
-    organization_storage::remove_organization_from_storage(env, organization)?;
-    events::organization_removed(env, organization);
+    set_organization_status(env, organization, OrganizationStatus::Active)
+}
+
+fn set_organization_status(
+    env: &Env,
+    organization: &Address,
+    status: OrganizationStatus,
+) -> Result<(), ContractError> {
+    let mut profile = get_organization_profile(env, organization)?;
+    profile.status = status;
+    profile.status_changed_at = env.ledger().timestamp();
+    organization_storage::set_organization_profile(env, organization, &profile);
+
+    events::organization_status_changed(env, organization, status, profile.status_changed_at);
 
     Ok(())
 }
 
+/// True only for registered organizations that are currently active.
 pub fn is_organization(env: &Env, organization: &Address) -> bool {
-    organization_storage::is_organization_registered(env, organization)
+    organization_storage::get_organization_status(env, organization) == Some(OrganizationStatus::Active)
 }
 
 pub fn verify_organization(env: &Env, organization: &Address) -> Result<(), ContractError> {
+    match organization_storage::get_organization_status(env, organization) {
+        None => Err(ContractError::OrganizationNotRegistered),
+        Some(OrganizationStatus::Deactivated) => Err(ContractError::OrganizationDeactivated),
+        Some(OrganizationStatus::Active) => Ok(()),
+    }
+}
+
+/// Lists organizations in registration order, optionally only those with the
+/// given status. A page reads at most MAX_QUERY_SCAN registry entries, so a
+/// filtered listing may come back short; resume from `next_cursor`.
+pub fn get_all_organizations(
+    env: &Env,
+    status: Option<OrganizationStatus>,
+    cursor: u32,
+    limit: u32,
+) -> Result<OrganizationQueryResult, ContractError> {
+    if limit > MAX_PAGINATION_LIMIT {
+        return Err(ContractError::PaginationLimitExceeded);
+    }
+    if limit == 0 {
+        return Err(ContractError::InvalidPaginationArguments);
+    }
+
+    let registry = organization_storage::get_all_organizations(env);
+    let len = registry.len();
+    let end = cursor.saturating_add(MAX_QUERY_SCAN).min(len);
+    let mut position = cursor;
+    let mut organizations = Vec::new(env);
+
+    while position < end && organizations.len() < limit {
+        let organization = registry.get_unchecked(position);
+        position += 1;
+        if status.is_none_or(|s| organization_storage::get_organization_status(env, &organization) == Some(s)) {
+            organizations.push_back(organization);
+        }
+    }
+
+    let next_cursor = if position < len { Some(position) } else { None };
+    Ok(OrganizationQueryResult { organizations, next_cursor })
+}
+
+/// Authorizes `signer` to act for the organization. The organization address
+/// itself may do anything; delegates need at least the given role, where
+/// Owner also covers Coordinator duties.
+pub fn require_organization_signer(
+    env: &Env,
+    signer: &Address,
+    organization: &Address,
+    required: OrganizationRole,
+) -> Result<(), ContractError> {
+    signer.require_auth();
+    verify_organization(env, organization)?;
+
+    if signer == organization {
+        return Ok(());
+    }
+    match organization_storage::get_delegate_role(env, organization, signer) {
+        Some(OrganizationRole::Owner) => Ok(()),
+        Some(OrganizationRole::Coordinator) if required == OrganizationRole::Coordinator => Ok(()),
+        _ => Err(ContractError::NotAuthorized),
+    }
+}
+
+fn validate_profile(name: &String, website: &Option<String>) -> Result<(), ContractError> {
+    if name.is_empty() {
+        return Err(ContractError::OrganizationNameEmpty);
+    }
+    if name.len() > MAX_ORGANIZATION_NAME_LEN {
+        return Err(ContractError::OrganizationNameTooLong);
+    }
+    if website.as_ref().is_some_and(|w| w.len() > MAX_WEBSITE_LEN) {
+        return Err(ContractError::WebsiteTooLong);
+    }
+    Ok(())
+}
+
+pub fn update_organization_profile(
+    env: &Env,
+    caller: &Address,
+    organization: &Address,
+    name: &String,
+    website: Option<String>,
+    contact_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    require_organization_signer(env, caller, organization, OrganizationRole::Owner)?;
+    validate_profile(name, &website)?;
+
+    let mut profile = get_organization_profile(env, organization)?;
+    profile.name = name.clone();
+    profile.website = website;
+    profile.contact_hash = contact_hash;
+    organization_storage::set_organization_profile(env, organization, &profile);
+    organization_storage::update_organization_name(env, organization, name);
+
+    events::organization_profile_updated(env, organization, &profile);
+
+    Ok(())
+}
+
+pub fn set_organization_verified(
+    env: &Env,
+    admin: &Address,
+    organization: &Address,
+    verified: bool,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    storage::check_admin(env, admin)?;
+
+    let mut profile = get_organization_profile(env, organization)?;
+    profile.verified = verified;
+    organization_storage::set_organization_profile(env, organization, &profile);
+
+    events::organization_profile_updated(env, organization, &profile);
+
+    Ok(())
+}
+
+pub fn get_organization_profile(env: &Env, organization: &Address) -> Result<OrganizationProfile, ContractError> {
     if !organization_storage::is_organization_registered(env, organization) {
-        Err(ContractError::OrganizationNotRegistered)
-    } else {
-        Ok(())
+        return Err(ContractError::OrganizationNotRegistered);
+    }
+    match organization_storage::get_organization_profile(env, organization) {
+        Some(profile) => Ok(profile),
+        // Organizations registered before profiles existed only have a name
+        None => Ok(OrganizationProfile {
+            name: organization_storage::get_organization_name(env, organization)
+                .ok_or(ContractError::OrganizationNotRegistered)?,
+            website: None,
+            contact_hash: BytesN::from_array(env, &[0; 32]),
+            verified: false,
+            status: OrganizationStatus::Active,
+            status_changed_at: 0,
+        }),
+    }
+}
+
+pub fn add_organization_delegate(
+    env: &Env,
+    caller: &Address,
+    organization: &Address,
+    delegate: &Address,
+    role: OrganizationRole,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    require_organization_signer(env, caller, organization, OrganizationRole::Owner)?;
+    if delegate == organization {
+        return Err(ContractError::InvalidAddress);
+    }
+
+    organization_storage::set_delegate(env, organization, delegate, role)?;
+    events::organization_delegate_set(env, organization, delegate, Some(role));
+
+    Ok(())
+}
+
+pub fn remove_organization_delegate(
+    env: &Env,
+    caller: &Address,
+    organization: &Address,
+    delegate: &Address,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    require_organization_signer(env, caller, organization, OrganizationRole::Owner)?;
+
+    organization_storage::remove_delegate(env, organization, delegate)?;
+    events::organization_delegate_set(env, organization, delegate, None);
+
+    Ok(())
+}
+
+pub fn get_organization_delegates(env: &Env, organization: &Address) -> Result<Vec<OrganizationDelegate>, ContractError> {
+    verify_organization(env, organization)?;
+    Ok(organization_storage::get_delegates(env, organization))
+}
+
+/// Lets an organization submit its own profile for registration instead of
+/// waiting for the admin to onboard it. Unreviewed applications expire after
+/// APPLICATION_TTL, and a rejected organization may apply again once
+/// APPLICATION_COOLDOWN has passed.
+pub fn apply_for_registration(
+    env: &Env,
+    organization: &Address,
+    name: &String,
+    website: Option<String>,
+    contact_hash: BytesN<32>,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    organization.require_auth();
+
+    if organization_storage::is_organization_registered(env, organization) {
+        return Err(ContractError::OrganizationAlreadyRegistered);
+    }
+    if let Some(previous) = organization_storage::get_application(env, organization) {
+        match previous.status {
+            ApplicationStatus::Pending => return Err(ContractError::ApplicationAlreadyPending),
+            ApplicationStatus::Rejected
+                if env.ledger().timestamp()
+                    < previous.decided_at.unwrap_or(0).saturating_add(APPLICATION_COOLDOWN) =>
+            {
+                return Err(ContractError::NotAuthorized);
+            }
+            _ => {}
+        }
+    }
+    validate_profile(name, &website)?;
+
+    let application = OrganizationApplication {
+        organization: organization.clone(),
+        name: name.clone(),
+        website,
+        contact_hash,
+        status: ApplicationStatus::Pending,
+        applied_at: env.ledger().timestamp(),
+        decided_at: None,
+        reason: None,
+    };
+    organization_storage::store_pending_application(env, &application)?;
+
+    events::organization_application_updated(env, organization, ApplicationStatus::Pending, &None);
+
+    Ok(())
+}
+
+fn get_pending_application(env: &Env, organization: &Address) -> Result<OrganizationApplication, ContractError> {
+    match organization_storage::get_application(env, organization) {
+        Some(application) if application.status == ApplicationStatus::Pending => Ok(application),
+        _ => Err(ContractError::ApplicationNotFound),
     }
 }
 
-pub fn get_all_organizations(env: &Env) -> Vec<Address> {
-    organization_storage::get_all_organizations(env)
-}
\ No newline at end of file
+pub fn approve_application(env: &Env, admin: &Address, organization: &Address) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    storage::check_admin(env, admin)?;
+
+    let mut application = get_pending_application(env, organization)?;
+    // The admin may have registered the applicant directly in the meantime
+    if organization_storage::is_organization_registered(env, organization) {
+        return Err(ContractError::OrganizationAlreadyRegistered);
+    }
+
+    organization_storage::store_organization(env, organization, &application.name)?;
+    let mut profile = organization_storage::new_profile(env, &application.name);
+    profile.website = application.website.clone();
+    profile.contact_hash = application.contact_hash.clone();
+    organization_storage::set_organization_profile(env, organization, &profile);
+
+    application.status = ApplicationStatus::Approved;
+    application.decided_at = Some(env.ledger().timestamp());
+    organization_storage::store_decided_application(env, &application);
+
+    events::organization_registered(env, organization, &application.name);
+    events::organization_application_updated(env, organization, ApplicationStatus::Approved, &None);
+
+    Ok(())
+}
+
+pub fn reject_application(
+    env: &Env,
+    admin: &Address,
+    organization: &Address,
+    reason: &String,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    storage::check_admin(env, admin)?;
+
+    if reason.is_empty() {
+        return Err(ContractError::ReasonEmpty);
+    }
+    if reason.len() > MAX_REJECTION_REASON_LEN {
+        return Err(ContractError::ReasonTooLong);
+    }
+
+    let mut application = get_pending_application(env, organization)?;
+    application.status = ApplicationStatus::Rejected;
+    application.decided_at = Some(env.ledger().timestamp());
+    application.reason = Some(reason.clone());
+    organization_storage::store_decided_application(env, &application);
+
+    events::organization_application_updated(env, organization, ApplicationStatus::Rejected, &application.reason);
+
+    Ok(())
+}
+
+pub fn get_application(env: &Env, organization: &Address) -> Result<OrganizationApplication, ContractError> {
+    organization_storage::get_application(env, organization).ok_or(ContractError::ApplicationNotFound)
+}
+
+pub fn get_pending_applications(
+    env: &Env,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<OrganizationApplication>, ContractError> {
+    if limit > MAX_PAGINATION_LIMIT {
+        return Err(ContractError::PaginationLimitExceeded);
+    }
+
+    let applicants = organization_storage::get_pending_applicants(env);
+    let page = paginate_vec(env, &applicants, offset, limit)?;
+
+    let mut applications = Vec::new(env);
+    for applicant in page.iter() {
+        // Expired applications stay queued until a full queue drops them
+        if let Some(application) = organization_storage::get_application(env, &applicant)
+            .filter(|a| a.status == ApplicationStatus::Pending)
+        {
+            applications.push_back(application);
+        }
+    }
+    Ok(applications)
+}
@@ -1,10 +1,90 @@
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{Address, BytesN, Env, String, Vec, contracttype};
 use crate::storage::{DataKey, bump_persistent_ttl, get_vec_from_persistent_storage, bump_instance_ttl};
 use crate::error::ContractError;
 
 // Organization validation constants
 pub const MAX_ORGANIZATION_NAME_LEN: u32 = 64;
 pub const MAX_ORGANIZATIONS: u32 = 1000;
+pub const MAX_WEBSITE_LEN: u32 = 128;
+pub const MAX_DELEGATES_PER_ORG: u32 = 20;
+pub const MAX_PENDING_APPLICATIONS: u32 = 500;
+pub const APPLICATION_TTL: u64 = 30 * 24 * 3600; // Seconds an application waits for review before expiring
+pub const APPLICATION_COOLDOWN: u64 = 7 * 24 * 3600; // Seconds a rejected applicant waits before applying again
+pub const MAX_APPLICATION_PRUNE: u32 = 10; // Expired applications dropped from a full queue per submission
+pub const MAX_REJECTION_REASON_LEN: u32 = 128;
+
+// Organizations are never deleted so that the records they issued stay
+// attributable; a deactivated organization can no longer act.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+#[contracttype]
+pub enum OrganizationStatus {
+    Active = 0,
+    Deactivated = 1,
+}
+
+// Public profile of an organization. `contact_hash` is a hash of off-chain
+// contact details (all zeros when not provided); `verified` can only be set
+// by the contract admin.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OrganizationProfile {
+    pub name: String,
+    pub website: Option<String>,
+    pub contact_hash: BytesN<32>,
+    pub verified: bool,
+    pub status: OrganizationStatus,
+    pub status_changed_at: u64,
+}
+
+// Role of an address acting on behalf of an organization
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+#[contracttype]
+pub enum OrganizationRole {
+    Owner = 0,       // Manages the profile and delegates
+    Coordinator = 1, // Registers participation on the organization's behalf
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+#[contracttype]
+pub enum ApplicationStatus {
+    Pending = 0,
+    Approved = 1,
+    Rejected = 2,
+    Expired = 3, // Not reviewed within APPLICATION_TTL
+}
+
+// Self-submitted registration request reviewed by the admin
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OrganizationApplication {
+    pub organization: Address,
+    pub name: String,
+    pub website: Option<String>,
+    pub contact_hash: BytesN<32>,
+    pub status: ApplicationStatus,
+    pub applied_at: u64,
+    pub decided_at: Option<u64>,
+    pub reason: Option<String>, // Set when rejected
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OrganizationDelegate {
+    pub delegate: Address,
+    pub role: OrganizationRole,
+}
+
+// One page of the organization registry. `next_cursor` is the registry
+// position to resume scanning from, or None once every entry has been read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct OrganizationQueryResult {
+    pub organizations: Vec<Address>,
+    pub next_cursor: Option<u32>,
+}
 
 pub fn store_organization(env: &Env, organization: &Address, name: &String) -> Result<(), ContractError> {
     // Validate organization name
@@ -25,6 +105,8 @@ pub fn store_organization(env: &Env, organization: &Address, name: &String) -> R
     env.storage().persistent().set(&org_key, name);
     bump_persistent_ttl(env, &org_key); // Bump TTL for the specific org entry
 
+    set_organization_profile(env, organization, &new_profile(env, name));
+
     let list_key = DataKey::OrganizationList;
     let mut organizations: Vec<Address> = get_vec_from_persistent_storage(env, &list_key);
 
@@ -44,42 +126,181 @@ pub fn store_organization(env: &Env, organization: &Address, name: &String) -> R
     Ok(())
 }
 
-pub fn remove_organization_from_storage(env: &Env, organization: &Address) -> Result<(), ContractError> {
-    let org_key = DataKey::Organization(organization.clone());
-    env.storage().persistent().remove(&org_key);
-
-    let list_key = DataKey::OrganizationList;
-    let organizations: Vec<Address> = get_vec_from_persistent_storage(env, &list_key);
-
-    let mut updated_orgs = Vec::new(env);
-    let mut found = false;
-    for org in organizations.iter() {
-        if org != *organization {
-            updated_orgs.push_back(org);
-        } else {
-            found = true;
-        }
-    }
-
-    if found {
-        env.storage().persistent().set(&list_key, &updated_orgs);
-        // Only bump TTL if we actually modified the list
-        bump_persistent_ttl(env, &list_key);
-        bump_instance_ttl(env); // Bump instance TTL for general contract activity
+/// Profile of a freshly registered, active organization.
+pub fn new_profile(env: &Env, name: &String) -> OrganizationProfile {
+    OrganizationProfile {
+        name: name.clone(),
+        website: None,
+        contact_hash: BytesN::from_array(env, &[0; 32]),
+        verified: false,
+        status: OrganizationStatus::Active,
+        status_changed_at: env.ledger().timestamp(),
     }
-
-    Ok(())
 }
 
+/// True for every organization ever registered, including deactivated ones.
 pub fn is_organization_registered(env: &Env, organization: &Address) -> bool {
      env.storage().persistent().has(&DataKey::Organization(organization.clone()))
 }
 
-#[allow(dead_code)]
+/// Status of a registered organization; those registered before profiles
+/// existed count as active.
+pub fn get_organization_status(env: &Env, organization: &Address) -> Option<OrganizationStatus> {
+    if !is_organization_registered(env, organization) {
+        return None;
+    }
+    Some(get_organization_profile(env, organization).map_or(OrganizationStatus::Active, |p| p.status))
+}
+
+pub fn update_organization_name(env: &Env, organization: &Address, name: &String) {
+    let key = DataKey::Organization(organization.clone());
+    env.storage().persistent().set(&key, name);
+    bump_persistent_ttl(env, &key);
+}
+
 pub fn get_organization_name(env: &Env, organization: &Address) -> Option<String> {
     env.storage().persistent().get(&DataKey::Organization(organization.clone()))
 }
 
 pub fn get_all_organizations(env: &Env) -> Vec<Address> {
     get_vec_from_persistent_storage(env, &DataKey::OrganizationList)
-}
\ No newline at end of file
+}
+
+pub fn get_organization_profile(env: &Env, organization: &Address) -> Option<OrganizationProfile> {
+    env.storage().persistent().get(&DataKey::OrganizationProfile(organization.clone()))
+}
+
+pub fn set_organization_profile(env: &Env, organization: &Address, profile: &OrganizationProfile) {
+    let key = DataKey::OrganizationProfile(organization.clone());
+    env.storage().persistent().set(&key, profile);
+    bump_persistent_ttl(env, &key);
+}
+
+// --- Delegates ---
+
+pub fn get_delegate_role(env: &Env, organization: &Address, delegate: &Address) -> Option<OrganizationRole> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::OrganizationDelegate(organization.clone(), delegate.clone()))
+}
+
+pub fn set_delegate(
+    env: &Env,
+    organization: &Address,
+    delegate: &Address,
+    role: OrganizationRole,
+) -> Result<(), ContractError> {
+    let list_key = DataKey::OrganizationDelegates(organization.clone());
+    let mut delegates: Vec<Address> = get_vec_from_persistent_storage(env, &list_key);
+    if !delegates.contains(delegate) {
+        if delegates.len() >= MAX_DELEGATES_PER_ORG {
+            return Err(ContractError::TooManyDelegates);
+        }
+        delegates.push_back(delegate.clone());
+        env.storage().persistent().set(&list_key, &delegates);
+    }
+    bump_persistent_ttl(env, &list_key);
+
+    let key = DataKey::OrganizationDelegate(organization.clone(), delegate.clone());
+    env.storage().persistent().set(&key, &role);
+    bump_persistent_ttl(env, &key);
+
+    Ok(())
+}
+
+pub fn remove_delegate(env: &Env, organization: &Address, delegate: &Address) -> Result<(), ContractError> {
+    let list_key = DataKey::OrganizationDelegates(organization.clone());
+    let mut delegates: Vec<Address> = get_vec_from_persistent_storage(env, &list_key);
+    let index = delegates.first_index_of(delegate).ok_or(ContractError::DelegateNotFound)?;
+    delegates.remove(index);
+    env.storage().persistent().set(&list_key, &delegates);
+    bump_persistent_ttl(env, &list_key);
+
+    env.storage()
+        .persistent()
+        .remove(&DataKey::OrganizationDelegate(organization.clone(), delegate.clone()));
+
+    Ok(())
+}
+
+pub fn get_delegates(env: &Env, organization: &Address) -> Vec<OrganizationDelegate> {
+    let delegates: Vec<Address> = get_vec_from_persistent_storage(env, &DataKey::OrganizationDelegates(organization.clone()));
+    let mut result = Vec::new(env);
+    for delegate in delegates.iter() {
+        if let Some(role) = get_delegate_role(env, organization, &delegate) {
+            result.push_back(OrganizationDelegate { delegate, role });
+        }
+    }
+    result
+}
+
+// --- Applications ---
+
+/// Returns the application, reporting a pending one as expired once it has
+/// waited longer than APPLICATION_TTL.
+pub fn get_application(env: &Env, organization: &Address) -> Option<OrganizationApplication> {
+    let mut application: OrganizationApplication = env.storage()
+        .persistent()
+        .get(&DataKey::OrganizationApplication(organization.clone()))?;
+    if application.status == ApplicationStatus::Pending
+        && env.ledger().timestamp() >= application.applied_at.saturating_add(APPLICATION_TTL)
+    {
+        application.status = ApplicationStatus::Expired;
+    }
+    Some(application)
+}
+
+/// Stores a new pending application and queues it for review. The queue is
+/// ordered by submission, so expired applications sit at its front; a full
+/// queue drops a few of them to make room.
+pub fn store_pending_application(env: &Env, application: &OrganizationApplication) -> Result<(), ContractError> {
+    let list_key = DataKey::PendingApplications;
+    let mut pending: Vec<Address> = get_vec_from_persistent_storage(env, &list_key);
+    // A reapplication after expiry replaces the applicant's stale entry
+    if let Some(index) = pending.first_index_of(&application.organization) {
+        pending.remove(index);
+    }
+    let mut pruned = 0;
+    while pending.len() >= MAX_PENDING_APPLICATIONS && pruned < MAX_APPLICATION_PRUNE {
+        let expired = pending.first()
+            .and_then(|applicant| get_application(env, &applicant))
+            .is_some_and(|a| a.status == ApplicationStatus::Expired);
+        if !expired {
+            break;
+        }
+        pending.pop_front();
+        pruned += 1;
+    }
+    if pending.len() >= MAX_PENDING_APPLICATIONS {
+        return Err(ContractError::TooManyPendingApplications);
+    }
+    pending.push_back(application.organization.clone());
+    env.storage().persistent().set(&list_key, &pending);
+    bump_persistent_ttl(env, &list_key);
+
+    let key = DataKey::OrganizationApplication(application.organization.clone());
+    env.storage().persistent().set(&key, application);
+    bump_persistent_ttl(env, &key);
+    bump_instance_ttl(env);
+
+    Ok(())
+}
+
+/// Records the admin's decision and takes the application off the review queue.
+pub fn store_decided_application(env: &Env, application: &OrganizationApplication) {
+    let list_key = DataKey::PendingApplications;
+    let mut pending: Vec<Address> = get_vec_from_persistent_storage(env, &list_key);
+    if let Some(index) = pending.first_index_of(&application.organization) {
+        pending.remove(index);
+        env.storage().persistent().set(&list_key, &pending);
+        bump_persistent_ttl(env, &list_key);
+    }
+
+    let key = DataKey::OrganizationApplication(application.organization.clone());
+    env.storage().persistent().set(&key, application);
+    bump_persistent_ttl(env, &key);
+}
+
+pub fn get_pending_applicants(env: &Env) -> Vec<Address> {
+    get_vec_from_persistent_storage(env, &DataKey::PendingApplications)
+}
@@ -1,47 +1,82 @@
-use soroban_sdk::{Address, Env, String, Vec};
+use soroban_sdk::{Address, BytesN, Env, String, Vec};
 
+use crate::badge;
 use crate::error::ContractError;
 use crate::events;
+use crate::hooks;
+use crate::index_storage::IndexId;
 use crate::organization;
+use crate::organization_storage::{self, OrganizationRole, OrganizationStatus};
 use crate::storage;
+use crate::task;
+use crate::task_storage::Task;
 use crate::participation_storage::{
-    self, Participation, MAX_TASK_NAME_LEN, MAX_METADATA_LEN, MAX_TASK_ID_LEN, MAX_PAGINATION_LIMIT,
-    paginate_vec, get_participations_from_keys
+    self, BatchEntryResult, BatchEntryStatus, Evidence, EvidenceKind, Participation, ParticipationFilter,
+    ParticipationQueryResult, ParticipationRole, Revocation,
+    MAX_TASK_NAME_LEN, MAX_METADATA_LEN, MAX_TASK_ID_LEN, MAX_PAGINATION_LIMIT, MAX_REVOCATION_REASON_LEN,
+    MAX_BATCH_SIZE, MAX_EVIDENCE_URI_LEN, paginate_vec, get_participations_from_keys
 };
 
+const SECONDS_PER_HOUR: u64 = 3600;
 
-pub fn register_participation(
-    env: &Env,
-    organization: &Address,
-    volunteer: &Address,
+fn validate_participation_input(
     task_id: &String,
     task_name: &String,
-    metadata: Option<String>, 
+    metadata: &Option<String>,
 ) -> Result<(), ContractError> {
-
-    organization.require_auth();
-    organization::verify_organization(env, organization)?;
-
     // Validate input lengths
     if task_name.len() > MAX_TASK_NAME_LEN {
         return Err(ContractError::TaskNameTooLong);
     }
-    if task_id.len() == 0 {
+    if task_id.is_empty() {
         return Err(ContractError::TaskIdEmpty);
     }
     if task_id.len() > MAX_TASK_ID_LEN {
         return Err(ContractError::TaskIdTooLong);
     }
-    if let Some(ref m) = metadata {
+    if let Some(m) = metadata {
         if m.len() > MAX_METADATA_LEN {
             return Err(ContractError::MetadataTooLong);
         }
     }
+    Ok(())
+}
+
+// An empty task name defaults to the name the task was created with.
+fn resolve_task_name(task_name: &String, task: &Task) -> String {
+    if task_name.is_empty() {
+        task.name.clone()
+    } else {
+        task_name.clone()
+    }
+}
+
+/// Registers a participation signed either by the organization itself or by
+/// one of its delegates.
+pub fn register_participation(
+    env: &Env,
+    signer: &Address,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    task_name: &String,
+    metadata: Option<String>, 
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    organization::require_organization_signer(env, signer, organization, OrganizationRole::Coordinator)?;
+
+    validate_participation_input(task_id, task_name, &metadata)?;
+    let task = task::verify_task_for_participation(env, organization, task_id)?;
+    let task_name = &resolve_task_name(task_name, &task);
 
     // Check if participation already exists
     if participation_storage::has_participation(env, volunteer, task_id) {
         return Err(ContractError::ParticipationAlreadyRegistered);
     }
+    if participation_storage::has_pending_participation(env, volunteer, task_id) {
+        return Err(ContractError::AttestationAlreadyPending);
+    }
 
     let timestamp = env.ledger().timestamp();
     
@@ -52,22 +87,451 @@ pub fn register_participation(
         return Err(ContractError::InvalidTimestamp);
     }
 
-    // Store using participation storage function
+    record_participation(env, organization, volunteer, task_id, task_name, &metadata, timestamp)
+}
+
+// Stores a new participation and runs everything that follows a registration:
+// the event, the downstream hooks and the badge mint.
+fn record_participation(
+    env: &Env,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    task_name: &String,
+    metadata: &Option<String>,
+    timestamp: u64,
+) -> Result<(), ContractError> {
     participation_storage::store_participation(
         env,
         organization,
         volunteer,
         task_id,
         task_name,
-        &metadata,
+        metadata,
+        timestamp,
+    )?;
+
+    events::participation_registered(env, organization, volunteer, task_id, task_name, metadata, timestamp);
+    hooks::on_participation_registered(env, volunteer);
+    badge::on_participation_registered(env, volunteer, task_id);
+
+    Ok(())
+}
+
+/// Registers the same task for many volunteers in one call, signed by the
+/// organization or one of its coordinators. The signer, task and inputs are
+/// checked once for the whole batch; each volunteer then gets its own result so
+/// one duplicate does not fail the rest. Registered entries go through the same
+/// hooks and badge minting as single registrations.
+pub fn register_participations_batch(
+    env: &Env,
+    signer: &Address,
+    organization: &Address,
+    task_id: &String,
+    task_name: &String,
+    volunteers: &Vec<Address>,
+    metadata: Option<String>,
+) -> Result<Vec<BatchEntryResult>, ContractError> {
+    storage::require_not_paused(env)?;
+
+    organization::require_organization_signer(env, signer, organization, OrganizationRole::Coordinator)?;
+
+    if volunteers.is_empty() || volunteers.len() > MAX_BATCH_SIZE {
+        return Err(ContractError::InvalidBatchSize);
+    }
+
+    validate_participation_input(task_id, task_name, &metadata)?;
+    let task = task::verify_task_open(env, organization, task_id)?;
+    let task_name = &resolve_task_name(task_name, &task);
+
+    let timestamp = env.ledger().timestamp();
+    let mut registered = participation_storage::get_task_volunteer_count(env, task_id);
+    let mut results = Vec::new(env);
+
+    for volunteer in volunteers.iter() {
+        let status = if participation_storage::has_participation(env, &volunteer, task_id)
+            || participation_storage::has_pending_participation(env, &volunteer, task_id)
+        {
+            BatchEntryStatus::Duplicate
+        } else if registered >= task.max_volunteers {
+            BatchEntryStatus::LimitExceeded
+        } else {
+            record_participation(env, organization, &volunteer, task_id, task_name, &metadata, timestamp)?;
+            registered += 1;
+            BatchEntryStatus::Registered
+        };
+
+        results.push_back(BatchEntryResult { volunteer, status });
+    }
+
+    Ok(results)
+}
+
+/// First phase of a two-sided attestation: the organization, or one of its
+/// coordinators, proposes a record that only counts once the volunteer accepts it.
+pub fn propose_participation(
+    env: &Env,
+    signer: &Address,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    task_name: &String,
+    metadata: Option<String>,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    organization::require_organization_signer(env, signer, organization, OrganizationRole::Coordinator)?;
+
+    validate_participation_input(task_id, task_name, &metadata)?;
+    let task = task::verify_task_for_participation(env, organization, task_id)?;
+    let task_name = &resolve_task_name(task_name, &task);
+
+    if participation_storage::has_participation(env, volunteer, task_id) {
+        return Err(ContractError::ParticipationAlreadyRegistered);
+    }
+    if participation_storage::has_pending_participation(env, volunteer, task_id) {
+        return Err(ContractError::AttestationAlreadyPending);
+    }
+
+    let timestamp = env.ledger().timestamp();
+    let participation = Participation {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+        task_name: task_name.clone(),
         timestamp,
+        organization: organization.clone(),
+        metadata,
+        role: ParticipationRole::Helper,
+        check_in: timestamp,
+        check_out: None,
+        duration: 0,
+        badge_token_id: None,
+    };
+    participation_storage::store_pending_participation(env, &participation)?;
+
+    events::participation_proposed(env, organization, volunteer, task_id, task_name, timestamp);
+
+    Ok(())
+}
+
+/// Second phase of a two-sided attestation: the volunteer confirms the record.
+pub fn accept_participation(
+    env: &Env,
+    volunteer: &Address,
+    task_id: &String,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    volunteer.require_auth();
+
+    let pending = participation_storage::get_pending_participation(env, volunteer, task_id)
+        .ok_or(ContractError::AttestationNotFound)?;
+
+    // The proposing organization must still be registered and the task must
+    // still have room for the volunteer
+    organization::verify_organization(env, &pending.organization)?;
+    task::verify_task_for_participation(env, &pending.organization, task_id)?;
+
+    participation_storage::remove_pending_participation(env, volunteer, task_id);
+    participation_storage::store_participation(
+        env,
+        &pending.organization,
+        volunteer,
+        task_id,
+        &pending.task_name,
+        &pending.metadata,
+        pending.timestamp,
+    )?;
+
+    events::participation_accepted(env, &pending.organization, volunteer, task_id);
+    events::participation_registered(
+        env,
+        &pending.organization,
+        volunteer,
+        task_id,
+        &pending.task_name,
+        &pending.metadata,
+        pending.timestamp,
     );
 
-    events::participation_registered(env, organization, volunteer, task_id, task_name, &metadata, timestamp);
+    hooks::on_participation_registered(env, volunteer);
+    badge::on_participation_registered(env, volunteer, task_id);
 
     Ok(())
 }
 
+/// Lets a volunteer decline a record proposed for them.
+pub fn reject_participation(
+    env: &Env,
+    volunteer: &Address,
+    task_id: &String,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    volunteer.require_auth();
+
+    let pending = participation_storage::get_pending_participation(env, volunteer, task_id)
+        .ok_or(ContractError::AttestationNotFound)?;
+
+    participation_storage::remove_pending_participation(env, volunteer, task_id);
+    events::participation_rejected(env, &pending.organization, volunteer, task_id);
+
+    Ok(())
+}
+
+pub fn get_pending_attestations(
+    env: &Env,
+    volunteer: &Address,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<Participation>, ContractError> {
+    // Validate pagination limits
+    if limit > MAX_PAGINATION_LIMIT {
+        return Err(ContractError::PaginationLimitExceeded);
+    }
+
+    let pending_keys = participation_storage::get_volunteer_pending_keys(env, volunteer);
+    let paginated_keys = paginate_vec(env, &pending_keys, offset, limit)?;
+
+    let mut pending = Vec::new(env);
+    for p_key in paginated_keys.iter() {
+        if let Some(participation) =
+            participation_storage::get_pending_participation(env, &p_key.volunteer, &p_key.task_id)
+        {
+            pending.push_back(participation);
+        }
+    }
+    Ok(pending)
+}
+
+/// Records when a volunteer actually arrived, for volunteers registered late or
+/// in a batch after the event. Allowed until check-out; `check_in` must fall
+/// within the task's time window and not be in the future.
+pub fn record_check_in(
+    env: &Env,
+    signer: &Address,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    check_in: u64,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    organization::require_organization_signer(env, signer, organization, OrganizationRole::Coordinator)?;
+
+    let mut participation = participation_storage::get_participation(env, volunteer, task_id)
+        .ok_or(ContractError::ParticipationNotFound)?;
+    if participation.organization != *organization {
+        return Err(ContractError::NotAuthorized);
+    }
+    if participation.check_out.is_some() {
+        return Err(ContractError::AlreadyCheckedOut);
+    }
+    if check_in > env.ledger().timestamp() {
+        return Err(ContractError::InvalidTimestamp);
+    }
+    let task = task::get_task(env, task_id)?;
+    if check_in < task.start_time || check_in > task.end_time {
+        return Err(ContractError::TaskOutsideTimeWindow);
+    }
+
+    participation.check_in = check_in;
+    participation_storage::update_participation(env, &participation);
+
+    events::participation_checked_in(env, organization, volunteer, task_id, check_in);
+
+    Ok(())
+}
+
+/// Records when a volunteer left and credits the time to the hour totals.
+/// `check_out` may be in the past, but not before check-in or in the future.
+pub fn record_check_out(
+    env: &Env,
+    signer: &Address,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    check_out: u64,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    organization::require_organization_signer(env, signer, organization, OrganizationRole::Coordinator)?;
+
+    let mut participation = participation_storage::get_participation(env, volunteer, task_id)
+        .ok_or(ContractError::ParticipationNotFound)?;
+    if participation.organization != *organization {
+        return Err(ContractError::NotAuthorized);
+    }
+    if participation.check_out.is_some() {
+        return Err(ContractError::AlreadyCheckedOut);
+    }
+    if check_out <= participation.check_in || check_out > env.ledger().timestamp() {
+        return Err(ContractError::InvalidCheckOutTime);
+    }
+
+    participation.check_out = Some(check_out);
+    participation.duration = check_out - participation.check_in;
+    participation_storage::update_participation(env, &participation);
+    participation_storage::record_volunteered_seconds(env, &participation);
+
+    events::participation_checked_out(env, organization, volunteer, task_id, check_out, participation.duration);
+
+    // Credit only the whole hours this check-out adds to the volunteer's total
+    let total = participation_storage::get_seconds(env, &storage::DataKey::VolunteerSeconds(volunteer.clone()));
+    let previous = total.saturating_sub(participation.duration);
+    hooks::on_hours_recorded(env, volunteer, total / SECONDS_PER_HOUR - previous / SECONDS_PER_HOUR);
+
+    Ok(())
+}
+
+pub fn set_participation_role(
+    env: &Env,
+    signer: &Address,
+    organization: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    role: ParticipationRole,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    organization::require_organization_signer(env, signer, organization, OrganizationRole::Coordinator)?;
+
+    let mut participation = participation_storage::get_participation(env, volunteer, task_id)
+        .ok_or(ContractError::ParticipationNotFound)?;
+    if participation.organization != *organization {
+        return Err(ContractError::NotAuthorized);
+    }
+
+    participation.role = role;
+    participation_storage::update_participation(env, &participation);
+
+    events::participation_role_updated(env, organization, volunteer, task_id, role);
+
+    Ok(())
+}
+
+/// Whole hours volunteered, computed from the accumulated seconds.
+pub fn get_volunteer_hours(env: &Env, volunteer: &Address) -> u64 {
+    participation_storage::get_seconds(env, &storage::DataKey::VolunteerSeconds(volunteer.clone())) / SECONDS_PER_HOUR
+}
+
+pub fn get_organization_hours(env: &Env, organization: &Address) -> u64 {
+    participation_storage::get_seconds(env, &storage::DataKey::OrganizationSeconds(organization.clone())) / SECONDS_PER_HOUR
+}
+
+pub fn get_task_hours(env: &Env, task_id: &String) -> u64 {
+    participation_storage::get_seconds(env, &storage::DataKey::TaskSeconds(task_id.clone())) / SECONDS_PER_HOUR
+}
+
+/// Revokes a participation. Allowed for the issuing organization and the admin.
+/// The record is replaced by a tombstone; the same volunteer/task pair may be
+/// registered again afterwards to issue a corrected record.
+pub fn revoke_participation(
+    env: &Env,
+    caller: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    reason: &String,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    caller.require_auth();
+
+    let participation = participation_storage::get_participation(env, volunteer, task_id)
+        .ok_or(ContractError::ParticipationNotFound)?;
+
+    if participation.organization != *caller && storage::check_admin(env, caller).is_err() {
+        return Err(ContractError::NotAuthorized);
+    }
+
+    if reason.is_empty() {
+        return Err(ContractError::ReasonEmpty);
+    }
+    if reason.len() > MAX_REVOCATION_REASON_LEN {
+        return Err(ContractError::ReasonTooLong);
+    }
+
+    let timestamp = env.ledger().timestamp();
+    let revocation = Revocation {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+        organization: participation.organization.clone(),
+        revoked_by: caller.clone(),
+        reason: reason.clone(),
+        timestamp,
+    };
+
+    participation_storage::remove_participation(env, &participation, &revocation);
+    badge::on_participation_revoked(env, &participation);
+
+    events::participation_revoked(
+        env,
+        &participation.organization,
+        volunteer,
+        task_id,
+        caller,
+        reason,
+        timestamp,
+    );
+
+    Ok(())
+}
+
+/// Appends evidence to an existing participation. Either the volunteer or the
+/// issuing organization (including its delegates) may add entries.
+pub fn add_evidence(
+    env: &Env,
+    caller: &Address,
+    volunteer: &Address,
+    task_id: &String,
+    kind: EvidenceKind,
+    content_hash: BytesN<32>,
+    uri: Option<String>,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    let participation = participation_storage::get_participation(env, volunteer, task_id)
+        .ok_or(ContractError::ParticipationNotFound)?;
+    if caller == volunteer {
+        caller.require_auth();
+    } else {
+        organization::require_organization_signer(env, caller, &participation.organization, OrganizationRole::Coordinator)?;
+    }
+
+    if uri.as_ref().is_some_and(|u| u.len() > MAX_EVIDENCE_URI_LEN) {
+        return Err(ContractError::EvidenceUriTooLong);
+    }
+
+    let evidence = Evidence {
+        kind,
+        content_hash,
+        uri,
+        added_by: caller.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    participation_storage::add_evidence(env, volunteer, task_id, &evidence)?;
+
+    events::evidence_added(env, &participation.organization, volunteer, task_id, &evidence);
+
+    Ok(())
+}
+
+pub fn get_evidence(env: &Env, volunteer: &Address, task_id: &String) -> Result<Vec<Evidence>, ContractError> {
+    if !participation_storage::has_participation(env, volunteer, task_id) {
+        return Err(ContractError::ParticipationNotFound);
+    }
+    Ok(participation_storage::get_evidence(env, volunteer, task_id))
+}
+
+pub fn get_revocation(
+    env: &Env,
+    volunteer: &Address,
+    task_id: &String,
+) -> Result<Revocation, ContractError> {
+    participation_storage::get_revocation(env, volunteer, task_id)
+        .ok_or(ContractError::ParticipationNotFound)
+}
+
 pub fn verify_participation(
     env: &Env,
     volunteer: &Address,
@@ -87,6 +551,35 @@ pub fn get_participation_details(
 }
 
 
+/// Status of the organization that issued a participation, so verifiers can
+/// flag records issued by a since-deactivated organization.
+pub fn get_issuer_status(
+    env: &Env,
+    volunteer: &Address,
+    task_id: &String,
+) -> Result<OrganizationStatus, ContractError> {
+    let participation = get_participation_details(env, volunteer, task_id)?;
+    organization_storage::get_organization_status(env, &participation.organization)
+        .ok_or(ContractError::OrganizationNotRegistered)
+}
+
+/// Digest an off-chain certificate can embed to prove this participation.
+pub fn get_participation_proof(
+    env: &Env,
+    volunteer: &Address,
+    task_id: &String,
+) -> Result<BytesN<32>, ContractError> {
+    let participation = get_participation_details(env, volunteer, task_id)?;
+    Ok(participation_storage::compute_proof_digest(env, &participation))
+}
+
+/// Resolves a certificate digest to the current state of the participation it
+/// commits to. Digests of revoked records no longer verify.
+pub fn verify_participation_proof(env: &Env, digest: &BytesN<32>) -> Result<Participation, ContractError> {
+    let p_key = participation_storage::get_proof_key(env, digest).ok_or(ContractError::ProofNotFound)?;
+    participation_storage::get_participation(env, &p_key.volunteer, &p_key.task_id)
+        .ok_or(ContractError::ProofNotFound)
+}
 
 pub fn get_volunteer_participations(
     env: &Env,
@@ -100,8 +593,7 @@ pub fn get_volunteer_participations(
         return Err(ContractError::PaginationLimitExceeded);
     }
 
-    let participation_keys: Vec<storage::ParticipationKey> = participation_storage::get_volunteer_participation_keys(env, volunteer);
-    let paginated_keys = paginate_vec(env, &participation_keys, offset, limit)?;
+    let paginated_keys = participation_storage::get_volunteer_participation_keys(env, volunteer, offset, limit)?;
     let participations = get_participations_from_keys(env, &paginated_keys);
 
     Ok(participations)
@@ -118,8 +610,7 @@ pub fn get_task_volunteers(
         return Err(ContractError::PaginationLimitExceeded);
     }
 
-    let volunteers: Vec<Address> = participation_storage::get_task_volunteers_list(env, task_id);
-    paginate_vec(env, &volunteers, offset, limit)
+    participation_storage::get_task_volunteers_list(env, task_id, offset, limit)
 }
 
 pub fn get_organization_participations(
@@ -133,9 +624,45 @@ pub fn get_organization_participations(
         return Err(ContractError::PaginationLimitExceeded);
     }
    
-    let participation_keys = participation_storage::get_organization_participation_keys(env, organization);
-    let paginated_keys = paginate_vec(env, &participation_keys, offset, limit)?;
+    let paginated_keys = participation_storage::get_organization_participation_keys(env, organization, offset, limit)?;
     let participations = get_participations_from_keys(env, &paginated_keys);
 
     Ok(participations)
-}
\ No newline at end of file
+}
+
+pub fn query_volunteer_participations(
+    env: &Env,
+    volunteer: &Address,
+    filter: &ParticipationFilter,
+    cursor: u32,
+    limit: u32,
+) -> Result<ParticipationQueryResult, ContractError> {
+    validate_query(filter, limit)?;
+    participation_storage::query_participations(env, &IndexId::Volunteer(volunteer.clone()), filter, cursor, limit)
+}
+
+pub fn query_org_participations(
+    env: &Env,
+    organization: &Address,
+    filter: &ParticipationFilter,
+    cursor: u32,
+    limit: u32,
+) -> Result<ParticipationQueryResult, ContractError> {
+    validate_query(filter, limit)?;
+    participation_storage::query_participations(env, &IndexId::Organization(organization.clone()), filter, cursor, limit)
+}
+
+fn validate_query(filter: &ParticipationFilter, limit: u32) -> Result<(), ContractError> {
+    if limit > MAX_PAGINATION_LIMIT {
+        return Err(ContractError::PaginationLimitExceeded);
+    }
+    if let (Some(from), Some(to)) = (filter.from, filter.to)
+        && from > to
+    {
+        return Err(ContractError::InvalidTimestamp);
+    }
+    if filter.task_name_prefix.as_ref().is_some_and(|prefix| prefix.len() > MAX_TASK_NAME_LEN) {
+        return Err(ContractError::TaskNameTooLong);
+    }
+    Ok(())
+}
@@ -1,17 +1,53 @@
-use soroban_sdk::{Address, Env, String, Vec, contracttype};
+use soroban_sdk::{Address, BytesN, Env, String, Vec, contracttype, xdr::ToXdr};
 use crate::storage::{DataKey, ParticipationKey, bump_persistent_ttl, get_vec_from_persistent_storage, bump_instance_ttl};
 use crate::error::ContractError;
+use crate::index_storage::{self, IndexId, INDEX_PAGE_SIZE};
 
 // Define maximum lengths
 pub const MAX_TASK_NAME_LEN: u32 = 64;
 pub const MAX_METADATA_LEN: u32 = 128;
 pub const MAX_TASK_ID_LEN: u32 = 128;
 pub const MAX_PAGINATION_LIMIT: u32 = 100;
-pub const MAX_VOLUNTEERS_PER_TASK: u32 = 1000;
-pub const MAX_PARTICIPATIONS_PER_VOLUNTEER: u32 = 1000;
-pub const MAX_PARTICIPATIONS_PER_ORG: u32 = 10000;
+pub const MAX_REVOCATION_REASON_LEN: u32 = 128;
+pub const MAX_PENDING_PER_VOLUNTEER: u32 = 100;
+pub const MAX_PENDING_PER_ORGANIZATION: u32 = 10; // Pending attestations one organization may hold for a volunteer
+pub const PENDING_ATTESTATION_TTL: u64 = 30 * 24 * 3600; // Seconds a proposal waits for the volunteer before expiring
+pub const MAX_BATCH_SIZE: u32 = 10; // Measured with badges and hooks enabled and MAX_HOOK_GOALS goals per volunteer
+pub const MAX_EVIDENCE_PER_PARTICIPATION: u32 = 10;
+pub const MAX_EVIDENCE_URI_LEN: u32 = 256;
+pub const MAX_QUERY_SCAN: u32 = 200; // Index entries a single filtered query may read
+
+// Role a volunteer held during a task
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+#[contracttype]
+pub enum ParticipationRole {
+    Helper = 0,
+    Lead = 1,
+    Driver = 2,
+}
+
+// Outcome of a single entry in a batch registration
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+#[contracttype]
+pub enum BatchEntryStatus {
+    Registered = 0,
+    Duplicate = 1,      // Already registered, pending, or repeated within the batch
+    LimitExceeded = 2,  // Task capacity reached
+}
 
-// Participation data structure including optional metadata
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BatchEntryResult {
+    pub volunteer: Address,
+    pub status: BatchEntryStatus,
+}
+
+// Participation data structure including optional metadata.
+// `check_in` defaults to the registration time and can be corrected until
+// check-out; `duration` is the number of seconds between check-in and
+// check-out, zero until the volunteer is checked out.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct Participation {
@@ -21,8 +57,102 @@ pub struct Participation {
     pub timestamp: u64,
     pub organization: Address,
     pub metadata: Option<String>,
+    pub role: ParticipationRole,
+    pub check_in: u64,
+    pub check_out: Option<u64>,
+    pub duration: u64,
+    pub badge_token_id: Option<u128>,
+}
+
+// Fields of a participation a proof digest commits to. None of them change
+// after registration, so certificates stay valid across record updates. The
+// role, check-in/out times, duration and badge id are left out because the
+// organization or the badge mint sets them later.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ParticipationClaim {
+    pub volunteer: Address,
+    pub task_id: String,
+    pub task_name: String,
+    pub organization: Address,
+    pub timestamp: u64,
+    pub metadata: Option<String>,
+}
+
+// Entry of a volunteer's pending list. The proposing organization and expiry
+// are kept here so the list can be pruned and capped without loading records.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingEntry {
+    pub task_id: String,
+    pub organization: Address,
+    pub expires_at: u64,
+}
+
+// Tombstone kept after a participation is revoked
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Revocation {
+    pub volunteer: Address,
+    pub task_id: String,
+    pub organization: Address,
+    pub revoked_by: Address,
+    pub reason: String,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+#[contracttype]
+pub enum EvidenceKind {
+    Photo = 0,
+    SignInSheet = 1,
+    Gps = 2,
+}
+
+// Supporting material for a participation; the content itself lives off-chain
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Evidence {
+    pub kind: EvidenceKind,
+    pub content_hash: BytesN<32>,
+    pub uri: Option<String>,
+    pub added_by: Address,
+    pub timestamp: u64,
+}
+
+// Optional criteria for participation queries; unset fields match everything.
+// `from` and `to` bound the participation timestamp and are both inclusive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ParticipationFilter {
+    pub organization: Option<Address>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    pub task_name_prefix: Option<String>,
+}
+
+// One page of a filtered query. `next_cursor` is the index position to resume
+// scanning from, or None once the whole index has been scanned. `matched` counts
+// the matches between the page's cursor and `next_cursor`, so summing it across
+// pages gives the total; a query with limit 0 only counts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ParticipationQueryResult {
+    pub participations: Vec<Participation>,
+    pub matched: u32,
+    pub next_cursor: Option<u32>,
 }
 
+// Pages of the volunteer, task and organization indexes holding a
+// participation, so it can be unindexed without scanning every page
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ParticipationIndexPages {
+    pub volunteer: u32,
+    pub task: u32,
+    pub organization: u32,
+}
 
 pub fn store_participation(
     env: &Env,
@@ -32,7 +162,7 @@ pub fn store_participation(
     task_name: &String,
     metadata: &Option<String>,
     timestamp: u64,
-) {
+) -> Result<(), ContractError> {
     let p_key = ParticipationKey {
         volunteer: volunteer.clone(),
         task_id: task_id.clone(),
@@ -45,54 +175,279 @@ pub fn store_participation(
         timestamp,
         organization: organization.clone(),
         metadata: metadata.clone(),
+        role: ParticipationRole::Helper,
+        check_in: timestamp,
+        check_out: None,
+        duration: 0,
+        badge_token_id: None,
     };
 
-    let record_key = DataKey::ParticipationRecord(p_key.clone());
+    // Index first so a failure leaves no unindexed record behind
+    let pages = ParticipationIndexPages {
+        volunteer: index_storage::push(env, &IndexId::Volunteer(volunteer.clone()), &p_key)?,
+        task: index_storage::push(env, &IndexId::Task(task_id.clone()), volunteer)?,
+        organization: index_storage::push(env, &IndexId::Organization(organization.clone()), &p_key)?,
+    };
+    let pages_key = DataKey::ParticipationIndexPages(p_key.clone());
+    env.storage().persistent().set(&pages_key, &pages);
+    bump_persistent_ttl(env, &pages_key);
+
+    let record_key = DataKey::ParticipationRecord(p_key);
     env.storage().persistent().set(&record_key, &participation);
     bump_persistent_ttl(env, &record_key);
+    set_proof_digest(env, &participation);
+
+    // Bump instance TTL for general activity
+    bump_instance_ttl(env);
+
+    Ok(())
+}
+
+/// Removes a participation record and its index entries, leaving a tombstone.
+pub fn remove_participation(env: &Env, participation: &Participation, revocation: &Revocation) {
+    let p_key = ParticipationKey {
+        volunteer: participation.volunteer.clone(),
+        task_id: participation.task_id.clone(),
+    };
+
+    env.storage().persistent().remove(&DataKey::ParticipationRecord(p_key.clone()));
+    env.storage().persistent().remove(&DataKey::ParticipationEvidence(p_key.clone()));
+    remove_proof_digest(env, participation);
+
+    // Hours of a revoked record no longer count towards any total
+    if participation.duration > 0 {
+        subtract_seconds(env, &DataKey::VolunteerSeconds(participation.volunteer.clone()), participation.duration);
+        subtract_seconds(env, &DataKey::OrganizationSeconds(participation.organization.clone()), participation.duration);
+        subtract_seconds(env, &DataKey::TaskSeconds(participation.task_id.clone()), participation.duration);
+    }
+
+    let tombstone_key = DataKey::RevokedParticipation(p_key.clone());
+    env.storage().persistent().set(&tombstone_key, revocation);
+    bump_persistent_ttl(env, &tombstone_key);
+
+    // Drop the entries from the volunteer, task and organization indexes
+    let pages_key = DataKey::ParticipationIndexPages(p_key.clone());
+    if let Some(pages) = env.storage().persistent().get::<_, ParticipationIndexPages>(&pages_key) {
+        index_storage::remove(env, &IndexId::Volunteer(participation.volunteer.clone()), pages.volunteer, &p_key);
+        index_storage::remove(env, &IndexId::Task(participation.task_id.clone()), pages.task, &participation.volunteer);
+        index_storage::remove(env, &IndexId::Organization(participation.organization.clone()), pages.organization, &p_key);
+        env.storage().persistent().remove(&pages_key);
+    }
+
+    bump_instance_ttl(env);
+}
+
+/// Overwrites an existing participation record in place. Only mutable fields
+/// may change, so the record's proof digest stays the same.
+pub fn update_participation(env: &Env, participation: &Participation) {
+    let key = DataKey::ParticipationRecord(ParticipationKey {
+        volunteer: participation.volunteer.clone(),
+        task_id: participation.task_id.clone(),
+    });
+    env.storage().persistent().set(&key, participation);
+    bump_persistent_ttl(env, &key);
+}
+
+// --- Proof Digests ---
+
+/// SHA-256 of the canonical XDR encoding of a participation's ParticipationClaim.
+pub fn compute_proof_digest(env: &Env, participation: &Participation) -> BytesN<32> {
+    let claim = ParticipationClaim {
+        volunteer: participation.volunteer.clone(),
+        task_id: participation.task_id.clone(),
+        task_name: participation.task_name.clone(),
+        organization: participation.organization.clone(),
+        timestamp: participation.timestamp,
+        metadata: participation.metadata.clone(),
+    };
+    env.crypto().sha256(&claim.to_xdr(env)).into()
+}
+
+fn set_proof_digest(env: &Env, participation: &Participation) {
+    let key = DataKey::ProofDigest(compute_proof_digest(env, participation));
+    let p_key = ParticipationKey {
+        volunteer: participation.volunteer.clone(),
+        task_id: participation.task_id.clone(),
+    };
+    env.storage().persistent().set(&key, &p_key);
+    bump_persistent_ttl(env, &key);
+}
+
+fn remove_proof_digest(env: &Env, participation: &Participation) {
+    let key = DataKey::ProofDigest(compute_proof_digest(env, participation));
+    env.storage().persistent().remove(&key);
+}
+
+pub fn get_proof_key(env: &Env, digest: &BytesN<32>) -> Option<ParticipationKey> {
+    env.storage().persistent().get(&DataKey::ProofDigest(digest.clone()))
+}
+
+// --- Evidence ---
+
+pub fn get_evidence(env: &Env, volunteer: &Address, task_id: &String) -> Vec<Evidence> {
+    let key = DataKey::ParticipationEvidence(ParticipationKey {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+    });
+    get_vec_from_persistent_storage(env, &key)
+}
+
+pub fn add_evidence(
+    env: &Env,
+    volunteer: &Address,
+    task_id: &String,
+    evidence: &Evidence,
+) -> Result<(), ContractError> {
+    let key = DataKey::ParticipationEvidence(ParticipationKey {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+    });
+    let mut entries: Vec<Evidence> = get_vec_from_persistent_storage(env, &key);
+    if entries.len() >= MAX_EVIDENCE_PER_PARTICIPATION {
+        return Err(ContractError::EvidenceLimitReached);
+    }
+    entries.push_back(evidence.clone());
+    env.storage().persistent().set(&key, &entries);
+    bump_persistent_ttl(env, &key);
+
+    Ok(())
+}
+
+// --- Volunteered Time Totals ---
 
-    // Update volunteer's participation list (storing keys now)
-    let volunteer_list_key = DataKey::VolunteerParticipations(volunteer.clone());
-    let mut volunteer_participation_keys: Vec<ParticipationKey> = get_vec_from_persistent_storage(env, &volunteer_list_key);
-    
-    // Check storage limits
-    if volunteer_participation_keys.len() as u32 >= MAX_PARTICIPATIONS_PER_VOLUNTEER {
-        return; // Prevent unbounded growth
-    }
-    
-    volunteer_participation_keys.push_back(p_key.clone());
-    env.storage().persistent().set(&volunteer_list_key, &volunteer_participation_keys);
-    bump_persistent_ttl(env, &volunteer_list_key);
-
-    // Update task's volunteer list
-    let task_list_key = DataKey::TaskVolunteers(task_id.clone());
-    let mut task_volunteers: Vec<Address> = get_vec_from_persistent_storage(env, &task_list_key);
-    // Avoid duplicates in task volunteer list
-    if !task_volunteers.contains(volunteer) {
-        // Check storage limits
-        if task_volunteers.len() as u32 >= MAX_VOLUNTEERS_PER_TASK {
-            return; // Prevent unbounded growth
+pub fn get_seconds(env: &Env, key: &DataKey) -> u64 {
+    env.storage().persistent().get(key).unwrap_or(0)
+}
+
+fn add_seconds(env: &Env, key: &DataKey, seconds: u64) {
+    let total = get_seconds(env, key).saturating_add(seconds);
+    env.storage().persistent().set(key, &total);
+    bump_persistent_ttl(env, key);
+}
+
+fn subtract_seconds(env: &Env, key: &DataKey, seconds: u64) {
+    let total = get_seconds(env, key).saturating_sub(seconds);
+    env.storage().persistent().set(key, &total);
+    bump_persistent_ttl(env, key);
+}
+
+/// Credits a checked-out participation to the volunteer, organization and task totals.
+pub fn record_volunteered_seconds(env: &Env, participation: &Participation) {
+    add_seconds(env, &DataKey::VolunteerSeconds(participation.volunteer.clone()), participation.duration);
+    add_seconds(env, &DataKey::OrganizationSeconds(participation.organization.clone()), participation.duration);
+    add_seconds(env, &DataKey::TaskSeconds(participation.task_id.clone()), participation.duration);
+    bump_instance_ttl(env);
+}
+
+// --- Pending Attestations ---
+
+pub fn has_pending_participation(env: &Env, volunteer: &Address, task_id: &String) -> bool {
+    get_pending_participation(env, volunteer, task_id).is_some()
+}
+
+/// Pending record awaiting the volunteer, or None once it has expired.
+pub fn get_pending_participation(
+    env: &Env,
+    volunteer: &Address,
+    task_id: &String,
+) -> Option<Participation> {
+    let key = ParticipationKey {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+    };
+    let pending: Participation = env.storage().persistent().get(&DataKey::PendingParticipation(key))?;
+    if env.ledger().timestamp() > pending.timestamp.saturating_add(PENDING_ATTESTATION_TTL) {
+        return None;
+    }
+    Some(pending)
+}
+
+// Expired entries are dropped from the list only; their records stop being
+// readable at expiry and are overwritten by any new proposal for the task.
+fn get_live_pending_entries(env: &Env, volunteer: &Address) -> Vec<PendingEntry> {
+    let now = env.ledger().timestamp();
+    let mut live = Vec::new(env);
+    for entry in get_vec_from_persistent_storage::<PendingEntry>(env, &DataKey::VolunteerPendingList(volunteer.clone())).iter() {
+        if entry.expires_at >= now {
+            live.push_back(entry);
         }
-        task_volunteers.push_back(volunteer.clone());
-        env.storage().persistent().set(&task_list_key, &task_volunteers);
     }
-    bump_persistent_ttl(env, &task_list_key);
+    live
+}
 
-    // Update organization's participation list
-    let org_list_key = DataKey::OrgParticipationList(organization.clone());
-    let mut org_participation_keys: Vec<ParticipationKey> = get_vec_from_persistent_storage(env, &org_list_key);
-    
-    // Check storage limits
-    if org_participation_keys.len() as u32 >= MAX_PARTICIPATIONS_PER_ORG {
-        return; // Prevent unbounded growth
+fn set_pending_entries(env: &Env, volunteer: &Address, entries: &Vec<PendingEntry>) {
+    let list_key = DataKey::VolunteerPendingList(volunteer.clone());
+    if entries.is_empty() {
+        env.storage().persistent().remove(&list_key);
+        return;
     }
-    
-    org_participation_keys.push_back(p_key.clone()); // p_key includes volunteer and task_id
-    env.storage().persistent().set(&org_list_key, &org_participation_keys);
-    bump_persistent_ttl(env, &org_list_key);
+    env.storage().persistent().set(&list_key, entries);
+    bump_persistent_ttl(env, &list_key);
+}
+
+pub fn store_pending_participation(env: &Env, participation: &Participation) -> Result<(), ContractError> {
+    let mut entries = get_live_pending_entries(env, &participation.volunteer);
+    if entries.len() >= MAX_PENDING_PER_VOLUNTEER {
+        return Err(ContractError::TooManyPendingAttestations);
+    }
+    let from_organization = entries
+        .iter()
+        .filter(|entry| entry.organization == participation.organization)
+        .count() as u32;
+    if from_organization >= MAX_PENDING_PER_ORGANIZATION {
+        return Err(ContractError::TooManyPendingAttestations);
+    }
+
+    entries.push_back(PendingEntry {
+        task_id: participation.task_id.clone(),
+        organization: participation.organization.clone(),
+        expires_at: participation.timestamp.saturating_add(PENDING_ATTESTATION_TTL),
+    });
+    set_pending_entries(env, &participation.volunteer, &entries);
+
+    let record_key = DataKey::PendingParticipation(ParticipationKey {
+        volunteer: participation.volunteer.clone(),
+        task_id: participation.task_id.clone(),
+    });
+    env.storage().persistent().set(&record_key, participation);
+    bump_persistent_ttl(env, &record_key);
 
-    // Bump instance TTL for general activity
     bump_instance_ttl(env);
+    Ok(())
+}
+
+pub fn remove_pending_participation(env: &Env, volunteer: &Address, task_id: &String) {
+    let p_key = ParticipationKey {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+    };
+    env.storage().persistent().remove(&DataKey::PendingParticipation(p_key));
+
+    let mut entries = get_live_pending_entries(env, volunteer);
+    if let Some(index) = entries.iter().position(|entry| entry.task_id == *task_id) {
+        entries.remove(index as u32);
+    }
+    set_pending_entries(env, volunteer, &entries);
+}
+
+/// Keys of the volunteer's pending attestations that have not expired.
+pub fn get_volunteer_pending_keys(env: &Env, volunteer: &Address) -> Vec<ParticipationKey> {
+    let mut keys = Vec::new(env);
+    for entry in get_live_pending_entries(env, volunteer).iter() {
+        keys.push_back(ParticipationKey {
+            volunteer: volunteer.clone(),
+            task_id: entry.task_id,
+        });
+    }
+    keys
+}
+
+pub fn get_revocation(env: &Env, volunteer: &Address, task_id: &String) -> Option<Revocation> {
+    let key = ParticipationKey {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+    };
+    env.storage().persistent().get(&DataKey::RevokedParticipation(key))
 }
 
 pub fn has_participation(env: &Env, volunteer: &Address, task_id: &String) -> bool {
@@ -115,16 +470,110 @@ pub fn get_participation(
     env.storage().persistent().get(&DataKey::ParticipationRecord(key))
 }
 
-pub fn get_volunteer_participation_keys(env: &Env, volunteer: &Address) -> Vec<ParticipationKey> {
-    get_vec_from_persistent_storage(env, &DataKey::VolunteerParticipations(volunteer.clone()))
+pub fn get_volunteer_participation_keys(
+    env: &Env,
+    volunteer: &Address,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<ParticipationKey>, ContractError> {
+    index_storage::get_range(env, &IndexId::Volunteer(volunteer.clone()), offset, limit)
 }
 
-pub fn get_task_volunteers_list(env: &Env, task_id: &String) -> Vec<Address> {
-    get_vec_from_persistent_storage(env, &DataKey::TaskVolunteers(task_id.clone()))
+pub fn get_task_volunteers_list(env: &Env, task_id: &String, offset: u32, limit: u32) -> Result<Vec<Address>, ContractError> {
+    index_storage::get_range(env, &IndexId::Task(task_id.clone()), offset, limit)
 }
 
-pub fn get_organization_participation_keys(env: &Env, organization: &Address) -> Vec<ParticipationKey> {
-    get_vec_from_persistent_storage(env, &DataKey::OrgParticipationList(organization.clone()))
+pub fn get_task_volunteer_count(env: &Env, task_id: &String) -> u32 {
+    index_storage::len(env, &IndexId::Task(task_id.clone()))
+}
+
+pub fn get_organization_participation_keys(
+    env: &Env,
+    organization: &Address,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<ParticipationKey>, ContractError> {
+    index_storage::get_range(env, &IndexId::Organization(organization.clone()), offset, limit)
+}
+
+/// Scans the index from position `cursor`, one page at a time, until `limit`
+/// matches are found or MAX_QUERY_SCAN entries have been read. With a zero
+/// `limit` the whole scan budget is spent counting matches without returning
+/// them. Revocations between calls shift later positions, so a resumed scan
+/// may skip or repeat a few entries.
+pub fn query_participations(
+    env: &Env,
+    id: &IndexId,
+    filter: &ParticipationFilter,
+    cursor: u32,
+    limit: u32,
+) -> Result<ParticipationQueryResult, ContractError> {
+    let len = index_storage::len(env, id);
+    let end = cursor.saturating_add(MAX_QUERY_SCAN).min(len);
+    let mut position = cursor;
+    let count_only = limit == 0;
+    let mut participations = Vec::new(env);
+    let mut matched = 0;
+
+    while position < end && (count_only || participations.len() < limit) {
+        let chunk = (end - position).min(INDEX_PAGE_SIZE);
+        let keys: Vec<ParticipationKey> = index_storage::get_range(env, id, position, chunk)?;
+        if keys.is_empty() {
+            break;
+        }
+
+        for key in keys.iter() {
+            position += 1;
+            let Some(participation) = get_participation(env, &key.volunteer, &key.task_id) else {
+                continue;
+            };
+            if !matches_filter(&participation, filter) {
+                continue;
+            }
+            matched += 1;
+            if !count_only {
+                participations.push_back(participation);
+                if participations.len() >= limit {
+                    break;
+                }
+            }
+        }
+    }
+
+    let next_cursor = if position < len { Some(position) } else { None };
+    Ok(ParticipationQueryResult { participations, matched, next_cursor })
+}
+
+fn matches_filter(participation: &Participation, filter: &ParticipationFilter) -> bool {
+    if filter.organization.as_ref().is_some_and(|org| *org != participation.organization) {
+        return false;
+    }
+    if filter.from.is_some_and(|from| participation.timestamp < from) {
+        return false;
+    }
+    if filter.to.is_some_and(|to| participation.timestamp > to) {
+        return false;
+    }
+    match &filter.task_name_prefix {
+        Some(prefix) => has_prefix(&participation.task_name, prefix),
+        None => true,
+    }
+}
+
+// Both strings are bounded by MAX_TASK_NAME_LEN, so they fit stack buffers
+fn has_prefix(value: &String, prefix: &String) -> bool {
+    const BUF_LEN: usize = MAX_TASK_NAME_LEN as usize;
+    let value_len = value.len() as usize;
+    let prefix_len = prefix.len() as usize;
+    if prefix_len > value_len || value_len > BUF_LEN {
+        return false;
+    }
+
+    let mut value_buf = [0u8; BUF_LEN];
+    let mut prefix_buf = [0u8; BUF_LEN];
+    value.copy_into_slice(&mut value_buf[..value_len]);
+    prefix.copy_into_slice(&mut prefix_buf[..prefix_len]);
+    value_buf[..prefix_len] == prefix_buf[..prefix_len]
 }
 
 /// Helper to paginate a Vec<T>.
@@ -1,6 +1,7 @@
-use soroban_sdk::{Address, Env, String, Vec, contracttype};
+use soroban_sdk::{Address, BytesN, Env, String, Vec, contracttype};
 
 use crate::error::ContractError;
+use crate::index_storage::IndexId;
 
 // Storage lifetime constants
 // Bump these values if more complex operations require more budget
@@ -15,12 +16,34 @@ const PERSISTENT_BUMP_AMOUNT: u32 = 180 * DAY_IN_LEDGERS; // ~180 days bump amou
 #[contracttype]
 pub enum DataKey {
     Admin,                              // Admin address (Instance storage)
+    PendingAdmin,                       // Proposed admin awaiting acceptance (Instance storage)
+    CoAdmins,                           // List of co-admin addresses (Instance storage)
+    Paused,                             // Pause flag blocking state changes (Instance storage)
+    HookConfig,                         // Downstream goal-tracker / reputation-system contracts (Instance storage)
+    BadgeConfig,                        // nft-core contract minting participation badges (Instance storage)
     Organization(Address),              // Maps organization address -> name (Persistent)
+    OrganizationProfile(Address),       // Maps organization address -> OrganizationProfile (Persistent)
+    OrganizationDelegate(Address, Address), // Maps (organization, delegate) -> OrganizationRole (Persistent)
+    OrganizationDelegates(Address),     // Maps organization -> list of delegate Addresses (Persistent)
+    OrganizationApplication(Address),   // Maps applicant address -> latest OrganizationApplication (Persistent)
+    PendingApplications,                // List of applicant addresses awaiting review (Persistent)
     OrganizationList,                   // List of all registered organizations (Persistent)
     ParticipationRecord(ParticipationKey),// Maps (volunteer, task_id) -> Participation (Persistent)
-    VolunteerParticipations(Address),   // Maps volunteer -> list of ParticipationKeys (Persistent)
-    TaskVolunteers(String),             // Maps task_id -> list of volunteer Addresses (Persistent)
-    OrgParticipationList(Address),      // Maps organization -> list of ParticipationKeys (Persistent)
+    IndexMeta(IndexId),                 // Maps index -> entry count and page lengths (Persistent)
+    IndexPage(IndexId, u32),            // Maps (index, page number) -> page of entries (Persistent)
+    ParticipationIndexPages(ParticipationKey), // Maps (volunteer, task_id) -> pages holding its index entries (Persistent)
+    ParticipationEvidence(ParticipationKey), // Maps (volunteer, task_id) -> list of Evidence (Persistent)
+    ProofDigest(BytesN<32>),            // Maps SHA-256 of a Participation's XDR -> its ParticipationKey (Persistent)
+    RevokedParticipation(ParticipationKey), // Maps (volunteer, task_id) -> Revocation tombstone (Persistent)
+    ParticipationBadge(ParticipationKey), // Maps (volunteer, task_id) -> badge token id, kept after revocation (Persistent)
+    BadgeParticipation(u128),           // Maps badge token id -> ParticipationKey it was minted for (Persistent)
+    PendingParticipation(ParticipationKey), // Maps (volunteer, task_id) -> Participation awaiting volunteer acceptance (Persistent)
+    VolunteerPendingList(Address),      // Maps volunteer -> list of PendingEntry awaiting acceptance (Persistent)
+    Task(String),                       // Maps task_id -> Task (Persistent)
+    OrganizationTasks(Address),         // Maps organization -> list of task_ids (Persistent)
+    VolunteerSeconds(Address),          // Maps volunteer -> total seconds volunteered (Persistent)
+    OrganizationSeconds(Address),       // Maps organization -> total seconds volunteered (Persistent)
+    TaskSeconds(String),                // Maps task_id -> total seconds volunteered (Persistent)
 }
 
 // Key struct for Participation records
@@ -48,7 +71,17 @@ pub fn set_admin(env: &Env, admin: &Address) {
     bump_instance_ttl(env);
 }
 
+/// Accepts the admin or any co-admin.
 pub fn check_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
+    if get_admin(env) == *admin || get_co_admins(env).contains(admin) {
+        Ok(())
+    } else {
+        Err(ContractError::NotAuthorized)
+    }
+}
+
+/// Accepts only the admin; used for managing the admin set itself.
+pub fn check_primary_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
     if get_admin(env) != *admin {
         Err(ContractError::NotAuthorized)
     } else {
@@ -56,6 +89,47 @@ pub fn check_admin(env: &Env, admin: &Address) -> Result<(), ContractError> {
     }
 }
 
+pub fn get_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PendingAdmin)
+}
+
+pub fn set_pending_admin(env: &Env, pending: &Address) {
+    env.storage().instance().set(&DataKey::PendingAdmin, pending);
+    bump_instance_ttl(env);
+}
+
+pub fn remove_pending_admin(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingAdmin);
+}
+
+pub fn get_co_admins(env: &Env) -> Vec<Address> {
+    env.storage().instance().get(&DataKey::CoAdmins).unwrap_or(Vec::new(env))
+}
+
+pub fn set_co_admins(env: &Env, co_admins: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::CoAdmins, co_admins);
+    bump_instance_ttl(env);
+}
+
+// --- Pause ---
+
+pub fn is_paused(env: &Env) -> bool {
+    env.storage().instance().get(&DataKey::Paused).unwrap_or(false)
+}
+
+pub fn set_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&DataKey::Paused, &paused);
+    bump_instance_ttl(env);
+}
+
+pub fn require_not_paused(env: &Env) -> Result<(), ContractError> {
+    if is_paused(env) {
+        Err(ContractError::ContractPaused)
+    } else {
+        Ok(())
+    }
+}
+
 // --- TTL Bump Helpers ---
 
 /// Bumps the TTL for instance storage.
@@ -0,0 +1,165 @@
+use soroban_sdk::{Address, Env, String, Vec};
+
+use crate::error::ContractError;
+use crate::events;
+use crate::organization;
+use crate::organization_storage::OrganizationRole;
+use crate::storage;
+use crate::participation_storage::{self, MAX_TASK_ID_LEN, MAX_TASK_NAME_LEN, MAX_PAGINATION_LIMIT, paginate_vec};
+use crate::task_storage::{self, Task, TaskParams, TaskStatus, MAX_TASK_DESCRIPTION_LEN, MAX_TASKS_PER_ORG};
+
+pub fn create_task(
+    env: &Env,
+    signer: &Address,
+    organization: &Address,
+    params: TaskParams,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    let TaskParams {
+        task_id,
+        name,
+        description,
+        start_time,
+        end_time,
+        max_volunteers,
+    } = params;
+
+    organization::require_organization_signer(env, signer, organization, OrganizationRole::Coordinator)?;
+
+    if task_id.is_empty() {
+        return Err(ContractError::TaskIdEmpty);
+    }
+    if task_id.len() > MAX_TASK_ID_LEN {
+        return Err(ContractError::TaskIdTooLong);
+    }
+    if name.len() > MAX_TASK_NAME_LEN {
+        return Err(ContractError::TaskNameTooLong);
+    }
+    if description.len() > MAX_TASK_DESCRIPTION_LEN {
+        return Err(ContractError::TaskDescriptionTooLong);
+    }
+    if start_time >= end_time {
+        return Err(ContractError::InvalidTimestamp);
+    }
+    if max_volunteers == 0 {
+        return Err(ContractError::InvalidTaskCapacity);
+    }
+
+    if task_storage::has_task(env, &task_id) {
+        return Err(ContractError::TaskAlreadyExists);
+    }
+    if task_storage::get_organization_task_ids(env, organization).len() >= MAX_TASKS_PER_ORG {
+        return Err(ContractError::TooManyTasks);
+    }
+
+    let task = Task {
+        task_id: task_id.clone(),
+        organization: organization.clone(),
+        name: name.clone(),
+        description,
+        start_time,
+        end_time,
+        max_volunteers,
+        status: TaskStatus::Open,
+    };
+    task_storage::store_task(env, &task);
+    task_storage::add_task_to_organization(env, organization, &task_id);
+
+    events::task_created(env, organization, &task_id, &name, start_time, end_time);
+
+    Ok(())
+}
+
+pub fn update_task_status(
+    env: &Env,
+    organization: &Address,
+    task_id: &String,
+    status: TaskStatus,
+) -> Result<(), ContractError> {
+    storage::require_not_paused(env)?;
+
+    organization.require_auth();
+
+    let mut task = task_storage::get_task(env, task_id).ok_or(ContractError::TaskNotFound)?;
+    if task.organization != *organization {
+        return Err(ContractError::TaskNotOwnedByOrganization);
+    }
+    // Cancelled tasks are final
+    if task.status == TaskStatus::Cancelled {
+        return Err(ContractError::TaskNotOpen);
+    }
+
+    task.status = status;
+    task_storage::store_task(env, &task);
+
+    events::task_status_updated(env, organization, task_id, status);
+
+    Ok(())
+}
+
+/// Checks that the task belongs to the organization and is accepting
+/// registrations right now, without looking at its capacity.
+pub fn verify_task_open(
+    env: &Env,
+    organization: &Address,
+    task_id: &String,
+) -> Result<Task, ContractError> {
+    let task = task_storage::get_task(env, task_id).ok_or(ContractError::TaskNotFound)?;
+
+    if task.organization != *organization {
+        return Err(ContractError::TaskNotOwnedByOrganization);
+    }
+    if task.status != TaskStatus::Open {
+        return Err(ContractError::TaskNotOpen);
+    }
+
+    let now = env.ledger().timestamp();
+    if now < task.start_time || now > task.end_time {
+        return Err(ContractError::TaskOutsideTimeWindow);
+    }
+
+    Ok(task)
+}
+
+pub fn verify_task_for_participation(
+    env: &Env,
+    organization: &Address,
+    task_id: &String,
+) -> Result<Task, ContractError> {
+    let task = verify_task_open(env, organization, task_id)?;
+
+    let registered = participation_storage::get_task_volunteer_count(env, task_id);
+    if registered >= task.max_volunteers {
+        return Err(ContractError::TaskCapacityReached);
+    }
+
+    Ok(task)
+}
+
+pub fn get_task(env: &Env, task_id: &String) -> Result<Task, ContractError> {
+    task_storage::get_task(env, task_id).ok_or(ContractError::TaskNotFound)
+}
+
+pub fn get_organization_tasks(
+    env: &Env,
+    organization: &Address,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<Task>, ContractError> {
+    // Validate pagination limits
+    if limit > MAX_PAGINATION_LIMIT {
+        return Err(ContractError::PaginationLimitExceeded);
+    }
+
+    let task_ids = task_storage::get_organization_task_ids(env, organization);
+    let paginated_ids = paginate_vec(env, &task_ids, offset, limit)?;
+
+    let mut tasks = Vec::new(env);
+    for task_id in paginated_ids.iter() {
+        if let Some(task) = task_storage::get_task(env, &task_id) {
+            tasks.push_back(task);
+        }
+    }
+    Ok(tasks)
+}
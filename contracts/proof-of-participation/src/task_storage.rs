@@ -0,0 +1,69 @@
+use soroban_sdk::{Address, Env, String, Vec, contracttype};
+use crate::storage::{DataKey, bump_persistent_ttl, get_vec_from_persistent_storage, bump_instance_ttl};
+
+// Task validation constants
+pub const MAX_TASK_DESCRIPTION_LEN: u32 = 256;
+pub const MAX_TASKS_PER_ORG: u32 = 1000;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+#[contracttype]
+pub enum TaskStatus {
+    Open = 0,
+    Closed = 1,
+    Cancelled = 2,
+}
+
+// Registered task an organization can attribute participation to
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Task {
+    pub task_id: String,
+    pub organization: Address,
+    pub name: String,
+    pub description: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub max_volunteers: u32,
+    pub status: TaskStatus,
+}
+
+// Caller-supplied fields of a new task
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TaskParams {
+    pub task_id: String,
+    pub name: String,
+    pub description: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub max_volunteers: u32,
+}
+
+pub fn store_task(env: &Env, task: &Task) {
+    let task_key = DataKey::Task(task.task_id.clone());
+    env.storage().persistent().set(&task_key, task);
+    bump_persistent_ttl(env, &task_key);
+    bump_instance_ttl(env);
+}
+
+/// Adds a newly created task to its organization's task list.
+pub fn add_task_to_organization(env: &Env, organization: &Address, task_id: &String) {
+    let list_key = DataKey::OrganizationTasks(organization.clone());
+    let mut task_ids: Vec<String> = get_vec_from_persistent_storage(env, &list_key);
+    task_ids.push_back(task_id.clone());
+    env.storage().persistent().set(&list_key, &task_ids);
+    bump_persistent_ttl(env, &list_key);
+}
+
+pub fn get_task(env: &Env, task_id: &String) -> Option<Task> {
+    env.storage().persistent().get(&DataKey::Task(task_id.clone()))
+}
+
+pub fn has_task(env: &Env, task_id: &String) -> bool {
+    env.storage().persistent().has(&DataKey::Task(task_id.clone()))
+}
+
+pub fn get_organization_task_ids(env: &Env, organization: &Address) -> Vec<String> {
+    get_vec_from_persistent_storage(env, &DataKey::OrganizationTasks(organization.clone()))
+}
@@ -2,12 +2,16 @@
 use super::*;
 
 use crate::storage as core_storage;
-use crate::organization_storage;
-use crate::participation_storage::{self, Participation};
+use crate::organization_storage::{self, ApplicationStatus, OrganizationRole, OrganizationStatus};
+use crate::index_storage::{self, IndexId, INDEX_PAGE_SIZE};
+use crate::task_storage::TaskParams;
+use crate::participation_storage::{
+    self, BatchEntryStatus, EvidenceKind, Participation, ParticipationClaim, ParticipationFilter, ParticipationRole,
+};
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo, Logs, storage::Persistent, storage::Instance},
-    Address, Env, String as SdkString, Vec,
+    xdr::ToXdr, Address, Env, String as SdkString, Vec,
 };
 
 
@@ -41,6 +45,31 @@ fn str_to_sdkstring(env: &Env, s: &str) -> SdkString {
     SdkString::from_str(env, s)
 }
 
+// Helper to build task parameters
+fn task_params(task_id: &SdkString, name: &SdkString, description: &SdkString, start_time: u64, end_time: u64, max_volunteers: u32) -> TaskParams {
+    TaskParams {
+        task_id: task_id.clone(),
+        name: name.clone(),
+        description: description.clone(),
+        start_time,
+        end_time,
+        max_volunteers,
+    }
+}
+
+// Helper to register an open task with no time or capacity constraints
+fn create_open_task(env: &Env, client: &ProofOfParticipationContractClient, organization: &Address, task_id: &SdkString) {
+    let params = task_params(
+        task_id,
+        &str_to_sdkstring(env, "Task"),
+        &str_to_sdkstring(env, "Task_Description"),
+        0,
+        u64::MAX,
+        1000,
+    );
+    client.create_task(organization, organization, &params);
+}
+
 // --- Test Cases ---
 
 #[test]
@@ -133,7 +162,7 @@ fn test_register_organization_already_registered() {
 
 
 #[test]
-fn test_remove_organization_success() {
+fn test_deactivate_organization_success() {
     let env = create_env();
     let contract_id = register_contract(&env);
     let client = create_client(&env, &contract_id);
@@ -144,20 +173,26 @@ fn test_remove_organization_success() {
     client.initialize(&admin);
     client.register_organization(&admin, &organization, &name);
     assert!(client.is_organization(&organization));
-    client.remove_organization(&admin, &organization);
+    setup_ledger_time(&env, 1700000000);
+    client.deactivate_organization(&admin, &organization);
     assert!(!client.is_organization(&organization));
 
+    let profile = client.get_organization_profile(&organization);
+    assert_eq!(profile.status, OrganizationStatus::Deactivated);
+    assert_eq!(profile.status_changed_at, 1700000000);
+
     env.as_contract(&contract_id, || {
-         assert!(!organization_storage::is_organization_registered(&env, &organization));
+         // Kept in the registry so its records stay attributable
+         assert!(organization_storage::is_organization_registered(&env, &organization));
          let orgs = organization_storage::get_all_organizations(&env);
-         assert!(!orgs.contains(&organization));
+         assert!(orgs.contains(&organization));
     });
     env.logs().print();
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #2)")]
-fn test_remove_organization_not_admin() {
+fn test_deactivate_organization_not_admin() {
     let env = create_env();
     let contract_id = register_contract(&env);
     let client = create_client(&env, &contract_id);
@@ -168,12 +203,12 @@ fn test_remove_organization_not_admin() {
     env.mock_all_auths();
     client.initialize(&admin);
     client.register_organization(&admin, &organization, &name);
-    client.remove_organization(&not_admin, &organization); // Should panic
+    client.deactivate_organization(&not_admin, &organization); // Should panic
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #102)")]
-fn test_remove_organization_not_registered() {
+fn test_deactivate_organization_not_registered() {
     let env = create_env();
     let contract_id = register_contract(&env);
     let client = create_client(&env, &contract_id);
@@ -181,7 +216,7 @@ fn test_remove_organization_not_registered() {
     let organization = Address::generate(&env);
     env.mock_all_auths();
     client.initialize(&admin);
-    client.remove_organization(&admin, &organization); // Should panic
+    client.deactivate_organization(&admin, &organization); // Should panic
 }
 
 
@@ -202,6 +237,7 @@ fn test_register_participation_success() {
     client.register_organization(&admin, &organization, &org_name);
     let timestamp = 1700000000u64;
     setup_ledger_time(&env, timestamp);
+    create_open_task(&env, &client, &organization, &task_id);
     client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
 
     assert!(client.verify_participation(&volunteer, &task_id));
@@ -220,23 +256,23 @@ fn test_register_participation_success() {
          assert!(env.storage().persistent().has(&record_key)); // Check record exists
         assert!(env.storage().persistent().get_ttl(&record_key) > 0);
 
-        let vol_list_key = crate::storage::DataKey::VolunteerParticipations(volunteer.clone());
-         assert!(env.storage().persistent().has(&vol_list_key)); // Check list exists
-        let vol_keys: Vec<crate::storage::ParticipationKey> = env.storage().persistent().get(&vol_list_key).unwrap();
+        let vol_page_key = crate::storage::DataKey::IndexPage(IndexId::Volunteer(volunteer.clone()), 0);
+         assert!(env.storage().persistent().has(&vol_page_key)); // Check page exists
+        let vol_keys: Vec<crate::storage::ParticipationKey> = env.storage().persistent().get(&vol_page_key).unwrap();
         assert!(vol_keys.contains(&p_key));
-        assert!(env.storage().persistent().get_ttl(&vol_list_key) > 0);
+        assert!(env.storage().persistent().get_ttl(&vol_page_key) > 0);
 
-        let task_list_key = crate::storage::DataKey::TaskVolunteers(task_id.clone());
-        assert!(env.storage().persistent().has(&task_list_key)); // Check list exists
-        let task_vols: Vec<Address> = env.storage().persistent().get(&task_list_key).unwrap();
+        let task_page_key = crate::storage::DataKey::IndexPage(IndexId::Task(task_id.clone()), 0);
+        assert!(env.storage().persistent().has(&task_page_key)); // Check page exists
+        let task_vols: Vec<Address> = env.storage().persistent().get(&task_page_key).unwrap();
         assert!(task_vols.contains(&volunteer));
-        assert!(env.storage().persistent().get_ttl(&task_list_key) > 0);
+        assert!(env.storage().persistent().get_ttl(&task_page_key) > 0);
 
-        let org_list_key = crate::storage::DataKey::OrgParticipationList(organization.clone());
-        assert!(env.storage().persistent().has(&org_list_key)); // Check list exists
-        let org_keys: Vec<crate::storage::ParticipationKey> = env.storage().persistent().get(&org_list_key).unwrap();
+        let org_page_key = crate::storage::DataKey::IndexPage(IndexId::Organization(organization.clone()), 0);
+        assert!(env.storage().persistent().has(&org_page_key)); // Check page exists
+        let org_keys: Vec<crate::storage::ParticipationKey> = env.storage().persistent().get(&org_page_key).unwrap();
         assert!(org_keys.contains(&p_key));
-        assert!(env.storage().persistent().get_ttl(&org_list_key) > 0);
+        assert!(env.storage().persistent().get_ttl(&org_page_key) > 0);
     });
     env.logs().print();
 }
@@ -274,6 +310,7 @@ fn test_register_participation_already_registered() {
     env.mock_all_auths();
     client.initialize(&admin);
     client.register_organization(&admin, &organization, &org_name);
+    create_open_task(&env, &client, &organization, &task_id);
     client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
     client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata); // Should panic
 }
@@ -294,6 +331,7 @@ fn test_register_participation_task_name_too_long() {
     env.mock_all_auths();
     client.initialize(&admin);
     client.register_organization(&admin, &organization, &org_name);
+    create_open_task(&env, &client, &organization, &task_id);
     client.register_participation(&organization, &volunteer, &task_id, &long_task_name, &metadata); // Should panic
 }
 
@@ -313,6 +351,7 @@ fn test_register_participation_metadata_too_long() {
     env.mock_all_auths();
     client.initialize(&admin);
     client.register_organization(&admin, &organization, &org_name);
+    create_open_task(&env, &client, &organization, &task_id);
     client.register_participation(&organization, &volunteer, &task_id, &task_name, &long_metadata); // Should panic
 }
 
@@ -365,40 +404,45 @@ fn test_get_volunteer_participations_pagination() {
     let task_name_0 = str_to_sdkstring(&env, "Paginated_Task_0");
     let timestamp_0 = 1700000000u64;
     setup_ledger_time(&env, timestamp_0);
+    create_open_task(&env, &client, &organization, &task_id_0);
     client.register_participation(&organization, &volunteer, &task_id_0, &task_name_0, &metadata);
-    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_0.clone(), task_name: task_name_0.clone(), timestamp: timestamp_0, organization: organization.clone(), metadata: metadata.clone() });
+    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_0.clone(), task_name: task_name_0.clone(), timestamp: timestamp_0, organization: organization.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_0, check_out: None, duration: 0, badge_token_id: None });
 
     // Participation 1
     let task_id_1 = str_to_sdkstring(&env, "task-p-1");
     let task_name_1 = str_to_sdkstring(&env, "Paginated_Task_1");
     let timestamp_1 = 1700001000u64;
     setup_ledger_time(&env, timestamp_1);
+    create_open_task(&env, &client, &organization, &task_id_1);
     client.register_participation(&organization, &volunteer, &task_id_1, &task_name_1, &metadata);
-    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_1.clone(), task_name: task_name_1.clone(), timestamp: timestamp_1, organization: organization.clone(), metadata: metadata.clone() });
+    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_1.clone(), task_name: task_name_1.clone(), timestamp: timestamp_1, organization: organization.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_1, check_out: None, duration: 0, badge_token_id: None });
 
     // Participation 2
     let task_id_2 = str_to_sdkstring(&env, "task-p-2");
     let task_name_2 = str_to_sdkstring(&env, "Paginated_Task_2");
     let timestamp_2 = 1700002000u64;
     setup_ledger_time(&env, timestamp_2);
+    create_open_task(&env, &client, &organization, &task_id_2);
     client.register_participation(&organization, &volunteer, &task_id_2, &task_name_2, &metadata);
-    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_2.clone(), task_name: task_name_2.clone(), timestamp: timestamp_2, organization: organization.clone(), metadata: metadata.clone() });
+    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_2.clone(), task_name: task_name_2.clone(), timestamp: timestamp_2, organization: organization.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_2, check_out: None, duration: 0, badge_token_id: None });
 
     // Participation 3
     let task_id_3 = str_to_sdkstring(&env, "task-p-3");
     let task_name_3 = str_to_sdkstring(&env, "Paginated_Task_3");
     let timestamp_3 = 1700003000u64;
     setup_ledger_time(&env, timestamp_3);
+    create_open_task(&env, &client, &organization, &task_id_3);
     client.register_participation(&organization, &volunteer, &task_id_3, &task_name_3, &metadata);
-    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_3.clone(), task_name: task_name_3.clone(), timestamp: timestamp_3, organization: organization.clone(), metadata: metadata.clone() });
+    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_3.clone(), task_name: task_name_3.clone(), timestamp: timestamp_3, organization: organization.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_3, check_out: None, duration: 0, badge_token_id: None });
 
     // Participation 4
     let task_id_4 = str_to_sdkstring(&env, "task-p-4");
     let task_name_4 = str_to_sdkstring(&env, "Paginated_Task_4");
     let timestamp_4 = 1700004000u64;
     setup_ledger_time(&env, timestamp_4);
+    create_open_task(&env, &client, &organization, &task_id_4);
     client.register_participation(&organization, &volunteer, &task_id_4, &task_name_4, &metadata);
-    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_4.clone(), task_name: task_name_4.clone(), timestamp: timestamp_4, organization: organization.clone(), metadata: metadata.clone() });
+    expected_participations.push_back(Participation { volunteer: volunteer.clone(), task_id: task_id_4.clone(), task_name: task_name_4.clone(), timestamp: timestamp_4, organization: organization.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_4, check_out: None, duration: 0, badge_token_id: None });
 
     // Test pagination scenarios
     let page1 = client.get_volunteer_participations(&volunteer, &0, &2);
@@ -452,6 +496,7 @@ fn test_get_task_volunteers_pagination() {
     let volunteer_0 = Address::generate(&env);
     expected_volunteers.push_back(volunteer_0.clone());
     setup_ledger_time(&env, 1700000000u64);
+    create_open_task(&env, &client, &organization, &task_id);
     client.register_participation(&organization, &volunteer_0, &task_id, &task_name, &metadata);
 
     // Volunteer 1
@@ -530,8 +575,9 @@ fn test_get_organization_participations_pagination() {
     let task_name_o1_0 = str_to_sdkstring(&env, "Org1_Task_0");
     let timestamp_o1_0 = 1700000000u64;
     setup_ledger_time(&env, timestamp_o1_0);
+    create_open_task(&env, &client, &org1, &task_id_o1_0);
     client.register_participation(&org1, &volunteer_o1_0, &task_id_o1_0, &task_name_o1_0, &metadata);
-    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_0.clone(), task_id: task_id_o1_0.clone(), task_name: task_name_o1_0.clone(), timestamp: timestamp_o1_0, organization: org1.clone(), metadata: metadata.clone() });
+    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_0.clone(), task_id: task_id_o1_0.clone(), task_name: task_name_o1_0.clone(), timestamp: timestamp_o1_0, organization: org1.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_o1_0, check_out: None, duration: 0, badge_token_id: None });
 
     // Org1 Participation 1
     let volunteer_o1_1 = Address::generate(&env);
@@ -539,8 +585,9 @@ fn test_get_organization_participations_pagination() {
     let task_name_o1_1 = str_to_sdkstring(&env, "Org1_Task_1");
     let timestamp_o1_1 = 1700001000u64;
     setup_ledger_time(&env, timestamp_o1_1);
+    create_open_task(&env, &client, &org1, &task_id_o1_1);
     client.register_participation(&org1, &volunteer_o1_1, &task_id_o1_1, &task_name_o1_1, &metadata);
-    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_1.clone(), task_id: task_id_o1_1.clone(), task_name: task_name_o1_1.clone(), timestamp: timestamp_o1_1, organization: org1.clone(), metadata: metadata.clone() });
+    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_1.clone(), task_id: task_id_o1_1.clone(), task_name: task_name_o1_1.clone(), timestamp: timestamp_o1_1, organization: org1.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_o1_1, check_out: None, duration: 0, badge_token_id: None });
 
     // Org1 Participation 2
     let volunteer_o1_2 = Address::generate(&env);
@@ -548,8 +595,9 @@ fn test_get_organization_participations_pagination() {
     let task_name_o1_2 = str_to_sdkstring(&env, "Org1_Task_2");
     let timestamp_o1_2 = 1700002000u64;
     setup_ledger_time(&env, timestamp_o1_2);
+    create_open_task(&env, &client, &org1, &task_id_o1_2);
     client.register_participation(&org1, &volunteer_o1_2, &task_id_o1_2, &task_name_o1_2, &metadata);
-    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_2.clone(), task_id: task_id_o1_2.clone(), task_name: task_name_o1_2.clone(), timestamp: timestamp_o1_2, organization: org1.clone(), metadata: metadata.clone() });
+    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_2.clone(), task_id: task_id_o1_2.clone(), task_name: task_name_o1_2.clone(), timestamp: timestamp_o1_2, organization: org1.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_o1_2, check_out: None, duration: 0, badge_token_id: None });
 
     // Org1 Participation 3
     let volunteer_o1_3 = Address::generate(&env);
@@ -557,8 +605,9 @@ fn test_get_organization_participations_pagination() {
     let task_name_o1_3 = str_to_sdkstring(&env, "Org1_Task_3");
     let timestamp_o1_3 = 1700003000u64;
     setup_ledger_time(&env, timestamp_o1_3);
+    create_open_task(&env, &client, &org1, &task_id_o1_3);
     client.register_participation(&org1, &volunteer_o1_3, &task_id_o1_3, &task_name_o1_3, &metadata);
-    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_3.clone(), task_id: task_id_o1_3.clone(), task_name: task_name_o1_3.clone(), timestamp: timestamp_o1_3, organization: org1.clone(), metadata: metadata.clone() });
+    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_3.clone(), task_id: task_id_o1_3.clone(), task_name: task_name_o1_3.clone(), timestamp: timestamp_o1_3, organization: org1.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_o1_3, check_out: None, duration: 0, badge_token_id: None });
 
     // Org1 Participation 4
     let volunteer_o1_4 = Address::generate(&env);
@@ -566,8 +615,9 @@ fn test_get_organization_participations_pagination() {
     let task_name_o1_4 = str_to_sdkstring(&env, "Org1_Task_4");
     let timestamp_o1_4 = 1700004000u64;
     setup_ledger_time(&env, timestamp_o1_4);
+    create_open_task(&env, &client, &org1, &task_id_o1_4);
     client.register_participation(&org1, &volunteer_o1_4, &task_id_o1_4, &task_name_o1_4, &metadata);
-    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_4.clone(), task_id: task_id_o1_4.clone(), task_name: task_name_o1_4.clone(), timestamp: timestamp_o1_4, organization: org1.clone(), metadata: metadata.clone() });
+    org1_expected_participations.push_back(Participation { volunteer: volunteer_o1_4.clone(), task_id: task_id_o1_4.clone(), task_name: task_name_o1_4.clone(), timestamp: timestamp_o1_4, organization: org1.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_o1_4, check_out: None, duration: 0, badge_token_id: None });
 
     // Register 1 participation for Org2
     let vol_org2 = Address::generate(&env);
@@ -575,8 +625,9 @@ fn test_get_organization_participations_pagination() {
     let task_name_org2 = str_to_sdkstring(&env,"Org2_Task");
     let timestamp_org2 = 1700000100u64;
     setup_ledger_time(&env, timestamp_org2);
+    create_open_task(&env, &client, &org2, &task_id_org2);
     client.register_participation(&org2, &vol_org2, &task_id_org2, &task_name_org2, &metadata);
-    let org2_participation = Participation { volunteer: vol_org2.clone(), task_id: task_id_org2.clone(), task_name: task_name_org2.clone(), timestamp: timestamp_org2, organization: org2.clone(), metadata: metadata.clone() };
+    let org2_participation = Participation { volunteer: vol_org2.clone(), task_id: task_id_org2.clone(), task_name: task_name_org2.clone(), timestamp: timestamp_org2, organization: org2.clone(), metadata: metadata.clone(), role: ParticipationRole::Helper, check_in: timestamp_org2, check_out: None, duration: 0, badge_token_id: None };
 
     // Test pagination scenarios for Org1
     let page1 = client.get_organization_participations(&org1, &0, &2);
@@ -626,23 +677,1989 @@ fn test_get_all_organizations() {
     env.mock_all_auths();
     client.initialize(&admin);
 
-    let initial_orgs = client.get_all_organizations();
+    let initial_orgs = client.get_all_organizations(&None, &0, &10).organizations;
     assert_eq!(initial_orgs.len(), 0);
 
     client.register_organization(&admin, &org1, &name1);
     client.register_organization(&admin, &org2, &name2);
 
-    let current_orgs = client.get_all_organizations();
+    let current_orgs = client.get_all_organizations(&None, &0, &10).organizations;
     assert_eq!(current_orgs.len(), 2);
 
     assert!(current_orgs.contains(&org1));
     assert!(current_orgs.contains(&org2));
 
-    client.remove_organization(&admin, &org1);
+    client.deactivate_organization(&admin, &org1);
 
-    let final_orgs = client.get_all_organizations();
+    assert_eq!(client.get_all_organizations(&None, &0, &10).organizations.len(), 2);
+    assert_eq!(client.get_all_organizations(&Some(OrganizationStatus::Deactivated), &0, &10).organizations, Vec::from_array(&env, [org1.clone()]));
+    assert_eq!(client.get_all_organizations(&None, &1, &10).organizations, Vec::from_array(&env, [org2.clone()]));
+
+    let final_orgs = client.get_all_organizations(&Some(OrganizationStatus::Active), &0, &10).organizations;
     assert_eq!(final_orgs.len(), 1);
     assert!(!final_orgs.contains(&org1));
     assert!(final_orgs.contains(&org2));
     assert_eq!(final_orgs.get_unchecked(0), org2); // Only org2 should remain
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_get_all_organizations_scan_is_capped() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.deactivate_organization(&admin, &organization);
+
+    // Queue the deactivated organization behind a full scan of other entries
+    env.as_contract(&contract_id, || {
+        let mut registry = Vec::new(&env);
+        for _ in 0..participation_storage::MAX_QUERY_SCAN {
+            registry.push_back(Address::generate(&env));
+        }
+        registry.push_back(organization.clone());
+        env.storage().persistent().set(&core_storage::DataKey::OrganizationList, &registry);
+    });
+
+    let status = Some(OrganizationStatus::Deactivated);
+    let first = client.get_all_organizations(&status, &0, &10);
+    assert_eq!(first.organizations.len(), 0);
+    assert_eq!(first.next_cursor, Some(participation_storage::MAX_QUERY_SCAN));
+
+    let second = client.get_all_organizations(&status, &first.next_cursor.unwrap(), &10);
+    assert_eq!(second.organizations, Vec::from_array(&env, [organization.clone()]));
+    assert_eq!(second.next_cursor, None);
+
+    // Unfiltered pages stop at the limit and resume after it
+    let page = client.get_all_organizations(&None, &0, &10);
+    assert_eq!(page.organizations.len(), 10);
+    assert_eq!(page.next_cursor, Some(10));
+}
+// --- Revocation Tests ---
+
+#[test]
+fn test_revoke_participation_by_organization() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-revoke");
+    let task_name = str_to_sdkstring(&env, "Beach_Cleanup");
+    let reason = str_to_sdkstring(&env, "Volunteer did not attend");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+    assert!(client.verify_participation(&volunteer, &task_id));
+
+    setup_ledger_time(&env, 1700000500);
+    client.revoke_participation(&organization, &volunteer, &task_id, &reason);
+
+    assert!(!client.verify_participation(&volunteer, &task_id));
+    assert_eq!(client.get_volunteer_participations(&volunteer, &0, &10).len(), 0);
+    assert_eq!(client.get_task_volunteers(&task_id, &0, &10).len(), 0);
+    assert_eq!(client.get_organization_participations(&organization, &0, &10).len(), 0);
+
+    let tombstone = client.get_revocation(&volunteer, &task_id);
+    assert_eq!(tombstone.organization, organization);
+    assert_eq!(tombstone.revoked_by, organization);
+    assert_eq!(tombstone.reason, reason);
+    assert_eq!(tombstone.timestamp, 1700000500);
+
+    // A corrected record can be issued again after revocation
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+    assert!(client.verify_participation(&volunteer, &task_id));
+}
+
+#[test]
+fn test_revoke_participation_by_admin() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-fraud");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    client.revoke_participation(&admin, &volunteer, &task_id, &str_to_sdkstring(&env, "Fraudulent entry"));
+
+    assert!(!client.verify_participation(&volunteer, &task_id));
+    assert_eq!(client.get_revocation(&volunteer, &task_id).revoked_by, admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_revoke_participation_other_organization() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let other_org = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-other");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Org_A"));
+    client.register_organization(&admin, &other_org, &str_to_sdkstring(&env, "Org_B"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    client.revoke_participation(&other_org, &volunteer, &task_id, &str_to_sdkstring(&env, "Not mine")); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #202)")]
+fn test_revoke_participation_not_found() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.revoke_participation(&admin, &volunteer, &str_to_sdkstring(&env, "missing"), &str_to_sdkstring(&env, "Reason")); // Should panic
+}
+
+// --- Two-Sided Attestation Tests ---
+
+#[test]
+fn test_propose_and_accept_participation() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-attest");
+    let task_name = str_to_sdkstring(&env, "Food_Bank");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+
+    setup_ledger_time(&env, 1700000000);
+    create_open_task(&env, &client, &organization, &task_id);
+    client.propose_participation(&organization, &organization, &volunteer, &task_id, &task_name, &metadata);
+
+    // Pending records do not count yet
+    assert!(!client.verify_participation(&volunteer, &task_id));
+    let pending = client.get_pending_attestations(&volunteer, &0, &10);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get_unchecked(0).organization, organization);
+
+    setup_ledger_time(&env, 1700003600);
+    client.accept_participation(&volunteer, &task_id);
+
+    assert!(client.verify_participation(&volunteer, &task_id));
+    assert_eq!(client.get_pending_attestations(&volunteer, &0, &10).len(), 0);
+    let details = client.get_participation_details(&volunteer, &task_id);
+    assert_eq!(details.timestamp, 1700000000); // Time of the proposal, not the acceptance
+    assert_eq!(client.get_organization_participations(&organization, &0, &10).len(), 1);
+}
+
+#[test]
+fn test_accept_participation_requires_volunteer_auth() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-auth");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.propose_participation(&organization, &organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    client.accept_participation(&volunteer, &task_id);
+    let auths = env.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, volunteer);
+}
+
+#[test]
+fn test_reject_participation() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-reject");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.propose_participation(&organization, &organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    client.reject_participation(&volunteer, &task_id);
+
+    assert!(!client.verify_participation(&volunteer, &task_id));
+    assert_eq!(client.get_pending_attestations(&volunteer, &0, &10).len(), 0);
+}
+
+#[test]
+fn test_pending_attestations_expire_and_are_capped_per_organization() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let other_organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.register_organization(&admin, &other_organization, &str_to_sdkstring(&env, "Other_Org"));
+    setup_ledger_time(&env, 1700000000);
+
+    // One organization cannot fill the volunteer's whole pending list
+    for i in 0..=participation_storage::MAX_PENDING_PER_ORGANIZATION {
+        let task_id = SdkString::from_bytes(&env, &[b't', b'-', b'a' + i as u8]);
+        create_open_task(&env, &client, &organization, &task_id);
+        let result = client.try_propose_participation(&organization, &organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+        if i < participation_storage::MAX_PENDING_PER_ORGANIZATION {
+            assert!(result.is_ok());
+        } else {
+            assert_eq!(result, Err(Ok(ContractError::TooManyPendingAttestations)));
+        }
+    }
+    let other_task = str_to_sdkstring(&env, "task-other");
+    create_open_task(&env, &client, &other_organization, &other_task);
+    client.propose_participation(&other_organization, &other_organization, &volunteer, &other_task, &str_to_sdkstring(&env, "Task"), &metadata);
+    assert_eq!(client.get_pending_attestations(&volunteer, &0, &100).len(), participation_storage::MAX_PENDING_PER_ORGANIZATION + 1);
+
+    // Unanswered proposals expire
+    setup_ledger_time(&env, 1700000000 + participation_storage::PENDING_ATTESTATION_TTL + 1);
+    assert_eq!(client.get_pending_attestations(&volunteer, &0, &100).len(), 0);
+    assert_eq!(client.try_accept_participation(&volunteer, &other_task), Err(Ok(ContractError::AttestationNotFound)));
+
+    // Expired proposals no longer block the volunteer or the organization
+    let task_id = str_to_sdkstring(&env, "t-a");
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+    client.propose_participation(&other_organization, &other_organization, &volunteer, &other_task, &str_to_sdkstring(&env, "Task"), &metadata);
+    assert_eq!(client.get_pending_attestations(&volunteer, &0, &100).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #203)")]
+fn test_register_participation_while_pending() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-dup");
+    let task_name = str_to_sdkstring(&env, "Task");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.propose_participation(&organization, &organization, &volunteer, &task_id, &task_name, &metadata);
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #204)")]
+fn test_accept_participation_not_found() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.accept_participation(&volunteer, &str_to_sdkstring(&env, "missing")); // Should panic
+}
+
+// --- Task Registry Tests ---
+
+#[test]
+fn test_create_task_and_query() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-registry");
+    let name = str_to_sdkstring(&env, "Tree_Planting");
+    let description = str_to_sdkstring(&env, "Plant 500 trees in the city park");
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+
+    client.create_task(&organization, &organization, &task_params(&task_id, &name, &description, 1700000000, 1700086400, 50));
+    create_open_task(&env, &client, &organization, &str_to_sdkstring(&env, "task-second"));
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.organization, organization);
+    assert_eq!(task.name, name);
+    assert_eq!(task.description, description);
+    assert_eq!(task.start_time, 1700000000);
+    assert_eq!(task.end_time, 1700086400);
+    assert_eq!(task.max_volunteers, 50);
+    assert_eq!(task.status, task_storage::TaskStatus::Open);
+
+    let tasks = client.get_organization_tasks(&organization, &0, &10);
+    assert_eq!(tasks.len(), 2);
+    assert_eq!(tasks.get_unchecked(0), task);
+
+    client.update_task_status(&organization, &task_id, &task_storage::TaskStatus::Closed);
+    assert_eq!(client.get_task(&task_id).status, task_storage::TaskStatus::Closed);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #301)")]
+fn test_create_task_already_exists() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-dup");
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    create_open_task(&env, &client, &organization, &task_id); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #302)")]
+fn test_register_participation_task_not_found() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.register_participation(&organization, &volunteer, &str_to_sdkstring(&env, "unknown-task"), &str_to_sdkstring(&env, "Task"), &metadata); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #303)")]
+fn test_register_participation_task_of_other_organization() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let other_org = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-owned");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Org_A"));
+    client.register_organization(&admin, &other_org, &str_to_sdkstring(&env, "Org_B"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&other_org, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #304)")]
+fn test_register_participation_task_closed() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-closed");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.update_task_status(&organization, &task_id, &task_storage::TaskStatus::Cancelled);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #305)")]
+fn test_register_participation_task_full() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-full");
+    let task_name = str_to_sdkstring(&env, "Task");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.create_task(&organization, &organization, &task_params(&task_id, &task_name, &str_to_sdkstring(&env, "Two seats"), 0, u64::MAX, 2));
+    client.register_participation(&organization, &Address::generate(&env), &task_id, &task_name, &metadata);
+    client.register_participation(&organization, &Address::generate(&env), &task_id, &task_name, &metadata);
+    client.register_participation(&organization, &Address::generate(&env), &task_id, &task_name, &metadata); // Should panic
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #306)")]
+fn test_register_participation_outside_task_window() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-window");
+    let task_name = str_to_sdkstring(&env, "Task");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.create_task(&organization, &organization, &task_params(&task_id, &task_name, &str_to_sdkstring(&env, "Weekend event"), 1700000000, 1700086400, 10));
+    setup_ledger_time(&env, 1700086401);
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata); // Should panic
+}
+
+#[test]
+fn test_check_out_accumulates_hours() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_a = str_to_sdkstring(&env, "task-hours-a");
+    let task_b = str_to_sdkstring(&env, "task-hours-b");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_a);
+    create_open_task(&env, &client, &organization, &task_b);
+
+    setup_ledger_time(&env, 1700000000);
+    client.register_participation(&organization, &volunteer, &task_a, &str_to_sdkstring(&env, "Task_A"), &metadata);
+    client.register_participation(&organization, &volunteer, &task_b, &str_to_sdkstring(&env, "Task_B"), &metadata);
+
+    setup_ledger_time(&env, 1700020000);
+    client.record_check_out(&organization, &organization, &volunteer, &task_a, &(1700000000 + 3 * 3600));
+    client.record_check_out(&organization, &organization, &volunteer, &task_b, &(1700000000 + 5400));
+
+    let details = client.get_participation_details(&volunteer, &task_a);
+    assert_eq!(details.check_in, 1700000000);
+    assert_eq!(details.check_out, Some(1700000000 + 3 * 3600));
+    assert_eq!(details.duration, 3 * 3600);
+    assert_eq!(details.role, ParticipationRole::Helper);
+
+    // 3h + 1.5h rounds down to whole hours
+    assert_eq!(client.get_volunteer_hours(&volunteer), 4);
+    assert_eq!(client.get_organization_hours(&organization), 4);
+    assert_eq!(client.get_task_hours(&task_a), 3);
+    assert_eq!(client.get_task_hours(&task_b), 1);
+
+    // Revoked records no longer count
+    client.revoke_participation(&organization, &volunteer, &task_a, &str_to_sdkstring(&env, "Logged twice"));
+    assert_eq!(client.get_volunteer_hours(&volunteer), 1);
+    assert_eq!(client.get_organization_hours(&organization), 1);
+    assert_eq!(client.get_task_hours(&task_a), 0);
+}
+
+#[test]
+fn test_record_check_in_for_late_registration() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-late");
+    let task_name = str_to_sdkstring(&env, "Cleanup");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.create_task(&organization, &organization, &task_params(&task_id, &task_name, &str_to_sdkstring(&env, "Weekend event"), 1700000000, 1700086400, 10));
+
+    // Registered hours after the volunteer actually arrived
+    setup_ledger_time(&env, 1700050000);
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+
+    // Check-in must fall within the task window and not in the future
+    assert_eq!(
+        client.try_record_check_in(&organization, &organization, &volunteer, &task_id, &1699999999),
+        Err(Ok(ContractError::TaskOutsideTimeWindow))
+    );
+    assert_eq!(
+        client.try_record_check_in(&organization, &organization, &volunteer, &task_id, &1700050001),
+        Err(Ok(ContractError::InvalidTimestamp))
+    );
+
+    client.record_check_in(&organization, &organization, &volunteer, &task_id, &(1700000000 + 3600));
+    client.record_check_out(&organization, &organization, &volunteer, &task_id, &(1700000000 + 3 * 3600));
+
+    let details = client.get_participation_details(&volunteer, &task_id);
+    assert_eq!(details.check_in, 1700000000 + 3600);
+    assert_eq!(details.duration, 2 * 3600);
+    assert_eq!(client.get_volunteer_hours(&volunteer), 2);
+
+    // The check-in is fixed once the volunteer is checked out
+    assert_eq!(
+        client.try_record_check_in(&organization, &organization, &volunteer, &task_id, &1700000000),
+        Err(Ok(ContractError::AlreadyCheckedOut))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #206)")]
+fn test_check_out_twice() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-hours");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    setup_ledger_time(&env, 1700000000);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    setup_ledger_time(&env, 1700003600);
+    client.record_check_out(&organization, &organization, &volunteer, &task_id, &1700003600);
+    client.record_check_out(&organization, &organization, &volunteer, &task_id, &1700003600);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #207)")]
+fn test_check_out_in_the_future() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-hours");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    setup_ledger_time(&env, 1700000000);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    client.record_check_out(&organization, &organization, &volunteer, &task_id, &1700003600);
+}
+
+#[test]
+fn test_set_participation_role() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-role");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    client.set_participation_role(&organization, &organization, &volunteer, &task_id, &ParticipationRole::Lead);
+    assert_eq!(client.get_participation_details(&volunteer, &task_id).role, ParticipationRole::Lead);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_participation_role_other_organization() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let other_org = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-role");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.register_organization(&admin, &other_org, &str_to_sdkstring(&env, "Other_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    client.set_participation_role(&other_org, &other_org, &volunteer, &task_id, &ParticipationRole::Driver);
+}
+
+#[test]
+fn test_register_participations_batch() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-batch");
+    let task_name = str_to_sdkstring(&env, "Beach_Cleanup");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.create_task(&organization, &organization, &task_params(&task_id, &task_name, &str_to_sdkstring(&env, "Three seats"), 0, u64::MAX, 3));
+
+    let existing = Address::generate(&env);
+    client.register_participation(&organization, &existing, &task_id, &task_name, &metadata);
+
+    let v1 = Address::generate(&env);
+    let v2 = Address::generate(&env);
+    let v3 = Address::generate(&env);
+    let volunteers = Vec::from_array(&env, [v1.clone(), existing.clone(), v1.clone(), v2.clone(), v3.clone()]);
+    let results = client.register_participations_batch(&organization, &organization, &task_id, &task_name, &volunteers, &metadata);
+
+    assert_eq!(results.len(), 5);
+    assert_eq!(results.get(0).unwrap().status, BatchEntryStatus::Registered);
+    assert_eq!(results.get(1).unwrap().status, BatchEntryStatus::Duplicate);
+    assert_eq!(results.get(2).unwrap().status, BatchEntryStatus::Duplicate);
+    assert_eq!(results.get(3).unwrap().status, BatchEntryStatus::Registered);
+    assert_eq!(results.get(4).unwrap().status, BatchEntryStatus::LimitExceeded);
+    assert_eq!(results.get(4).unwrap().volunteer, v3);
+
+    assert!(client.verify_participation(&v1, &task_id));
+    assert!(client.verify_participation(&v2, &task_id));
+    assert!(!client.verify_participation(&v3, &task_id));
+    assert_eq!(client.get_task_volunteers(&task_id, &0, &10).len(), 3);
+}
+
+#[test]
+fn test_coordinator_batch_mints_badges() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let nft_id = env.register(mock_nft::MockNftCore, ());
+    let nft = mock_nft::MockNftCoreClient::new(&env, &nft_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let coordinator = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-batch");
+    let task_name = str_to_sdkstring(&env, "Task");
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.add_organization_delegate(&organization, &organization, &coordinator, &OrganizationRole::Coordinator);
+    client.set_badge_config(&admin, &Some(nft_id.clone()), &true);
+
+    let v1 = Address::generate(&env);
+    let v2 = Address::generate(&env);
+    let volunteers = Vec::from_array(&env, [v1.clone(), v2.clone()]);
+    client.register_participations_batch(&coordinator, &organization, &task_id, &task_name, &volunteers, &None);
+
+    assert_eq!(nft.minted_count(), 2);
+    assert!(client.get_participation_details(&v1, &task_id).badge_token_id.is_some());
+    assert!(client.get_participation_details(&v2, &task_id).badge_token_id.is_some());
+
+    // Addresses without a role cannot submit batches
+    let result = client.try_register_participations_batch(
+        &Address::generate(&env),
+        &organization,
+        &task_id,
+        &task_name,
+        &Vec::from_array(&env, [Address::generate(&env)]),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_register_participations_batch_empty() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-batch");
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+
+    client.register_participations_batch(&organization, &organization, &task_id, &str_to_sdkstring(&env, "Task"), &Vec::new(&env), &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_register_participations_batch_too_large() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-batch");
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+
+    let mut volunteers = Vec::new(&env);
+    for _ in 0..(participation_storage::MAX_BATCH_SIZE + 1) {
+        volunteers.push_back(Address::generate(&env));
+    }
+    client.register_participations_batch(&organization, &organization, &task_id, &str_to_sdkstring(&env, "Task"), &volunteers, &None);
+}
+
+#[test]
+fn test_participation_indexes_span_pages() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let id = IndexId::Task(str_to_sdkstring(&env, "task-large"));
+    let total = INDEX_PAGE_SIZE * 2 + INDEX_PAGE_SIZE / 2;
+
+    env.as_contract(&contract_id, || {
+        for i in 0..total {
+            let page = index_storage::push(&env, &id, &i).unwrap();
+            assert_eq!(page, i / INDEX_PAGE_SIZE);
+        }
+        assert_eq!(index_storage::len(&env, &id), total);
+
+        // A range crossing the boundary between the first and second page
+        let range: Vec<u32> = index_storage::get_range(&env, &id, INDEX_PAGE_SIZE - 5, 10).unwrap();
+        assert_eq!(range.len(), 10);
+        for i in 0..10 {
+            assert_eq!(range.get(i).unwrap(), INDEX_PAGE_SIZE - 5 + i);
+        }
+        let tail: Vec<u32> = index_storage::get_range(&env, &id, total - 3, 10).unwrap();
+        assert_eq!(tail.len(), 3);
+
+        // Removing from the first page shifts later entries down by one
+        assert!(index_storage::remove(&env, &id, 0, &0u32));
+        assert!(!index_storage::remove(&env, &id, 0, &0u32));
+        assert_eq!(index_storage::len(&env, &id), total - 1);
+        let range: Vec<u32> = index_storage::get_range(&env, &id, INDEX_PAGE_SIZE - 1, 2).unwrap();
+        assert_eq!(range.get(0).unwrap(), INDEX_PAGE_SIZE);
+        assert_eq!(range.get(1).unwrap(), INDEX_PAGE_SIZE + 1);
+
+        // New entries keep filling the last page
+        assert_eq!(index_storage::push(&env, &id, &total).unwrap(), 2);
+    });
+}
+
+#[test]
+fn test_participation_index_drops_empty_pages() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let id = IndexId::Volunteer(Address::generate(&env));
+    let meta_key = core_storage::DataKey::IndexMeta(id.clone());
+
+    env.as_contract(&contract_id, || {
+        for i in 0..(INDEX_PAGE_SIZE * 2) {
+            index_storage::push(&env, &id, &i).unwrap();
+        }
+
+        // Emptying the first page removes it from storage and from the metadata
+        for i in 0..INDEX_PAGE_SIZE {
+            assert!(index_storage::remove(&env, &id, 0, &i));
+        }
+        let meta: index_storage::IndexMeta = env.storage().persistent().get(&meta_key).unwrap();
+        assert_eq!(meta.page_lens.len(), 1);
+        assert!(!env.storage().persistent().has(&core_storage::DataKey::IndexPage(id.clone(), 0)));
+
+        // Page numbers are not reused while the index still has entries
+        assert_eq!(index_storage::push(&env, &id, &0u32).unwrap(), 2);
+        let all: Vec<u32> = index_storage::get_range(&env, &id, 0, INDEX_PAGE_SIZE * 2).unwrap();
+        assert_eq!(all.len(), INDEX_PAGE_SIZE + 1);
+        assert_eq!(all.get(0).unwrap(), INDEX_PAGE_SIZE);
+        let range: Vec<u32> = index_storage::get_range(&env, &id, INDEX_PAGE_SIZE, 1).unwrap();
+        assert_eq!(range.get(0).unwrap(), 0);
+
+        // An emptied index leaves nothing behind
+        for i in INDEX_PAGE_SIZE..(INDEX_PAGE_SIZE * 2) {
+            assert!(index_storage::remove(&env, &id, 1, &i));
+        }
+        assert!(index_storage::remove(&env, &id, 2, &0u32));
+        assert_eq!(index_storage::len(&env, &id), 0);
+        assert!(!env.storage().persistent().has(&meta_key));
+    });
+}
+
+#[test]
+fn test_organization_profile() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+
+    let profile = client.get_organization_profile(&organization);
+    assert_eq!(profile.name, str_to_sdkstring(&env, "Test_Org"));
+    assert_eq!(profile.website, None);
+    assert!(!profile.verified);
+
+    let website = Some(str_to_sdkstring(&env, "https://example.org"));
+    let contact_hash = BytesN::from_array(&env, &[7u8; 32]);
+    client.update_organization_profile(&organization, &organization, &str_to_sdkstring(&env, "Renamed_Org"), &website, &contact_hash);
+    client.set_organization_verified(&admin, &organization, &true);
+
+    let profile = client.get_organization_profile(&organization);
+    assert_eq!(profile.name, str_to_sdkstring(&env, "Renamed_Org"));
+    assert_eq!(profile.website, website);
+    assert_eq!(profile.contact_hash, contact_hash);
+    assert!(profile.verified);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_organization_verified_not_admin() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+
+    client.set_organization_verified(&organization, &organization, &true);
+}
+
+#[test]
+fn test_coordinator_registers_participation() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let coordinator = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-delegate");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+
+    client.add_organization_delegate(&organization, &organization, &owner, &OrganizationRole::Owner);
+    // Owners manage the other delegates
+    client.add_organization_delegate(&owner, &organization, &coordinator, &OrganizationRole::Coordinator);
+
+    let delegates = client.get_organization_delegates(&organization);
+    assert_eq!(delegates.len(), 2);
+    assert_eq!(delegates.get(1).unwrap().delegate, coordinator);
+    assert_eq!(delegates.get(1).unwrap().role, OrganizationRole::Coordinator);
+
+    client.delegate_register_participation(&coordinator, &organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+    let details = client.get_participation_details(&volunteer, &task_id);
+    assert_eq!(details.organization, organization);
+
+    client.remove_organization_delegate(&owner, &organization, &coordinator);
+    assert_eq!(client.get_organization_delegates(&organization).len(), 1);
+    let result = client.try_delegate_register_participation(
+        &coordinator,
+        &organization,
+        &Address::generate(&env),
+        &task_id,
+        &str_to_sdkstring(&env, "Task"),
+        &metadata,
+    );
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+#[test]
+fn test_coordinator_manages_tasks_and_check_times() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let coordinator = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-coordinated");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.add_organization_delegate(&organization, &organization, &coordinator, &OrganizationRole::Coordinator);
+
+    setup_ledger_time(&env, 1700000000);
+    let params = task_params(&task_id, &str_to_sdkstring(&env, "Task"), &str_to_sdkstring(&env, "Description"), 0, u64::MAX, 10);
+    assert_eq!(
+        client.try_create_task(&outsider, &organization, &params),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    client.create_task(&coordinator, &organization, &params);
+
+    client.delegate_register_participation(&coordinator, &organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+    setup_ledger_time(&env, 1700010000);
+    client.record_check_in(&coordinator, &organization, &volunteer, &task_id, &(1700000000 + 600));
+    client.set_participation_role(&coordinator, &organization, &volunteer, &task_id, &ParticipationRole::Lead);
+    assert_eq!(
+        client.try_record_check_out(&outsider, &organization, &volunteer, &task_id, &(1700000000 + 3600)),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+    client.record_check_out(&coordinator, &organization, &volunteer, &task_id, &(1700000000 + 3600));
+
+    let details = client.get_participation_details(&volunteer, &task_id);
+    assert_eq!(details.role, ParticipationRole::Lead);
+    assert_eq!(details.duration, 3000);
+
+    let other_volunteer = Address::generate(&env);
+    client.propose_participation(&coordinator, &organization, &other_volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+    assert_eq!(
+        client.try_propose_participation(&outsider, &organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata),
+        Err(Ok(ContractError::NotAuthorized))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_coordinator_cannot_manage_delegates() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let coordinator = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    client.add_organization_delegate(&organization, &organization, &coordinator, &OrganizationRole::Coordinator);
+
+    client.add_organization_delegate(&coordinator, &organization, &Address::generate(&env), &OrganizationRole::Coordinator);
+}
+
+#[test]
+fn test_application_approved() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let applicant = Address::generate(&env);
+    let other = Address::generate(&env);
+    let website = Some(str_to_sdkstring(&env, "https://example.org"));
+    let contact_hash = BytesN::from_array(&env, &[1u8; 32]);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    setup_ledger_time(&env, 1700000000);
+
+    client.apply_for_registration(&applicant, &str_to_sdkstring(&env, "Applicant_Org"), &website, &contact_hash);
+    client.apply_for_registration(&other, &str_to_sdkstring(&env, "Other_Org"), &None, &contact_hash);
+    assert!(!client.is_organization(&applicant));
+
+    let pending = client.get_pending_applications(&0, &10);
+    assert_eq!(pending.len(), 2);
+    assert_eq!(pending.get(0).unwrap().organization, applicant);
+    assert_eq!(pending.get(0).unwrap().status, ApplicationStatus::Pending);
+    assert_eq!(client.get_pending_applications(&1, &10).len(), 1);
+
+    setup_ledger_time(&env, 1700000100);
+    client.approve_application(&admin, &applicant);
+
+    assert!(client.is_organization(&applicant));
+    let profile = client.get_organization_profile(&applicant);
+    assert_eq!(profile.name, str_to_sdkstring(&env, "Applicant_Org"));
+    assert_eq!(profile.website, website);
+    assert_eq!(profile.contact_hash, contact_hash);
+
+    let application = client.get_application(&applicant);
+    assert_eq!(application.status, ApplicationStatus::Approved);
+    assert_eq!(application.decided_at, Some(1700000100));
+    assert_eq!(client.get_pending_applications(&0, &10).len(), 1);
+
+    // An organization registered while its application was pending cannot be
+    // approved over its current profile
+    client.register_organization(&admin, &other, &str_to_sdkstring(&env, "Other_Org"));
+    client.set_organization_verified(&admin, &other, &true);
+    let result = client.try_approve_application(&admin, &other);
+    assert_eq!(result, Err(Ok(ContractError::OrganizationAlreadyRegistered)));
+    assert!(client.get_organization_profile(&other).verified);
+}
+
+#[test]
+fn test_application_expires_and_frees_the_queue() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let applicant = Address::generate(&env);
+    let late_applicant = Address::generate(&env);
+    let contact_hash = BytesN::from_array(&env, &[1u8; 32]);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    setup_ledger_time(&env, 1700000000);
+
+    // Anyone may apply without the admin's involvement
+    client.apply_for_registration(&applicant, &str_to_sdkstring(&env, "Applicant_Org"), &None, &contact_hash);
+
+    // Fill the rest of the queue with placeholders queued after the applicant
+    env.as_contract(&contract_id, || {
+        let mut pending = organization_storage::get_pending_applicants(&env);
+        for _ in 1..organization_storage::MAX_PENDING_APPLICATIONS {
+            pending.push_back(Address::generate(&env));
+        }
+        env.storage().persistent().set(&core_storage::DataKey::PendingApplications, &pending);
+    });
+    let result = client.try_apply_for_registration(&late_applicant, &str_to_sdkstring(&env, "Late_Org"), &None, &contact_hash);
+    assert_eq!(result, Err(Ok(ContractError::TooManyPendingApplications)));
+
+    setup_ledger_time(&env, 1700000000 + organization_storage::APPLICATION_TTL);
+    assert_eq!(client.get_application(&applicant).status, ApplicationStatus::Expired);
+    assert_eq!(client.get_pending_applications(&0, &10).len(), 0);
+    assert_eq!(client.try_approve_application(&admin, &applicant), Err(Ok(ContractError::ApplicationNotFound)));
+
+    // The expired application at the front of the queue makes room
+    client.apply_for_registration(&late_applicant, &str_to_sdkstring(&env, "Late_Org"), &None, &contact_hash);
+    env.as_contract(&contract_id, || {
+        let pending = organization_storage::get_pending_applicants(&env);
+        assert_eq!(pending.len(), organization_storage::MAX_PENDING_APPLICATIONS);
+        assert!(!pending.contains(&applicant));
+    });
+    client.approve_application(&admin, &late_applicant);
+    assert!(client.is_organization(&late_applicant));
+
+    // An expired applicant may simply apply again
+    client.apply_for_registration(&applicant, &str_to_sdkstring(&env, "Applicant_Org"), &None, &contact_hash);
+    assert_eq!(client.get_application(&applicant).status, ApplicationStatus::Pending);
+}
+
+#[test]
+fn test_application_rejected_and_reapplied() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let applicant = Address::generate(&env);
+    let contact_hash = BytesN::from_array(&env, &[1u8; 32]);
+    let reason = str_to_sdkstring(&env, "Missing registration documents");
+    env.mock_all_auths();
+    client.initialize(&admin);
+    setup_ledger_time(&env, 1700000000);
+
+    client.apply_for_registration(&applicant, &str_to_sdkstring(&env, "Applicant_Org"), &None, &contact_hash);
+    let result = client.try_apply_for_registration(&applicant, &str_to_sdkstring(&env, "Applicant_Org"), &None, &contact_hash);
+    assert_eq!(result, Err(Ok(ContractError::ApplicationAlreadyPending)));
+
+    client.reject_application(&admin, &applicant, &reason);
+    assert!(!client.is_organization(&applicant));
+    let application = client.get_application(&applicant);
+    assert_eq!(application.status, ApplicationStatus::Rejected);
+    assert_eq!(application.reason, Some(reason));
+    assert_eq!(client.get_pending_applications(&0, &10).len(), 0);
+
+    // A decided application cannot be approved afterwards
+    let result = client.try_approve_application(&admin, &applicant);
+    assert_eq!(result, Err(Ok(ContractError::ApplicationNotFound)));
+
+    // A rejected applicant waits out the cooldown before applying again
+    let result = client.try_apply_for_registration(&applicant, &str_to_sdkstring(&env, "Applicant_Org"), &None, &contact_hash);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    setup_ledger_time(&env, 1700000000 + organization_storage::APPLICATION_COOLDOWN);
+    client.apply_for_registration(&applicant, &str_to_sdkstring(&env, "Applicant_Org"), &None, &contact_hash);
+    assert_eq!(client.get_application(&applicant).status, ApplicationStatus::Pending);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_approve_application_not_admin() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let applicant = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.apply_for_registration(&applicant, &str_to_sdkstring(&env, "Applicant_Org"), &None, &BytesN::from_array(&env, &[0u8; 32]));
+
+    client.approve_application(&applicant, &applicant);
+}
+
+#[test]
+fn test_admin_transfer() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let new_admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    assert_eq!(client.try_accept_admin(&new_admin), Err(Ok(ContractError::NoPendingAdmin)));
+    client.propose_admin(&admin, &new_admin);
+    // Nothing changes until the candidate accepts
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.try_accept_admin(&organization), Err(Ok(ContractError::NotAuthorized)));
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+
+    let result = client.try_register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+    client.register_organization(&new_admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    assert!(client.is_organization(&organization));
+}
+
+#[test]
+fn test_co_admins() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let co_admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    client.add_co_admin(&admin, &co_admin);
+    assert_eq!(client.get_co_admins(), Vec::from_array(&env, [co_admin.clone()]));
+    client.register_organization(&co_admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    assert!(client.is_organization(&organization));
+
+    // Co-admins cannot manage the admin set
+    let result = client.try_add_co_admin(&co_admin, &Address::generate(&env));
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+    client.remove_co_admin(&admin, &co_admin);
+    assert_eq!(client.get_co_admins().len(), 0);
+    let result = client.try_deactivate_organization(&co_admin, &organization);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+#[test]
+fn test_pause_blocks_writes_but_not_reads() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-pause");
+    let task_name = str_to_sdkstring(&env, "Task");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+
+    client.pause(&admin);
+    assert!(client.is_paused());
+
+    let result = client.try_register_participation(&organization, &Address::generate(&env), &task_id, &task_name, &metadata);
+    assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+    let result = client.try_register_organization(&admin, &Address::generate(&env), &str_to_sdkstring(&env, "Other_Org"));
+    assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+    let result = client.try_deactivate_organization(&admin, &organization);
+    assert_eq!(result, Err(Ok(ContractError::ContractPaused)));
+
+    assert!(client.verify_participation(&volunteer, &task_id));
+    assert_eq!(client.get_task_volunteers(&task_id, &0, &10).len(), 1);
+    assert!(client.is_organization(&organization));
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.register_participation(&organization, &Address::generate(&env), &task_id, &task_name, &metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_pause_not_admin() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    client.pause(&Address::generate(&env));
+}
+
+#[test]
+fn test_participation_proof() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-proof");
+    let metadata = Some(str_to_sdkstring(&env, "Morning shift"));
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    setup_ledger_time(&env, 1700000000);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    // The digest can be recomputed off-chain from the claim's XDR
+    let details = client.get_participation_details(&volunteer, &task_id);
+    let digest = client.get_participation_proof(&volunteer, &task_id);
+    let claim = ParticipationClaim {
+        volunteer: volunteer.clone(),
+        task_id: task_id.clone(),
+        task_name: str_to_sdkstring(&env, "Task"),
+        organization: organization.clone(),
+        timestamp: 1700000000,
+        metadata: metadata.clone(),
+    };
+    let expected: BytesN<32> = env.crypto().sha256(&claim.to_xdr(&env)).into();
+    assert_eq!(digest, expected);
+    assert_eq!(client.verify_participation_proof(&digest), details);
+
+    // Updates keep the digest and it resolves to the current record
+    setup_ledger_time(&env, 1700003600);
+    client.record_check_out(&organization, &organization, &volunteer, &task_id, &1700003600);
+    client.set_participation_role(&organization, &organization, &volunteer, &task_id, &ParticipationRole::Driver);
+    assert_eq!(client.get_participation_proof(&volunteer, &task_id), digest);
+    let current = client.verify_participation_proof(&digest);
+    assert_eq!(current.check_out, Some(1700003600));
+    assert_eq!(current.role, ParticipationRole::Driver);
+
+    // Revoked records no longer verify
+    client.revoke_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Invalid"));
+    assert_eq!(client.try_verify_participation_proof(&digest), Err(Ok(ContractError::ProofNotFound)));
+}
+
+mod mock_hooks {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol, Vec};
+
+    use crate::hooks::{Goal, GoalType};
+
+    #[contract]
+    pub struct MockGoalTracker;
+
+    // Goals are stored one entry per goal, with a goal id list per volunteer
+    #[contractimpl]
+    impl MockGoalTracker {
+        pub fn add_goal(env: Env, volunteer: Address, goal_type: GoalType, target_amount: u64) -> u64 {
+            let id = env.storage().instance().get(&0u32).unwrap_or(0u64) + 1;
+            env.storage().instance().set(&0u32, &id);
+            env.storage().persistent().set(&id, &Goal { id, volunteer: volunteer.clone(), goal_type, target_amount, current_amount: 0, is_completed: false });
+            let mut ids: Vec<u64> = env.storage().persistent().get(&volunteer).unwrap_or(Vec::new(&env));
+            ids.push_back(id);
+            env.storage().persistent().set(&volunteer, &ids);
+            id
+        }
+
+        pub fn get_goals_by_user(env: Env, volunteer: Address) -> Vec<u64> {
+            env.storage().persistent().get(&volunteer).unwrap_or(Vec::new(&env))
+        }
+
+        pub fn get_goal(env: Env, goal_id: u64) -> Goal {
+            env.storage().persistent().get(&goal_id).unwrap()
+        }
+
+        pub fn update_progress(env: Env, goal_id: u64, amount_to_add: u64) -> bool {
+            let mut goal: Goal = env.storage().persistent().get(&goal_id).unwrap();
+            goal.current_amount += amount_to_add;
+            goal.is_completed = goal.current_amount >= goal.target_amount;
+            env.storage().persistent().set(&goal_id, &goal);
+            true
+        }
+    }
+
+    #[contract]
+    pub struct MockReputation;
+
+    #[contractimpl]
+    impl MockReputation {
+        pub fn endorse_volunteer(env: Env, org: Address, volunteer: Address, score: u32, _category: Symbol) {
+            org.require_auth();
+            env.storage().instance().set(&volunteer, &score);
+        }
+
+        pub fn get_reputation(env: Env, volunteer: Address) -> u32 {
+            env.storage().instance().get(&volunteer).unwrap_or(0)
+        }
+    }
+
+}
+
+mod mock_failing_hooks {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    #[contract]
+    pub struct FailingReputation;
+
+    #[contractimpl]
+    impl FailingReputation {
+        pub fn endorse_volunteer(_env: Env, _org: Address, _volunteer: Address, _score: u32, _category: Symbol) {
+            panic!("Unauthorized organization");
+        }
+    }
+}
+
+#[test]
+fn test_hooks_credit_goals_and_reputation() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let goal_tracker_id = env.register(mock_hooks::MockGoalTracker, ());
+    let goal_tracker = mock_hooks::MockGoalTrackerClient::new(&env, &goal_tracker_id);
+    let reputation_id = env.register(mock_hooks::MockReputation, ());
+    let reputation = mock_hooks::MockReputationClient::new(&env, &reputation_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_a = str_to_sdkstring(&env, "task-hook-a");
+    let task_b = str_to_sdkstring(&env, "task-hook-b");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_a);
+    create_open_task(&env, &client, &organization, &task_b);
+    client.set_hook_config(&admin, &Some(goal_tracker_id.clone()), &Some(reputation_id.clone()));
+
+    let tasks_goal = goal_tracker.add_goal(&volunteer, &crate::hooks::GoalType::TasksCompleted, &2);
+    let hours_goal = goal_tracker.add_goal(&volunteer, &crate::hooks::GoalType::HoursVolunteered, &10);
+
+    setup_ledger_time(&env, 1700000000);
+    client.register_participation(&organization, &volunteer, &task_a, &str_to_sdkstring(&env, "Task_A"), &metadata);
+    client.register_participation(&organization, &volunteer, &task_b, &str_to_sdkstring(&env, "Task_B"), &metadata);
+
+    let goal = goal_tracker.get_goal(&tasks_goal);
+    assert_eq!(goal.current_amount, 2);
+    assert!(goal.is_completed);
+    assert_eq!(reputation.get_reputation(&volunteer), 2 * crate::hooks::REPUTATION_PER_PARTICIPATION);
+
+    // Half hours only count once they add up to a whole hour
+    setup_ledger_time(&env, 1700000000 + 5400);
+    client.record_check_out(&organization, &organization, &volunteer, &task_a, &(1700000000 + 5400));
+    assert_eq!(goal_tracker.get_goal(&hours_goal).current_amount, 1);
+    client.record_check_out(&organization, &organization, &volunteer, &task_b, &(1700000000 + 5400));
+    assert_eq!(goal_tracker.get_goal(&hours_goal).current_amount, 3);
+}
+
+#[test]
+fn test_failing_hook_does_not_block_registration() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let failing_id = env.register(mock_failing_hooks::FailingReputation, ());
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-hook");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    // The goal tracker address is not even a contract
+    client.set_hook_config(&admin, &Some(Address::generate(&env)), &Some(failing_id));
+
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+    assert!(client.verify_participation(&volunteer, &task_id));
+}
+
+#[test]
+fn test_deactivated_organization_records_stay_verifiable() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-retained");
+    let task_name = str_to_sdkstring(&env, "Task");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+    assert_eq!(client.get_issuer_status(&volunteer, &task_id), OrganizationStatus::Active);
+
+    client.deactivate_organization(&admin, &organization);
+
+    assert!(client.verify_participation(&volunteer, &task_id));
+    assert_eq!(client.get_issuer_status(&volunteer, &task_id), OrganizationStatus::Deactivated);
+    let result = client.try_register_participation(&organization, &Address::generate(&env), &task_id, &task_name, &metadata);
+    assert_eq!(result, Err(Ok(ContractError::OrganizationDeactivated)));
+    let result = client.try_register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    assert_eq!(result, Err(Ok(ContractError::OrganizationAlreadyRegistered)));
+
+    client.reactivate_organization(&admin, &organization);
+    assert!(client.is_organization(&organization));
+    assert_eq!(client.get_issuer_status(&volunteer, &task_id), OrganizationStatus::Active);
+    client.register_participation(&organization, &Address::generate(&env), &task_id, &task_name, &metadata);
+
+    let result = client.try_reactivate_organization(&admin, &organization);
+    assert_eq!(result, Err(Ok(ContractError::OrganizationAlreadyRegistered)));
+}
+
+#[test]
+fn test_add_evidence_by_volunteer_and_organization() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-evidence");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+    assert_eq!(client.get_evidence(&volunteer, &task_id).len(), 0);
+
+    setup_ledger_time(&env, 1700000100);
+    let photo_hash = BytesN::from_array(&env, &[1; 32]);
+    let photo_uri = Some(str_to_sdkstring(&env, "ipfs://photo"));
+    client.add_evidence(&volunteer, &volunteer, &task_id, &EvidenceKind::Photo, &photo_hash, &photo_uri);
+    let sheet_hash = BytesN::from_array(&env, &[2; 32]);
+    client.add_evidence(&organization, &volunteer, &task_id, &EvidenceKind::SignInSheet, &sheet_hash, &None);
+
+    let evidence = client.get_evidence(&volunteer, &task_id);
+    assert_eq!(evidence.len(), 2);
+    let first = evidence.get(0).unwrap();
+    assert_eq!(first.kind, EvidenceKind::Photo);
+    assert_eq!(first.content_hash, photo_hash);
+    assert_eq!(first.uri, photo_uri);
+    assert_eq!(first.added_by, volunteer);
+    assert_eq!(first.timestamp, 1700000100);
+    let second = evidence.get(1).unwrap();
+    assert_eq!(second.kind, EvidenceKind::SignInSheet);
+    assert_eq!(second.added_by, organization);
+    assert_eq!(second.uri, None);
+}
+
+#[test]
+fn test_add_evidence_validation() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-evidence");
+    let metadata: Option<SdkString> = None;
+    let hash = BytesN::from_array(&env, &[7; 32]);
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+
+    let result = client.try_add_evidence(&volunteer, &volunteer, &task_id, &EvidenceKind::Gps, &hash, &None);
+    assert_eq!(result, Err(Ok(ContractError::ParticipationNotFound)));
+    let result = client.try_get_evidence(&volunteer, &task_id);
+    assert_eq!(result, Err(Ok(ContractError::ParticipationNotFound)));
+
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    let stranger = Address::generate(&env);
+    let result = client.try_add_evidence(&stranger, &volunteer, &task_id, &EvidenceKind::Gps, &hash, &None);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+
+    let long_uri = Some(str_to_sdkstring(&env, &"u".repeat(257)));
+    let result = client.try_add_evidence(&volunteer, &volunteer, &task_id, &EvidenceKind::Gps, &hash, &long_uri);
+    assert_eq!(result, Err(Ok(ContractError::EvidenceUriTooLong)));
+
+    for _ in 0..participation_storage::MAX_EVIDENCE_PER_PARTICIPATION {
+        client.add_evidence(&volunteer, &volunteer, &task_id, &EvidenceKind::Gps, &hash, &None);
+    }
+    let result = client.try_add_evidence(&volunteer, &volunteer, &task_id, &EvidenceKind::Gps, &hash, &None);
+    assert_eq!(result, Err(Ok(ContractError::EvidenceLimitReached)));
+}
+
+#[test]
+fn test_revoke_participation_clears_evidence() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-evidence");
+    let task_name = str_to_sdkstring(&env, "Task");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+    client.add_evidence(&volunteer, &volunteer, &task_id, &EvidenceKind::Photo, &BytesN::from_array(&env, &[1; 32]), &None);
+
+    client.revoke_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Duplicate"));
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+
+    assert_eq!(client.get_evidence(&volunteer, &task_id).len(), 0);
+}
+
+fn empty_filter() -> ParticipationFilter {
+    ParticipationFilter { organization: None, from: None, to: None, task_name_prefix: None }
+}
+
+#[test]
+fn test_query_volunteer_participations_filters() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let org_a = Address::generate(&env);
+    let org_b = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &org_a, &str_to_sdkstring(&env, "Org_A"));
+    client.register_organization(&admin, &org_b, &str_to_sdkstring(&env, "Org_B"));
+
+    let records = [
+        (&org_a, "task-1", "Beach_Cleanup", 1700000100u64),
+        (&org_a, "task-2", "Park_Cleanup", 1700000200),
+        (&org_b, "task-3", "Beach_Survey", 1700000300),
+    ];
+    for (org, task, name, time) in records {
+        setup_ledger_time(&env, time);
+        let task_id = str_to_sdkstring(&env, task);
+        create_open_task(&env, &client, org, &task_id);
+        client.register_participation(org, &volunteer, &task_id, &str_to_sdkstring(&env, name), &metadata);
+    }
+
+    let result = client.query_volunteer_participations(&volunteer, &empty_filter(), &0, &10);
+    assert_eq!(result.participations.len(), 3);
+    assert_eq!(result.next_cursor, None);
+
+    let by_org = ParticipationFilter { organization: Some(org_a.clone()), ..empty_filter() };
+    let result = client.query_volunteer_participations(&volunteer, &by_org, &0, &10);
+    assert_eq!(result.participations.len(), 2);
+    assert!(result.participations.iter().all(|p| p.organization == org_a));
+
+    let by_time = ParticipationFilter { from: Some(1700000200), to: Some(1700000300), ..empty_filter() };
+    let result = client.query_volunteer_participations(&volunteer, &by_time, &0, &10);
+    assert_eq!(result.participations.len(), 2);
+    assert_eq!(result.participations.get(0).unwrap().task_id, str_to_sdkstring(&env, "task-2"));
+
+    let by_prefix = ParticipationFilter { task_name_prefix: Some(str_to_sdkstring(&env, "Beach")), ..empty_filter() };
+    let result = client.query_volunteer_participations(&volunteer, &by_prefix, &0, &10);
+    assert_eq!(result.participations.len(), 2);
+
+    let combined = ParticipationFilter {
+        organization: Some(org_a.clone()),
+        task_name_prefix: Some(str_to_sdkstring(&env, "Beach")),
+        ..empty_filter()
+    };
+    let result = client.query_volunteer_participations(&volunteer, &combined, &0, &10);
+    assert_eq!(result.participations.len(), 1);
+    assert_eq!(result.participations.get(0).unwrap().task_id, str_to_sdkstring(&env, "task-1"));
+
+    // A full page stops the scan and points past the last match
+    let result = client.query_volunteer_participations(&volunteer, &by_prefix, &0, &1);
+    assert_eq!(result.participations.get(0).unwrap().task_id, str_to_sdkstring(&env, "task-1"));
+    assert_eq!(result.next_cursor, Some(1));
+    let result = client.query_volunteer_participations(&volunteer, &by_prefix, &1, &1);
+    assert_eq!(result.participations.get(0).unwrap().task_id, str_to_sdkstring(&env, "task-3"));
+    assert_eq!(result.next_cursor, None);
+}
+
+#[test]
+fn test_query_org_participations_time_range() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-query");
+    let task_name = str_to_sdkstring(&env, "Task");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    for i in 0..5u64 {
+        setup_ledger_time(&env, 1700000000 + i * 100);
+        client.register_participation(&organization, &Address::generate(&env), &task_id, &task_name, &metadata);
+    }
+
+    let filter = ParticipationFilter { from: Some(1700000100), to: Some(1700000300), ..empty_filter() };
+    let result = client.query_org_participations(&organization, &filter, &0, &2);
+    assert_eq!(result.participations.len(), 2);
+    assert_eq!(result.participations.get(0).unwrap().timestamp, 1700000100);
+    assert_eq!(result.next_cursor, Some(3));
+    assert_eq!(result.matched, 2);
+    let result = client.query_org_participations(&organization, &filter, &3, &2);
+    assert_eq!(result.participations.len(), 1);
+    assert_eq!(result.participations.get(0).unwrap().timestamp, 1700000300);
+    assert_eq!(result.matched, 1);
+
+    // A zero limit only counts the matches
+    let result = client.query_org_participations(&organization, &filter, &0, &0);
+    assert_eq!(result.participations.len(), 0);
+    assert_eq!(result.matched, 3);
+    assert_eq!(result.next_cursor, None);
+
+    let filter = ParticipationFilter { from: Some(1700000500), ..empty_filter() };
+    let result = client.query_org_participations(&organization, &filter, &0, &10);
+    assert_eq!(result.participations.len(), 0);
+    assert_eq!(result.next_cursor, None);
+}
+
+#[test]
+fn test_query_participations_scan_is_capped() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let organization = Address::generate(&env);
+    let id = IndexId::Organization(organization.clone());
+    let task_name = str_to_sdkstring(&env, "Task");
+    let scan = participation_storage::MAX_QUERY_SCAN;
+
+    // Index entries without a record never match, so the matching records sit
+    // beyond what one call may scan
+    env.as_contract(&contract_id, || {
+        for _ in 0..scan {
+            let key = core_storage::ParticipationKey {
+                volunteer: Address::generate(&env),
+                task_id: str_to_sdkstring(&env, "task-missing"),
+            };
+            index_storage::push(&env, &id, &key).unwrap();
+        }
+    });
+    env.as_contract(&contract_id, || {
+        for i in 0..10u64 {
+            let task_id = str_to_sdkstring(&env, "task-query");
+            participation_storage::store_participation(&env, &organization, &Address::generate(&env), &task_id, &task_name, &None, i)
+                .unwrap();
+        }
+    });
+
+    // Each query has to fit in the default budget
+    env.cost_estimate().budget().reset_default();
+    env.as_contract(&contract_id, || {
+        let result = participation_storage::query_participations(&env, &id, &empty_filter(), 0, 5).unwrap();
+        assert_eq!(result.participations.len(), 0);
+        assert_eq!(result.next_cursor, Some(scan));
+
+        let result = participation_storage::query_participations(&env, &id, &empty_filter(), scan, 5).unwrap();
+        assert_eq!(result.participations.len(), 5);
+        assert_eq!(result.participations.get(0).unwrap().timestamp, 0);
+        assert_eq!(result.next_cursor, Some(scan + 5));
+   
+        // Counting spends the same scan budget and resumes the same way
+        let result = participation_storage::query_participations(&env, &id, &empty_filter(), 0, 0).unwrap();
+        assert_eq!(result.matched, 0);
+        assert_eq!(result.next_cursor, Some(scan));
+        let result = participation_storage::query_participations(&env, &id, &empty_filter(), scan, 0).unwrap();
+        assert_eq!(result.matched, 10);
+        assert_eq!(result.next_cursor, None);
+    });
+}
+
+#[test]
+fn test_query_participations_invalid_arguments() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let volunteer = Address::generate(&env);
+
+    let inverted = ParticipationFilter { from: Some(200), to: Some(100), ..empty_filter() };
+    let result = client.try_query_volunteer_participations(&volunteer, &inverted, &0, &10);
+    assert_eq!(result, Err(Ok(ContractError::InvalidTimestamp)));
+
+    let long_prefix = ParticipationFilter {
+        task_name_prefix: Some(str_to_sdkstring(&env, &"a".repeat(65))),
+        ..empty_filter()
+    };
+    let result = client.try_query_volunteer_participations(&volunteer, &long_prefix, &0, &10);
+    assert_eq!(result, Err(Ok(ContractError::TaskNameTooLong)));
+
+    let result = client.try_query_org_participations(&volunteer, &empty_filter(), &0, &101);
+    assert_eq!(result, Err(Ok(ContractError::PaginationLimitExceeded)));
+}
+
+mod mock_nft {
+    use soroban_sdk::{contract, contractimpl, Address, Env, Map, String, Vec};
+
+    #[contract]
+    pub struct MockNftCore;
+
+    #[contractimpl]
+    impl MockNftCore {
+        pub fn mint_nft(
+            env: Env,
+            minter: Address,
+            recipient: Address,
+            _title: String,
+            _description: String,
+            attributes: Vec<(String, String)>,
+            transferable: bool,
+        ) -> u128 {
+            minter.require_auth();
+            let id = env.storage().instance().get(&0u32).unwrap_or(0u128) + 1;
+            env.storage().instance().set(&0u32, &id);
+            let mut attribute_map = Map::new(&env);
+            for (key, value) in attributes.iter() {
+                attribute_map.set(key, value);
+            }
+            env.storage().instance().set(&id, &(recipient, transferable, attribute_map));
+            id
+        }
+
+        pub fn get_token(env: Env, token_id: u128) -> (Address, bool, Map<String, String>) {
+            env.storage().instance().get(&token_id).unwrap()
+        }
+
+        pub fn minted_count(env: Env) -> u128 {
+            env.storage().instance().get(&0u32).unwrap_or(0)
+        }
+    }
+}
+
+#[test]
+fn test_auto_mint_participation_badge() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let nft_id = env.register(mock_nft::MockNftCore, ());
+    let nft = mock_nft::MockNftCoreClient::new(&env, &nft_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-badge");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.set_badge_config(&admin, &Some(nft_id.clone()), &true);
+
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    let participation = client.get_participation_details(&volunteer, &task_id);
+    assert_eq!(participation.badge_token_id, Some(1));
+    let (owner, transferable, attributes) = nft.get_token(&1);
+    assert_eq!(owner, volunteer);
+    assert!(!transferable);
+    assert_eq!(attributes.get(str_to_sdkstring(&env, "task_id")), Some(task_id.clone()));
+    assert_eq!(attributes.get(str_to_sdkstring(&env, "organization")), Some(organization.to_string()));
+
+    // The proof digest resolves to the record carrying the token id
+    let digest = client.get_participation_proof(&volunteer, &task_id);
+    assert_eq!(client.verify_participation_proof(&digest), participation);
+
+    // Claiming an already minted badge does not mint again
+    assert_eq!(client.claim_participation_badge(&volunteer, &task_id), 1);
+    assert_eq!(nft.minted_count(), 1);
+}
+
+#[test]
+fn test_empty_task_name_defaults_for_records_and_badges() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let nft_id = env.register(nft_core::NFTCore, ());
+    let nft = nft_core::NFTCoreClient::new(&env, &nft_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let named_task = str_to_sdkstring(&env, "task-named");
+    let unnamed_task = str_to_sdkstring(&env, "task-unnamed");
+    let empty = str_to_sdkstring(&env, "");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    nft.initialize(&admin);
+    nft.add_authorized_minter(&admin, &contract_id);
+    client.set_badge_config(&admin, &Some(nft_id.clone()), &true);
+
+    let description = str_to_sdkstring(&env, "Description");
+    client.create_task(&organization, &organization, &task_params(&named_task, &str_to_sdkstring(&env, "Beach_Cleanup"), &description, 0, u64::MAX, 10));
+    client.create_task(&organization, &organization, &task_params(&unnamed_task, &empty, &description, 0, u64::MAX, 10));
+
+    // The record takes the task's own name
+    client.register_participation(&organization, &volunteer, &named_task, &empty, &metadata);
+    let details = client.get_participation_details(&volunteer, &named_task);
+    assert_eq!(details.task_name, str_to_sdkstring(&env, "Beach_Cleanup"));
+    assert_eq!(nft.get_nft(&details.badge_token_id.unwrap()).metadata.title, details.task_name);
+
+    // Without any name the badge is titled with the task id
+    client.register_participation(&organization, &volunteer, &unnamed_task, &empty, &metadata);
+    let details = client.get_participation_details(&volunteer, &unnamed_task);
+    assert_eq!(details.task_name, empty);
+    assert_eq!(nft.get_nft(&details.badge_token_id.unwrap()).metadata.title, unnamed_task);
+}
+
+#[test]
+fn test_revoked_badge_with_nft_core() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let nft_id = env.register(nft_core::NFTCore, ());
+    let nft = nft_core::NFTCoreClient::new(&env, &nft_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-badge");
+    let task_name = str_to_sdkstring(&env, "Task");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    nft.initialize(&admin);
+    nft.add_authorized_minter(&admin, &contract_id);
+    client.set_badge_config(&admin, &Some(nft_id.clone()), &true);
+
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+    let token_id = client.get_participation_details(&volunteer, &task_id).badge_token_id.unwrap();
+    let badge = nft.get_nft(&token_id);
+    assert_eq!(badge.owner, volunteer);
+    assert!(!badge.transferable);
+    assert_eq!(client.verify_participation_badge(&token_id).task_id, task_id);
+
+    // The badge stays with the volunteer but stops verifying
+    client.revoke_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Invalid"));
+    assert_eq!(nft.get_nft(&token_id).owner, volunteer);
+    assert_eq!(client.try_verify_participation_badge(&token_id), Err(Ok(ContractError::ParticipationNotFound)));
+
+    // Registering the task again reattaches the badge instead of minting another
+    client.register_participation(&organization, &volunteer, &task_id, &task_name, &metadata);
+    assert_eq!(client.get_participation_details(&volunteer, &task_id).badge_token_id, Some(token_id));
+    assert_eq!(nft.get_nfts_by_owner(&volunteer).len(), 1);
+    assert_eq!(client.claim_participation_badge(&volunteer, &task_id), token_id);
+    assert_eq!(client.verify_participation_badge(&token_id).volunteer, volunteer);
+}
+
+#[test]
+fn test_claim_participation_badge() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let nft_id = env.register(mock_nft::MockNftCore, ());
+    let nft = mock_nft::MockNftCoreClient::new(&env, &nft_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-badge");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+
+    let result = client.try_claim_participation_badge(&volunteer, &task_id);
+    assert_eq!(result, Err(Ok(ContractError::BadgeUnavailable)));
+    let result = client.try_claim_participation_badge(&volunteer, &str_to_sdkstring(&env, "missing"));
+    assert_eq!(result, Err(Ok(ContractError::ParticipationNotFound)));
+
+    client.set_badge_config(&admin, &Some(nft_id.clone()), &false);
+    let other = Address::generate(&env);
+    client.register_participation(&organization, &other, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+    assert_eq!(client.get_participation_details(&other, &task_id).badge_token_id, None);
+    assert_eq!(nft.minted_count(), 0);
+
+    assert_eq!(client.claim_participation_badge(&volunteer, &task_id), 1);
+    assert_eq!(client.get_participation_details(&volunteer, &task_id).badge_token_id, Some(1));
+    assert_eq!(nft.get_token(&1).0, volunteer);
+}
+
+#[test]
+fn test_failed_badge_mint_does_not_block_registration() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let volunteer = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-badge");
+    let metadata: Option<SdkString> = None;
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    // Not a contract, so every mint fails
+    client.set_badge_config(&admin, &Some(Address::generate(&env)), &true);
+
+    client.register_participation(&organization, &volunteer, &task_id, &str_to_sdkstring(&env, "Task"), &metadata);
+    assert!(client.verify_participation(&volunteer, &task_id));
+    assert_eq!(client.get_participation_details(&volunteer, &task_id).badge_token_id, None);
+
+    let result = client.try_claim_participation_badge(&volunteer, &task_id);
+    assert_eq!(result, Err(Ok(ContractError::BadgeUnavailable)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+fn test_set_badge_config_not_admin() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let admin = Address::generate(&env);
+    env.mock_all_auths();
+    client.initialize(&admin);
+
+    client.set_badge_config(&Address::generate(&env), &None, &false);
+}
+
+#[test]
+fn test_register_participations_batch_at_limit_with_hooks() {
+    let env = create_env();
+    let contract_id = register_contract(&env);
+    let client = create_client(&env, &contract_id);
+    let nft_id = env.register(nft_core::NFTCore, ());
+    let nft = nft_core::NFTCoreClient::new(&env, &nft_id);
+    let goal_tracker_id = env.register(mock_hooks::MockGoalTracker, ());
+    let goal_tracker = mock_hooks::MockGoalTrackerClient::new(&env, &goal_tracker_id);
+    let reputation_id = env.register(mock_hooks::MockReputation, ());
+    let admin = Address::generate(&env);
+    let organization = Address::generate(&env);
+    let task_id = str_to_sdkstring(&env, "task-batch");
+    env.mock_all_auths();
+    client.initialize(&admin);
+    client.register_organization(&admin, &organization, &str_to_sdkstring(&env, "Test_Org"));
+    create_open_task(&env, &client, &organization, &task_id);
+    nft.initialize(&admin);
+    nft.add_authorized_minter(&admin, &contract_id);
+    client.set_badge_config(&admin, &Some(nft_id.clone()), &true);
+    client.set_hook_config(&admin, &Some(goal_tracker_id.clone()), &Some(reputation_id.clone()));
+
+    // Worst case: every volunteer has the most goals the hook will update
+    let mut volunteers = Vec::new(&env);
+    for _ in 0..participation_storage::MAX_BATCH_SIZE {
+        let volunteer = Address::generate(&env);
+        for _ in 0..crate::hooks::MAX_HOOK_GOALS {
+            goal_tracker.add_goal(&volunteer, &crate::hooks::GoalType::TasksCompleted, &100);
+        }
+        volunteers.push_back(volunteer);
+    }
+
+    env.cost_estimate().budget().reset_default();
+    let results = client.register_participations_batch(&organization, &organization, &task_id, &str_to_sdkstring(&env, "Task"), &volunteers, &None);
+    assert_eq!(results.len(), participation_storage::MAX_BATCH_SIZE);
+    for result in results.iter() {
+        assert_eq!(result.status, BatchEntryStatus::Registered);
+    }
+    assert_eq!(nft.get_nfts_by_owner(&volunteers.get(0).unwrap()).len(), 1);
+}